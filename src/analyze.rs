@@ -0,0 +1,349 @@
+use crate::keys::{self, LoadProgress};
+use crate::model::KeyInfo;
+use anyhow::Result;
+use redis::aio::MultiplexedConnection;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Keys requested per SCAN call while analyzing
+const SCAN_COUNT: usize = 1000;
+
+/// Entries kept in each top-N list
+pub const TOP_N: usize = 10;
+
+/// Label for keys without the namespace separator
+pub const NO_PREFIX: &str = "(no prefix)";
+
+/// Distinct prefixes tracked; keys of further prefixes are counted together
+pub const MAX_PREFIXES: usize = 10_000;
+
+/// Label for keys of the prefixes beyond `MAX_PREFIXES`
+pub const OTHER_PREFIXES: &str = "(other prefixes)";
+
+/// A key and its size, in bytes or elements depending on the list
+#[derive(Debug, Clone, Serialize)]
+pub struct KeySize {
+    pub key: String,
+    pub key_type: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GroupStats {
+    pub keys: u64,
+    pub memory: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TtlHistogram {
+    pub no_expiry: u64,
+    pub under_hour: u64,
+    pub under_day: u64,
+    pub longer: u64,
+}
+
+/// Keyspace report, filled in while the background scan runs
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AnalysisReport {
+    pub started_at: String,
+    pub finished: bool,
+    pub error: Option<String>,
+    pub total_keys: u64, // DBSIZE when the scan started, used for progress
+    pub scanned_keys: u64,
+    pub total_memory: u64,
+    pub biggest_by_memory: Vec<KeySize>,
+    pub biggest_by_elements: BTreeMap<String, Vec<KeySize>>, // Per type
+    pub types: BTreeMap<String, GroupStats>,
+    pub ttl: TtlHistogram,
+    pub prefixes: HashMap<String, GroupStats>, // At most `MAX_PREFIXES` plus `OTHER_PREFIXES`
+}
+
+impl AnalysisReport {
+    /// Share of the keyspace scanned so far, between 0 and 1
+    pub fn progress(&self) -> f64 {
+        if self.finished {
+            1.0
+        } else if self.total_keys == 0 {
+            0.0
+        } else {
+            (self.scanned_keys as f64 / self.total_keys as f64).min(1.0)
+        }
+    }
+
+    /// Prefixes ordered by memory, largest first
+    pub fn top_prefixes(&self, n: usize) -> Vec<(&str, &GroupStats)> {
+        let mut prefixes: Vec<_> = self
+            .prefixes
+            .iter()
+            .map(|(p, stats)| (p.as_str(), stats))
+            .collect();
+        prefixes.sort_by(|a, b| b.1.memory.cmp(&a.1.memory).then(a.0.cmp(b.0)));
+        prefixes.truncate(n);
+        prefixes
+    }
+
+    fn record(&mut self, key: &KeyInfo, elements: Option<u64>, separator: &str) {
+        self.scanned_keys += 1;
+        self.total_memory += key.memory_usage;
+
+        let type_stats = self.types.entry(key.key_type.clone()).or_default();
+        type_stats.keys += 1;
+        type_stats.memory += key.memory_usage;
+
+        let prefix = match key.key.split_once(separator) {
+            Some((prefix, _)) => prefix,
+            None => NO_PREFIX,
+        };
+        let prefix = if self.prefixes.contains_key(prefix) || self.prefixes.len() < MAX_PREFIXES {
+            prefix
+        } else {
+            OTHER_PREFIXES
+        };
+        let prefix_stats = self.prefixes.entry(prefix.to_string()).or_default();
+        prefix_stats.keys += 1;
+        prefix_stats.memory += key.memory_usage;
+
+        match key.pttl {
+            pttl if pttl < 0 => self.ttl.no_expiry += 1,
+            pttl if pttl < 3_600_000 => self.ttl.under_hour += 1,
+            pttl if pttl < 86_400_000 => self.ttl.under_day += 1,
+            _ => self.ttl.longer += 1,
+        }
+
+        push_top(
+            &mut self.biggest_by_memory,
+            KeySize {
+                key: key.key.clone(),
+                key_type: key.key_type.clone(),
+                size: key.memory_usage,
+            },
+        );
+        if let Some(elements) = elements {
+            push_top(
+                self.biggest_by_elements
+                    .entry(key.key_type.clone())
+                    .or_default(),
+                KeySize {
+                    key: key.key.clone(),
+                    key_type: key.key_type.clone(),
+                    size: elements,
+                },
+            );
+        }
+    }
+
+    /// Write the report as pretty JSON under `<config dir>/tredis/reports`
+    pub fn export(&self) -> Result<PathBuf> {
+        let dir = crate::model::TredisConfig::config_path()
+            .parent()
+            .map(|p| p.join("reports"))
+            .unwrap_or_else(|| PathBuf::from("reports"));
+        std::fs::create_dir_all(&dir)?;
+
+        let path = dir.join(format!(
+            "analysis-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+}
+
+/// Insert into a list kept sorted by size, largest first, capped at `TOP_N`
+fn push_top(list: &mut Vec<KeySize>, entry: KeySize) {
+    if list.len() >= TOP_N && list.last().is_some_and(|last| last.size >= entry.size) {
+        return;
+    }
+    let pos = list.partition_point(|e| e.size >= entry.size);
+    list.insert(pos, entry);
+    list.truncate(TOP_N);
+}
+
+/// Walk the whole keyspace, updating `report` after every SCAN batch.
+/// `connections` holds one connection per cluster primary, or just the main connection.
+pub async fn run(
+    mut connections: Vec<MultiplexedConnection>,
+    separator: String,
+    report: Arc<Mutex<AnalysisReport>>,
+) {
+    let result = scan_all(&mut connections, &separator, &report).await;
+
+    let mut report = report.lock().unwrap();
+    report.finished = true;
+    if let Err(e) = result {
+        report.error = Some(e.to_string());
+    }
+}
+
+async fn scan_all(
+    connections: &mut [MultiplexedConnection],
+    separator: &str,
+    report: &Mutex<AnalysisReport>,
+) -> Result<()> {
+    let mut total_keys = 0;
+    for con in connections.iter_mut() {
+        let size: u64 = redis::cmd("DBSIZE").query_async(con).await.unwrap_or(0);
+        total_keys += size;
+    }
+    report.lock().unwrap().total_keys = total_keys;
+
+    let progress = LoadProgress::default();
+    for con in connections.iter_mut() {
+        let mut cursor: u64 = 0;
+        loop {
+            let (next_cursor, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("COUNT")
+                .arg(SCAN_COUNT)
+                .query_async(con)
+                .await?;
+
+            let infos = keys::fetch_metadata(con, batch, &progress).await?;
+            let elements = fetch_element_counts(con, &infos).await?;
+
+            let mut report = report.lock().unwrap();
+            for (info, count) in infos.iter().zip(elements) {
+                // Expired or deleted since SCAN returned it
+                if info.key_type == "none" {
+                    continue;
+                }
+                report.record(info, count, separator);
+            }
+            drop(report);
+
+            if next_cursor == 0 {
+                break;
+            }
+            cursor = next_cursor;
+        }
+    }
+
+    Ok(())
+}
+
+/// Length of each key in its type's own unit (bytes, items, members, fields, entries)
+async fn fetch_element_counts(
+    con: &mut MultiplexedConnection,
+    keys: &[KeyInfo],
+) -> Result<Vec<Option<u64>>> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut pipe = redis::pipe();
+    pipe.ignore_errors();
+    for key in keys {
+        let command = match key.key_type.as_str() {
+            "string" => "STRLEN",
+            "list" => "LLEN",
+            "set" => "SCARD",
+            "zset" => "ZCARD",
+            "hash" => "HLEN",
+            "stream" => "XLEN",
+            // Keeps replies aligned with the keys
+            _ => "EXISTS",
+        };
+        pipe.cmd(command).arg(&key.key);
+    }
+
+    let results: Vec<redis::Value> = pipe.query_async(con).await?;
+    Ok(results
+        .into_iter()
+        .zip(keys)
+        .map(|(value, key)| match key.key_type.as_str() {
+            "string" | "list" | "set" | "zset" | "hash" | "stream" => {
+                redis::from_redis_value(value).ok()
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str, key_type: &str, memory: u64, pttl: i64) -> KeyInfo {
+        KeyInfo {
+            key: name.to_string(),
+            key_type: key_type.to_string(),
+            pttl,
+            memory_usage: memory,
+            encoding: String::new(),
+        }
+    }
+
+    fn size(key: &str, size: u64) -> KeySize {
+        KeySize {
+            key: key.to_string(),
+            key_type: "string".to_string(),
+            size,
+        }
+    }
+
+    #[test]
+    fn push_top_keeps_the_largest_in_order() {
+        let mut list = Vec::new();
+        for n in 0..(TOP_N as u64 + 5) {
+            push_top(&mut list, size(&n.to_string(), n % 7 * 10 + n));
+        }
+        assert_eq!(list.len(), TOP_N);
+        assert!(list.windows(2).all(|w| w[0].size >= w[1].size));
+        let smallest_kept = list.last().unwrap().size;
+        push_top(&mut list, size("small", smallest_kept));
+        assert!(list.iter().all(|e| e.key != "small"));
+        push_top(&mut list, size("big", u64::MAX));
+        assert_eq!(list[0].key, "big");
+    }
+
+    #[test]
+    fn record_groups_by_type_prefix_and_ttl() {
+        let mut report = AnalysisReport::default();
+        report.record(&key("user:1", "hash", 100, -1), Some(3), ":");
+        report.record(&key("user:2", "hash", 50, 1_000), Some(5), ":");
+        report.record(&key("plain", "string", 10, 7_200_000), None, ":");
+
+        assert_eq!(report.scanned_keys, 3);
+        assert_eq!(report.total_memory, 160);
+        assert_eq!(report.types["hash"].keys, 2);
+        assert_eq!(report.prefixes["user"].memory, 150);
+        assert_eq!(report.prefixes[NO_PREFIX].keys, 1);
+        assert_eq!(
+            (
+                report.ttl.no_expiry,
+                report.ttl.under_hour,
+                report.ttl.under_day
+            ),
+            (1, 1, 1)
+        );
+        assert_eq!(report.biggest_by_memory[0].key, "user:1");
+        assert_eq!(report.biggest_by_elements["hash"][0].key, "user:2");
+        assert!(!report.biggest_by_elements.contains_key("string"));
+    }
+
+    #[test]
+    fn record_folds_prefixes_beyond_the_cap() {
+        let mut report = AnalysisReport::default();
+        for n in 0..MAX_PREFIXES + 3 {
+            report.record(&key(&format!("p{}:k", n), "string", 1, -1), None, ":");
+        }
+        // A known prefix keeps counting on its own
+        report.record(&key("p0:other", "string", 1, -1), None, ":");
+
+        assert_eq!(report.prefixes.len(), MAX_PREFIXES + 1);
+        assert_eq!(report.prefixes[OTHER_PREFIXES].keys, 3);
+        assert_eq!(report.prefixes["p0"].keys, 2);
+    }
+
+    #[test]
+    fn top_prefixes_orders_by_memory_then_name() {
+        let mut report = AnalysisReport::default();
+        report.record(&key("b:1", "string", 5, -1), None, ":");
+        report.record(&key("a:1", "string", 5, -1), None, ":");
+        report.record(&key("c:1", "string", 9, -1), None, ":");
+        let top: Vec<&str> = report.top_prefixes(2).into_iter().map(|(p, _)| p).collect();
+        assert_eq!(top, vec!["c", "a"]);
+    }
+}
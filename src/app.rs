@@ -1,12 +1,34 @@
+use crate::analyze::{self, AnalysisReport};
+use crate::cluster::{self, ClusterConnections};
+use crate::connection::{self, ConnectionFactory};
+use crate::decoders;
+use crate::editor::{SaveOutcome, ValueEditor};
+use crate::keys::{self, KeyFilter, KeyPage, LoadProgress, NamespaceRow, TtlChange, TtlReport};
 use crate::model::{
-    ConnectionConfig, KeyInfo, KeyValue, ServerConfig, ServerInfo, ServerType, StreamEntry,
+    ClusterNode, ConnectionConfig, KeyInfo, KeyValue, ServerConfig, ServerInfo, ServerType,
     TredisConfig,
 };
+use crate::monitor::{MonitorFilter, MonitorRecorder, MonitorStats};
+use crate::sentinel;
+use crate::streams::{self, StreamDetails, StreamPane, StreamReadMode};
+use crate::ui::field_dialog::FieldDialogState;
+use crate::ui::new_key_dialog::NewKeyDialogState;
 use crate::ui::server_dialog::ServerDialogState;
 use crate::ui::splash::SplashState;
+use crate::values::{self, DescribeWindow};
+use crate::viewer::ValueViewer;
 use anyhow::Result;
 use redis::AsyncCommands;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Accepted formats of the TTL dialog
+const TTL_HINT: &str = "90, 15m, 2h, 7d, 500ms, @2026-01-01 12:00:00, @<unix> or persist";
+
+/// Monitor entries kept for display
+const MONITOR_ENTRY_LIMIT: usize = 1000;
+/// Entries held back while the monitor is paused
+const MONITOR_BUFFER_LIMIT: usize = 10_000;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Mode {
@@ -16,6 +38,9 @@ pub enum Mode {
     Confirm,
     Resources,
     ServerDialog,
+    EditValue,
+    FieldDialog,
+    NewKeyDialog,
 }
 
 #[derive(Debug, Clone)]
@@ -31,6 +56,79 @@ pub enum PendingActionType {
     DeleteKey,
     DeleteServer,
     DeleteSelected,
+    CreateKey, // Submitting the new key dialog, never confirmed itself
+    OverwriteKey,
+    SetTtl,
+    RenameKey,
+    RenameKeyOverwrite,
+    CopyKey,
+    CopyKeyReplace,
+    MoveKeys,
+    HashSetField,
+    HashAddField,
+    HashDeleteField,
+    ListSetItem,
+    ListPushItem,
+    ListRemoveItem,
+    SetReplaceMember,
+    SetAddMember,
+    SetRemoveMember,
+    ZSetUpdateScore,
+    ZSetAddMember,
+    ZSetRemoveMember,
+    HashFieldTtl,
+    StreamCreateGroup,
+    StreamDestroyGroup,
+    StreamSetGroupId,
+    StreamDeleteConsumer,
+    StreamClaim,
+    StreamAutoClaim,
+    StreamAck,
+    TailStreams,
+    ConsumeStreams,
+}
+
+impl PendingActionType {
+    /// Actions on a single field/element/member of the described key
+    pub fn is_field_edit(&self) -> bool {
+        !matches!(
+            self,
+            PendingActionType::DeleteKey
+                | PendingActionType::DeleteServer
+                | PendingActionType::DeleteSelected
+                | PendingActionType::CreateKey
+                | PendingActionType::OverwriteKey
+                | PendingActionType::SetTtl
+                | PendingActionType::RenameKey
+                | PendingActionType::RenameKeyOverwrite
+                | PendingActionType::CopyKey
+                | PendingActionType::CopyKeyReplace
+                | PendingActionType::MoveKeys
+                | PendingActionType::TailStreams
+                | PendingActionType::ConsumeStreams
+        )
+    }
+
+    /// Consumer group administration of the described stream
+    pub fn is_stream_group_action(&self) -> bool {
+        matches!(
+            self,
+            PendingActionType::StreamCreateGroup
+                | PendingActionType::StreamDestroyGroup
+                | PendingActionType::StreamSetGroupId
+                | PendingActionType::StreamDeleteConsumer
+                | PendingActionType::StreamClaim
+                | PendingActionType::StreamAutoClaim
+                | PendingActionType::StreamAck
+        )
+    }
+}
+
+/// Values collected by the field dialog, carried by a pending action
+#[derive(Debug, Clone, Default)]
+pub struct FieldEdit {
+    pub target: String, // Hash field, list index or member the change applies to, or the new key name
+    pub value: String,  // New value, member, score, TTL or database index
 }
 
 pub struct PendingAction {
@@ -38,13 +136,50 @@ pub struct PendingAction {
     pub action_type: PendingActionType,
     pub selected_yes: bool,
     pub matched_keys: Vec<String>,
+    pub field_edit: Option<FieldEdit>,
+}
+
+/// Where the pagination moves once a loading page arrives
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PageMove {
+    Stay,
+    Forward,
+    Back,
+}
+
+/// A key page being loaded by a background task
+pub struct KeyLoad {
+    pub progress: Arc<LoadProgress>,
+    start: (usize, u64),
+    page_move: PageMove,
+    task: tokio::task::JoinHandle<Result<KeyPage>>,
+}
+
+/// Order of the keys table
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum KeySort {
+    #[default]
+    Scan, // As returned by SCAN
+    MemoryDesc,
+    MemoryAsc,
+}
+
+impl KeySort {
+    pub fn next(self) -> Self {
+        match self {
+            KeySort::Scan => KeySort::MemoryDesc,
+            KeySort::MemoryDesc => KeySort::MemoryAsc,
+            KeySort::MemoryAsc => KeySort::Scan,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct PaginationState {
     pub cursor: u64,
-    pub next_cursor: u64,
-    pub cursor_stack: Vec<u64>,
+    pub cursor_stack: Vec<(usize, u64)>, // (node, cursor) of previous pages
+    pub node: usize,                     // Cluster primary being scanned (always 0 otherwise)
+    pub next: Option<(usize, u64)>,      // (node, cursor) of the next page, `None` on the last page
     pub total_keys: u64,
     pub page_size: usize,
 }
@@ -53,14 +188,29 @@ impl Default for PaginationState {
     fn default() -> Self {
         Self {
             cursor: 0,
-            next_cursor: 0,
             cursor_stack: Vec::new(),
+            node: 0,
+            next: None,
             total_keys: 0,
             page_size: 100,
         }
     }
 }
 
+impl PaginationState {
+    /// Go back to the first page
+    pub fn reset(&mut self) {
+        self.cursor = 0;
+        self.node = 0;
+        self.cursor_stack.clear();
+    }
+
+    /// Whether SCAN has more keys, either on the current node or on a following cluster primary
+    pub fn has_next_page(&self) -> bool {
+        self.next.is_some()
+    }
+}
+
 pub struct App {
     pub mode: Mode,
     pub active_resource: String,
@@ -69,8 +219,11 @@ pub struct App {
 
     // Server configuration
     pub tredis_config: TredisConfig,
+    pub decoders: Vec<decoders::Decoder>, // Built from `tredis_config.decoders`
+    pub decoder_error: Option<String>,    // Decoders of the config that could not be built
     pub current_server: Option<ServerConfig>,
     pub server_dialog_state: ServerDialogState,
+    pub new_key_dialog_state: NewKeyDialogState,
     pub selected_server_index: usize,
 
     // Data - Keys
@@ -81,6 +234,26 @@ pub struct App {
     pub pagination: PaginationState,
     pub selected_key_index: usize,
     pub selected_keys: HashSet<String>, // Multi-select: tracks selected key names
+    pub key_sort: KeySort,
+    pub key_load: Option<KeyLoad>, // Page currently loading in the background
+    pub key_message: Option<String>, // Outcome of the last key operation
+    pub key_tree_view: bool,
+    pub key_tree_expanded: HashSet<String>, // Expanded namespace prefixes
+    pub key_tree_scope: Option<String>,     // Prefix the SCAN is limited to
+    pub selected_tree_index: usize,
+
+    // Data - Cluster nodes
+    pub cluster_nodes: Vec<ClusterNode>,
+    pub selected_cluster_node_index: usize,
+    pub focused_node: Option<ClusterNode>, // Node that info/clients/slowlog are scoped to
+    pub focused_node_connection: Option<redis::aio::MultiplexedConnection>,
+
+    // Data - Sentinel
+    pub sentinel_entries: Vec<crate::model::SentinelEntry>,
+    pub selected_sentinel_index: usize,
+    pub sentinel_connection: Option<redis::aio::MultiplexedConnection>,
+    pub sentinel_task: Option<tokio::task::JoinHandle<()>>, // Watches for failovers
+    pub sentinel_reconnect: Option<String>, // Why reconnecting after a failover keeps failing
 
     // Data - Clients
     pub clients: Vec<crate::model::ClientInfo>,
@@ -107,20 +280,41 @@ pub struct App {
     pub selected_acl_index: usize,
 
     // Data - Monitor
-    pub monitor_entries: Vec<crate::model::MonitorEntry>,
+    pub monitor_entries: VecDeque<crate::model::MonitorEntry>, // Newest first
     pub selected_monitor_index: usize,
     pub monitor_scroll: usize,
     pub monitor_active: bool,
     pub monitor_task: Option<tokio::task::JoinHandle<()>>,
+    pub monitor_filter_text: String,
+    pub monitor_filter_active: bool,
+    pub monitor_filter: MonitorFilter,
+    pub monitor_paused: bool,
+    pub monitor_buffer: VecDeque<crate::model::MonitorEntry>, // Entries received while paused
+    pub monitor_stats: MonitorStats,
+    pub monitor_last_seen: Option<(f64, std::time::Instant)>, // Server time of the newest live entry and when it arrived
+    pub monitor_recorder: Option<MonitorRecorder>,
+    pub monitor_replay: Option<std::path::PathBuf>, // Recording opened with `tredis replay`
+
+    // Data - Analyze
+    pub analysis: Option<Arc<Mutex<AnalysisReport>>>,
+    pub analysis_task: Option<tokio::task::JoinHandle<()>>,
+    pub analysis_scroll: usize,
+    pub analysis_export: Option<std::path::PathBuf>, // Where the last report was written
 
     // Data - Streams
     pub streams: Vec<crate::model::StreamInfo>,
     pub selected_stream_index: usize,
-    pub stream_messages: Vec<crate::model::StreamEntry>,
+    pub selected_streams: HashSet<String>, // Marked to be read together
+    pub stream_messages: Vec<(String, crate::model::StreamEntry)>, // Stream and entry, newest first
     pub stream_scroll: usize,
     pub stream_active: bool,
     pub stream_task: Option<tokio::task::JoinHandle<()>>,
-    pub stream_consumer_group: String,
+    pub stream_acks: Option<tokio::sync::mpsc::UnboundedSender<(String, String)>>, // Stream and ID to XACK once shown, group mode only
+    pub stream_consumer_group: String, // Default group and consumer name of group mode
+    pub stream_read_mode: Option<StreamReadMode>,
+    pub stream_read_streams: Vec<String>,
+    pub stream_read_error: Option<String>, // Why the reader stopped
+    pub stream_generation: u64, // Bumped per reader, so messages of stopped readers are dropped
 
     // Data - PubSub
     pub pubsub_channels: Vec<crate::model::PubSubChannel>,
@@ -142,7 +336,25 @@ pub struct App {
 
     // Describe Data
     pub describe_data: KeyValue,
+    pub describe_key: Option<String>, // Key the describe data was loaded from
     pub describe_scroll: usize,
+    pub describe_selected: usize,         // Row in collection tables
+    pub describe_message: Option<String>, // Error of the last edit
+    pub describe_field_ttls: HashMap<String, i64>, // Hash field TTLs in seconds (Redis 7.4+)
+    pub describe_window: Option<DescribeWindow>, // Windowed loading of collections
+    pub describe_viewer: ValueViewer,     // How raw string values are shown
+    pub describe_view: String,            // Raw value rendered by `describe_viewer`
+    pub stream_details: Option<StreamDetails>, // Groups and consumers of a described stream
+
+    // Describe search
+    pub describe_search_active: bool,
+    pub describe_search_text: String,
+    pub describe_search_matches: Vec<usize>, // Row indices (line indices for strings) that match
+    pub describe_search_current: usize,      // Current match index
+    pub describe_search_results: Option<KeyValue>, // Server-side search hits, shown instead of the loaded rows
+    pub describe_search_truncated: bool, // The server-side search stopped at `SEARCH_LIMIT`
+    pub value_editor: Option<ValueEditor>,
+    pub field_dialog: Option<FieldDialogState>,
 
     // Confirm Action
     pub pending_action: Option<PendingAction>,
@@ -151,6 +363,8 @@ pub struct App {
     // Redis
     pub client: Option<redis::Client>,
     pub connection: Option<redis::aio::MultiplexedConnection>,
+    pub connection_uri: Option<String>, // URI the main connection was opened with
+    pub cluster: Option<ClusterConnections>, // Set when connected to a Redis Cluster
 }
 
 impl App {
@@ -166,6 +380,21 @@ impl App {
                 command: "keys".to_string(),
                 description: "Browse all keys".to_string(),
             },
+            ResourceItem {
+                name: "Nodes".to_string(),
+                command: "nodes".to_string(),
+                description: "Cluster nodes and slots".to_string(),
+            },
+            ResourceItem {
+                name: "Sentinel".to_string(),
+                command: "sentinel".to_string(),
+                description: "Sentinel masters, replicas and sentinels".to_string(),
+            },
+            ResourceItem {
+                name: "Analyze".to_string(),
+                command: "analyze".to_string(),
+                description: "Keyspace report: big keys, types, TTLs, prefixes".to_string(),
+            },
             ResourceItem {
                 name: "Streams".to_string(),
                 command: "streams".to_string(),
@@ -210,6 +439,15 @@ impl App {
 
         // Load existing config
         let tredis_config = TredisConfig::load();
+        let (decoders, decoder_errors) = decoders::load(&tredis_config.decoders);
+        for error in &decoder_errors {
+            crate::log!(crate::LogLevel::Error, "[CONFIG] {}", error);
+        }
+        let decoder_error = match decoder_errors.as_slice() {
+            [] => None,
+            [error] => Some(error.clone()),
+            [error, rest @ ..] => Some(format!("{} (and {} more)", error, rest.len())),
+        };
 
         Self {
             mode: Mode::Splash,
@@ -217,8 +455,11 @@ impl App {
             splash_state: SplashState::new(),
             connection_config: ConnectionConfig::default(),
             tredis_config,
+            decoders,
+            decoder_error,
             current_server: None,
             server_dialog_state: ServerDialogState::new(),
+            new_key_dialog_state: NewKeyDialogState::new(),
             selected_server_index: 0,
             all_keys: Vec::new(),
             scan_result: Vec::new(),
@@ -227,6 +468,22 @@ impl App {
             pagination: PaginationState::default(),
             selected_key_index: 0,
             selected_keys: HashSet::new(),
+            key_sort: KeySort::default(),
+            key_load: None,
+            key_message: None,
+            key_tree_view: false,
+            key_tree_expanded: HashSet::new(),
+            key_tree_scope: None,
+            selected_tree_index: 0,
+            cluster_nodes: Vec::new(),
+            selected_cluster_node_index: 0,
+            focused_node: None,
+            focused_node_connection: None,
+            sentinel_entries: Vec::new(),
+            selected_sentinel_index: 0,
+            sentinel_connection: None,
+            sentinel_task: None,
+            sentinel_reconnect: None,
             clients: Vec::new(),
             selected_client_index: 0,
             info_data: Vec::new(),
@@ -241,17 +498,36 @@ impl App {
             selected_config_index: 0,
             acls: Vec::new(),
             selected_acl_index: 0,
-            monitor_entries: Vec::new(),
+            monitor_entries: VecDeque::new(),
             selected_monitor_index: 0,
             monitor_scroll: 0,
             monitor_active: false,
             monitor_task: None,
+            monitor_filter_text: String::new(),
+            monitor_filter_active: false,
+            monitor_filter: MonitorFilter::default(),
+            monitor_paused: false,
+            monitor_buffer: VecDeque::new(),
+            monitor_stats: MonitorStats::default(),
+            monitor_last_seen: None,
+            monitor_recorder: None,
+            monitor_replay: None,
             streams: Vec::new(),
             selected_stream_index: 0,
+            selected_streams: HashSet::new(),
             stream_messages: Vec::new(),
             stream_scroll: 0,
             stream_active: false,
+            stream_read_mode: None,
+            stream_read_streams: Vec::new(),
+            stream_read_error: None,
+            stream_generation: 0,
+            analysis: None,
+            analysis_task: None,
+            analysis_scroll: 0,
+            analysis_export: None,
             stream_task: None,
+            stream_acks: None,
             stream_consumer_group: {
                 let hostname = hostname::get()
                     .ok()
@@ -273,11 +549,29 @@ impl App {
             command_suggestion_selected: 0,
             command_preview: None,
             describe_data: KeyValue::None,
+            describe_key: None,
             describe_scroll: 0,
+            describe_selected: 0,
+            describe_message: None,
+            describe_field_ttls: HashMap::new(),
+            describe_window: None,
+            describe_viewer: ValueViewer::default(),
+            describe_view: String::new(),
+            stream_details: None,
+            describe_search_active: false,
+            describe_search_text: String::new(),
+            describe_search_matches: Vec::new(),
+            describe_search_current: 0,
+            describe_search_results: None,
+            describe_search_truncated: false,
+            value_editor: None,
+            field_dialog: None,
             pending_action: None,
             last_key_press: None,
             client: None,
             connection: None,
+            connection_uri: None,
+            cluster: None,
         }
     }
 
@@ -311,6 +605,7 @@ impl App {
             name,
             uri,
             info: None,
+            sentinel: None,
         });
 
         Ok(())
@@ -379,14 +674,180 @@ impl App {
 
     /// Get the current server name for display in header
     pub fn current_server_name(&self) -> &str {
+        if self.monitor_replay.is_some() {
+            return "Replay (offline)";
+        }
         self.current_server
             .as_ref()
             .map(|s| s.name.as_str())
             .unwrap_or("No Server")
     }
 
-    pub async fn fetch_clients(&mut self) -> Result<()> {
+    /// Connection for server-level views: the focused cluster node if any, the main connection otherwise
+    pub fn node_connection(&mut self) -> Option<&mut redis::aio::MultiplexedConnection> {
+        if self.focused_node_connection.is_some() {
+            self.focused_node_connection.as_mut()
+        } else {
+            self.connection.as_mut()
+        }
+    }
+
+    pub async fn fetch_cluster_nodes(&mut self) -> Result<()> {
         if let Some(con) = &mut self.connection {
+            let nodes_str: String = redis::cmd("CLUSTER").arg("NODES").query_async(con).await?;
+            let nodes = cluster::parse_cluster_nodes(&nodes_str);
+            self.cluster_nodes = cluster::order_nodes(nodes);
+            if self.selected_cluster_node_index >= self.cluster_nodes.len() {
+                self.selected_cluster_node_index = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Address of the primary a replica follows
+    pub fn cluster_node_primary_addr(&self, node: &ClusterNode) -> Option<&str> {
+        self.cluster_nodes
+            .iter()
+            .find(|n| n.id == node.master_id)
+            .map(|n| n.addr.as_str())
+    }
+
+    /// Scope info/clients/slowlog to the selected cluster node
+    pub async fn focus_selected_cluster_node(&mut self) -> Result<()> {
+        use std::time::Duration;
+        use tokio::time::timeout;
+
+        let Some(node) = self
+            .cluster_nodes
+            .get(self.selected_cluster_node_index)
+            .cloned()
+        else {
+            return Ok(());
+        };
+        let Some(base_uri) = self.current_server.as_ref().map(|s| s.uri.clone()) else {
+            return Ok(());
+        };
+
+        let client = redis::Client::open(connection::node_uri(&base_uri, &node.host, node.port))?;
+        let connection = timeout(
+            Duration::from_secs(30),
+            client.get_multiplexed_async_connection(),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Connection timed out after 30 seconds"))??;
+
+        self.focused_node = Some(node);
+        self.focused_node_connection = Some(connection);
+        Ok(())
+    }
+
+    /// Switch to info, clients or slowlog for the selected cluster node
+    pub async fn open_node_view(&mut self, resource: &str) -> Result<()> {
+        self.focus_selected_cluster_node().await?;
+        self.active_resource = resource.to_string();
+        match resource {
+            "info" => {
+                self.clear_info_search();
+                self.info_scroll = 0;
+                self.fetch_info().await
+            }
+            "clients" => {
+                self.selected_client_index = 0;
+                self.fetch_clients().await
+            }
+            "slowlog" => {
+                self.selected_slowlog_index = 0;
+                self.fetch_slowlog().await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Fetch masters, replicas and sentinels, either from the configured sentinel
+    /// group or from the server itself when it is a sentinel
+    pub async fn fetch_sentinel_entries(&mut self) -> Result<()> {
+        let con = if self.sentinel_connection.is_some() {
+            self.sentinel_connection.as_mut()
+        } else {
+            self.connection.as_mut()
+        };
+        if let Some(con) = con {
+            let entries = sentinel::fetch_entries(con).await?;
+            self.sentinel_entries = entries;
+            if self.selected_sentinel_index >= self.sentinel_entries.len() {
+                self.selected_sentinel_index = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Scan the whole keyspace in the background, replacing any previous report
+    pub fn start_analysis(&mut self) {
+        self.stop_analysis();
+
+        let connections: Vec<_> = match &self.cluster {
+            Some(cluster) => cluster
+                .primaries
+                .iter()
+                .map(|p| p.connection.clone())
+                .collect(),
+            None => self.connection.iter().cloned().collect(),
+        };
+        if connections.is_empty() {
+            return;
+        }
+
+        let report = Arc::new(Mutex::new(AnalysisReport {
+            started_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            ..Default::default()
+        }));
+        self.analysis = Some(report.clone());
+        self.analysis_scroll = 0;
+        self.analysis_export = None;
+        self.analysis_task = Some(tokio::spawn(analyze::run(
+            connections,
+            self.tredis_config.key_separator().to_string(),
+            report,
+        )));
+    }
+
+    /// Cancel a running analysis, keeping what was collected so far
+    pub fn stop_analysis(&mut self) {
+        if let Some(task) = self.analysis_task.take() {
+            task.abort();
+        }
+    }
+
+    pub fn analysis_running(&self) -> bool {
+        self.analysis_task
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+    }
+
+    pub fn export_analysis(&mut self) -> Result<std::path::PathBuf> {
+        let path = match &self.analysis {
+            Some(report) => report.lock().unwrap().export()?,
+            None => return Err(anyhow::anyhow!("No analysis to export")),
+        };
+        self.analysis_export = Some(path.clone());
+        Ok(path)
+    }
+
+    pub fn stop_sentinel_watch(&mut self) {
+        if let Some(task) = self.sentinel_task.take() {
+            task.abort();
+        }
+        self.sentinel_reconnect = None;
+    }
+
+    /// Go back to running server-level views against the main connection
+    pub fn clear_node_focus(&mut self) {
+        self.focused_node = None;
+        self.focused_node_connection = None;
+    }
+
+    pub async fn fetch_clients(&mut self) -> Result<()> {
+        if let Some(con) = self.node_connection() {
             let client_list: String = redis::cmd("CLIENT").arg("LIST").query_async(con).await?;
             let mut clients = Vec::new();
 
@@ -425,10 +886,21 @@ impl App {
     }
 
     pub fn go_to_top(&mut self) {
+        if self.key_tree_view {
+            self.selected_tree_index = 0;
+            self.sync_tree_selection(&self.namespace_rows());
+            return;
+        }
         self.selected_key_index = 0;
     }
 
     pub fn go_to_bottom(&mut self) {
+        if self.key_tree_view {
+            let rows = self.namespace_rows();
+            self.selected_tree_index = rows.len().saturating_sub(1);
+            self.sync_tree_selection(&rows);
+            return;
+        }
         if !self.scan_result.is_empty() {
             self.selected_key_index = self.scan_result.len() - 1;
         }
@@ -436,14 +908,16 @@ impl App {
 
     pub fn describe_go_to_top(&mut self) {
         self.describe_scroll = 0;
+        self.describe_selected = 0;
     }
 
     pub fn describe_go_to_bottom(&mut self, _visible_lines: usize) {
         self.describe_scroll = 999999;
+        self.describe_selected = self.collection_rows().len().saturating_sub(1);
     }
 
     pub async fn fetch_info(&mut self) -> Result<()> {
-        if let Some(con) = &mut self.connection {
+        if let Some(con) = self.node_connection() {
             let info: String = redis::cmd("INFO").query_async(con).await?;
             let mut info_data = Vec::new();
             for line in info.lines() {
@@ -462,7 +936,7 @@ impl App {
     }
 
     pub async fn fetch_slowlog(&mut self) -> Result<()> {
-        if let Some(con) = &mut self.connection {
+        if let Some(con) = self.node_connection() {
             let raw_logs: Vec<(i64, i64, i64, Vec<String>)> = redis::cmd("SLOWLOG")
                 .arg("GET")
                 .arg(100)
@@ -523,16 +997,10 @@ impl App {
         Ok(())
     }
 
-    pub async fn connect(&mut self) -> Result<()> {
-        use std::time::Duration;
-        use tokio::time::timeout;
-
-        // Close existing connection first (should already be closed, but just in case)
-        drop(self.connection.take());
-        drop(self.client.take());
-
-        // Use the original URI from current_server if available (preserves auth, TLS, etc.)
-        let url = if let Some(ref server) = self.current_server {
+    /// URI for the current server. Uses the original URI from current_server if
+    /// available (preserves auth, TLS, etc.), otherwise builds one from connection_config
+    fn connection_url(&self) -> String {
+        if let Some(ref server) = self.current_server {
             server.uri.clone()
         } else {
             // Fallback: Build URL from connection config
@@ -572,23 +1040,153 @@ impl App {
                     self.connection_config.db
                 )
             }
+        }
+    }
+
+    /// Factory for background task connections, matching the main connection
+    pub fn connection_factory(&self) -> ConnectionFactory {
+        ConnectionFactory::new(
+            self.connection_uri
+                .clone()
+                .unwrap_or_else(|| self.connection_url()),
+        )
+    }
+
+    pub async fn connect(&mut self) -> Result<()> {
+        use std::time::Duration;
+        use tokio::time::timeout;
+
+        // Close existing connection first (should already be closed, but just in case)
+        self.cancel_key_load();
+        self.stop_analysis();
+        // Invalid decoders are reported until the first key operation
+        self.key_message = self.decoder_error.clone();
+        drop(self.connection.take());
+        drop(self.client.take());
+        drop(self.cluster.take());
+        drop(self.sentinel_connection.take());
+        self.connection_uri = None;
+        self.clear_node_focus();
+        self.cluster_nodes.clear();
+
+        let url = self.connection_url();
+
+        // Sentinel groups: connect to whichever node the sentinels currently report as master
+        let sentinel_config = self
+            .current_server
+            .as_ref()
+            .and_then(|s| s.sentinel.clone());
+        let mut sentinel_master = None;
+        let url = if let Some(ref sentinel_config) = sentinel_config {
+            let (host, port) = timeout(
+                Duration::from_secs(30),
+                sentinel::resolve_master(sentinel_config, &url),
+            )
+            .await
+            .map_err(|_| anyhow::anyhow!("Sentinel lookup timed out after 30 seconds"))??;
+            let master_url = connection::node_uri(&url, &host, port);
+            self.set_connection_from_uri(&master_url)?;
+            sentinel_master = Some(format!("{}:{}", host, port));
+            master_url
+        } else {
+            url
         };
 
-        let client = redis::Client::open(url)?;
+        let client = redis::Client::open(url.as_str())?;
 
         // Use timeout for connection (30 seconds for TLS connections which can be slow)
-        let connection = timeout(
+        let mut connection = timeout(
             Duration::from_secs(30),
             client.get_multiplexed_async_connection(),
         )
         .await
         .map_err(|_| anyhow::anyhow!("Connection timed out after 30 seconds"))??;
 
+        if let Some(addr) = sentinel_master {
+            if !sentinel::is_master(&mut connection).await? {
+                anyhow::bail!(
+                    "Sentinels report {} as master, but it is a replica (failover in progress?), try again",
+                    addr
+                );
+            }
+        }
+
+        // In cluster mode, open a connection to every primary so keys can be scanned and routed
+        let cluster = timeout(
+            Duration::from_secs(30),
+            ClusterConnections::discover(&mut connection, &url),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Cluster discovery timed out after 30 seconds"))??;
+
+        if let Some(ref sentinel_config) = sentinel_config {
+            let base_uri = self
+                .current_server
+                .as_ref()
+                .map(|s| s.uri.clone())
+                .unwrap_or_default();
+            self.sentinel_connection = sentinel::connect(sentinel_config, &base_uri).await.ok();
+        }
+
         self.client = Some(client);
         self.connection = Some(connection);
+        self.connection_uri = Some(url);
+        self.cluster = cluster;
+        self.pagination = PaginationState::default();
         Ok(())
     }
 
+    /// Connection that should receive commands for `key`: the owning primary in
+    /// cluster mode, the main connection otherwise
+    pub fn connection_for_key(
+        &mut self,
+        key: &str,
+    ) -> Option<&mut redis::aio::MultiplexedConnection> {
+        match &mut self.cluster {
+            Some(cluster) => cluster.connection_for_key(key),
+            None => self.connection.as_mut(),
+        }
+    }
+
+    /// Reload the cluster slot map when `error` is a MOVED redirect, so that the next
+    /// command for the moved slot goes to its new owner. Returns whether the caller
+    /// should retry. ASK redirects are not retried: the slot is still being migrated
+    /// and the source node keeps owning it until the migration finishes.
+    pub async fn handle_cluster_redirect(&mut self, error: &anyhow::Error) -> bool {
+        let moved = error
+            .chain()
+            .filter_map(|e| e.downcast_ref::<redis::RedisError>())
+            .any(is_moved);
+        moved && self.reload_cluster_map().await
+    }
+
+    /// Reload the cluster slot map, returning whether it worked
+    async fn reload_cluster_map(&mut self) -> bool {
+        let (Some(cluster), Some(base_uri)) =
+            (self.cluster.as_mut(), self.connection_uri.as_deref())
+        else {
+            return false;
+        };
+        match cluster.refresh(base_uri).await {
+            Ok(()) => {
+                crate::log!(
+                    crate::LogLevel::Info,
+                    "Slot moved, reloaded cluster map: {} primaries",
+                    cluster.primaries.len()
+                );
+                true
+            }
+            Err(e) => {
+                crate::log!(
+                    crate::LogLevel::Error,
+                    "Failed to reload cluster map: {}",
+                    e
+                );
+                false
+            }
+        }
+    }
+
     /// Detect server type and info by connecting and running INFO/CLUSTER commands
     pub async fn detect_server_info(uri: &str) -> Result<ServerInfo> {
         use std::time::Duration;
@@ -613,10 +1211,8 @@ impl App {
                     "redis_version" => info.redis_version = val.to_string(),
                     "os" => info.os = val.to_string(),
                     "role" => info.role = val.to_string(),
-                    "redis_mode" => {
-                        if val == "sentinel" {
-                            info.server_type = ServerType::Sentinel;
-                        }
+                    "redis_mode" if val == "sentinel" => {
+                        info.server_type = ServerType::Sentinel;
                     }
                     _ => {}
                 }
@@ -654,10 +1250,8 @@ impl App {
             for line in cluster_str.lines() {
                 if let Some((key, val)) = line.split_once(':') {
                     match key {
-                        "cluster_state" => {
-                            if val == "ok" {
-                                cluster_enabled = true;
-                            }
+                        "cluster_state" if val == "ok" => {
+                            cluster_enabled = true;
                         }
                         "cluster_size" => {
                             cluster_size = val.parse().unwrap_or(0);
@@ -700,87 +1294,279 @@ impl App {
         Ok(())
     }
 
+    /// Reload the current page and wait until it is shown, for callers that carry on
+    /// with the refreshed key list
     pub async fn fetch_keys(&mut self, pattern: Option<String>) -> Result<()> {
-        if let Some(con) = &mut self.connection {
-            let total: u64 = redis::cmd("DBSIZE").query_async(con).await.unwrap_or(0);
-            self.pagination.total_keys = total;
+        self.start_key_load(pattern).await?;
+        match self.key_load.take() {
+            Some(load) => self.finish_key_load(load).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Start reloading the current page in the background and return right away;
+    /// `poll_key_load` shows the page once it has loaded
+    pub async fn start_key_load(&mut self, pattern: Option<String>) -> Result<()> {
+        let start = (self.pagination.node, self.pagination.cursor);
+        self.load_key_page(pattern, start, PageMove::Stay).await
+    }
 
-            let mut cmd = redis::cmd("SCAN");
-            cmd.arg(self.pagination.cursor);
+    /// Start loading the page beginning at `start` in the background, see `poll_key_load`
+    async fn load_key_page(
+        &mut self,
+        pattern: Option<String>,
+        start: (usize, u64),
+        page_move: PageMove,
+    ) -> Result<()> {
+        // In cluster mode, SCAN walks the primaries one after another
+        let connections = match &self.cluster {
+            Some(cluster) => cluster
+                .primaries
+                .iter()
+                .map(|p| p.connection.clone())
+                .collect(),
+            None => match &self.connection {
+                Some(con) => vec![con.clone()],
+                None => return Ok(()),
+            },
+        };
 
-            if let Some(p) = &pattern {
-                cmd.arg("MATCH").arg(format!("*{}*", p));
+        let mut filter = KeyFilter::parse(pattern.as_deref().unwrap_or_default());
+        if self.key_tree_view {
+            if let Some(scope) = &self.key_tree_scope {
+                let separator = self.tredis_config.key_separator();
+                filter.scope = Some(format!("{}{}", scope, separator));
             }
+        }
 
-            cmd.arg("COUNT").arg(self.pagination.page_size);
+        self.cancel_key_load();
+        let progress = Arc::new(LoadProgress::default());
+        let task = tokio::spawn(keys::load_page(
+            connections,
+            start,
+            filter,
+            self.pagination.page_size,
+            progress.clone(),
+        ));
+        self.key_load = Some(KeyLoad {
+            progress,
+            start,
+            page_move,
+            task,
+        });
+        Ok(())
+    }
 
-            let (next_cursor, keys): (u64, Vec<String>) = cmd.query_async(con).await?;
-            self.pagination.next_cursor = next_cursor;
+    /// Apply the key page once its background load has finished
+    pub async fn poll_key_load(&mut self) -> Result<()> {
+        if !self
+            .key_load
+            .as_ref()
+            .is_some_and(|load| load.task.is_finished())
+        {
+            return Ok(());
+        }
+        let Some(load) = self.key_load.take() else {
+            return Ok(());
+        };
+        self.finish_key_load(load).await
+    }
 
-            let mut key_infos = Vec::new();
-            for key in keys {
-                let key_type: String = con.key_type(&key).await.unwrap_or("unknown".to_string());
-                let ttl: i64 = con.ttl(&key).await.unwrap_or(-1);
-                let memory = 0;
-
-                key_infos.push(KeyInfo {
-                    key,
-                    key_type,
-                    ttl,
-                    memory_usage: memory,
-                });
+    async fn finish_key_load(&mut self, load: KeyLoad) -> Result<()> {
+        let page = load.task.await??;
+
+        // Pagination only moves once the page has actually loaded, so a cancelled load keeps the current page
+        match load.page_move {
+            PageMove::Stay => {}
+            PageMove::Forward => self
+                .pagination
+                .cursor_stack
+                .push((self.pagination.node, self.pagination.cursor)),
+            PageMove::Back => {
+                self.pagination.cursor_stack.pop();
             }
-
-            self.all_keys = key_infos;
-            self.apply_filter();
         }
+        if load.page_move != PageMove::Stay {
+            self.selected_keys.clear(); // Clear selection when changing pages
+        }
+        (self.pagination.node, self.pagination.cursor) = load.start;
+        self.pagination.next = page.next;
+        self.pagination.total_keys = page.total_keys;
+
+        self.all_keys = page.keys;
+        self.apply_filter();
         Ok(())
     }
 
-    pub async fn next_page(&mut self) -> Result<()> {
-        if self.pagination.next_cursor != 0 {
-            self.pagination.cursor_stack.push(self.pagination.cursor);
-            self.pagination.cursor = self.pagination.next_cursor;
-            self.selected_keys.clear(); // Clear selection when changing pages
+    pub fn cancel_key_load(&mut self) {
+        if let Some(load) = self.key_load.take() {
+            load.task.abort();
+        }
+    }
 
-            let pattern = if self.filter_text.is_empty() {
-                None
-            } else {
-                Some(self.filter_text.clone())
-            };
-            self.fetch_keys(pattern).await?;
+    pub async fn next_page(&mut self) -> Result<()> {
+        if let Some(next) = self.pagination.next {
+            let pattern = self.key_filter_pattern();
+            self.load_key_page(pattern, next, PageMove::Forward).await?;
         }
         Ok(())
     }
 
     pub async fn prev_page(&mut self) -> Result<()> {
-        if let Some(prev_cursor) = self.pagination.cursor_stack.pop() {
-            self.pagination.cursor = prev_cursor;
-            self.selected_keys.clear(); // Clear selection when changing pages
+        if let Some(&prev) = self.pagination.cursor_stack.last() {
+            let pattern = self.key_filter_pattern();
+            self.load_key_page(pattern, prev, PageMove::Back).await?;
+        }
+        Ok(())
+    }
 
-            let pattern = if self.filter_text.is_empty() {
-                None
-            } else {
-                Some(self.filter_text.clone())
-            };
-            self.fetch_keys(pattern).await?;
+    pub fn key_filter_pattern(&self) -> Option<String> {
+        if self.filter_text.is_empty() {
+            None
+        } else {
+            Some(self.filter_text.clone())
+        }
+    }
+
+    /// Rows of the namespace tree for the keys on the current page
+    pub fn namespace_rows(&self) -> Vec<NamespaceRow> {
+        keys::build_namespace_tree(
+            &self.scan_result,
+            self.tredis_config.key_separator(),
+            &self.key_tree_expanded,
+            self.key_sort != KeySort::Scan,
+        )
+    }
+
+    /// Switch the keys resource between the flat list and the namespace tree
+    pub async fn toggle_key_tree_view(&mut self) -> Result<()> {
+        self.key_tree_view = !self.key_tree_view;
+        self.key_tree_expanded.clear();
+        self.selected_tree_index = 0;
+        if self.key_tree_scope.take().is_some() {
+            self.pagination.reset();
+            let pattern = self.key_filter_pattern();
+            self.start_key_load(pattern).await?;
+        }
+        Ok(())
+    }
+
+    pub fn tree_next(&mut self) {
+        let rows = self.namespace_rows();
+        if self.selected_tree_index + 1 < rows.len() {
+            self.selected_tree_index += 1;
+        }
+        self.sync_tree_selection(&rows);
+    }
+
+    pub fn tree_previous(&mut self) {
+        let rows = self.namespace_rows();
+        self.selected_tree_index = self
+            .selected_tree_index
+            .saturating_sub(1)
+            .min(rows.len().saturating_sub(1));
+        self.sync_tree_selection(&rows);
+    }
+
+    /// Keep the key selection (used by describe and delete) on the highlighted leaf
+    fn sync_tree_selection(&mut self, rows: &[NamespaceRow]) {
+        if let Some(index) = rows
+            .get(self.selected_tree_index)
+            .and_then(|row| row.key_index)
+        {
+            self.selected_key_index = index;
+        }
+    }
+
+    /// Whether the highlighted tree row is a single key rather than a group
+    pub fn tree_key_selected(&self) -> bool {
+        self.namespace_rows()
+            .get(self.selected_tree_index)
+            .is_some_and(|row| row.key_index.is_some())
+    }
+
+    /// Expand or collapse the highlighted group. Expanding scopes SCAN to the prefix.
+    pub async fn toggle_tree_node(&mut self) -> Result<()> {
+        let rows = self.namespace_rows();
+        let Some(row) = rows.get(self.selected_tree_index) else {
+            return Ok(());
+        };
+        if row.key_index.is_some() {
+            return Ok(());
+        }
+
+        if row.expanded {
+            let (path, depth) = (row.path.clone(), row.depth);
+            self.collapse_tree_prefix(&path, depth).await
+        } else {
+            self.key_tree_expanded.insert(row.path.clone());
+            self.key_tree_scope = Some(row.path.clone());
+            // Once the scoped page loads, the group sits below its ancestors
+            self.selected_tree_index = row.depth;
+            self.pagination.reset();
+            let pattern = self.key_filter_pattern();
+            self.start_key_load(pattern).await
+        }
+    }
+
+    /// Collapse the highlighted group, or the group containing the highlighted row
+    pub async fn collapse_tree_node(&mut self) -> Result<()> {
+        let rows = self.namespace_rows();
+        let Some(row) = rows.get(self.selected_tree_index) else {
+            return Ok(());
+        };
+
+        if row.key_index.is_none() && row.expanded {
+            let (path, depth) = (row.path.clone(), row.depth);
+            return self.collapse_tree_prefix(&path, depth).await;
+        }
+        let separator = self.tredis_config.key_separator();
+        if let Some(parent) = keys::parent_prefix(&row.path, separator, row.depth) {
+            self.collapse_tree_prefix(&parent, row.depth - 1).await?;
         }
         Ok(())
     }
 
+    async fn collapse_tree_prefix(&mut self, path: &str, depth: usize) -> Result<()> {
+        let separator = self.tredis_config.key_separator().to_string();
+        let nested = format!("{}{}", path, separator);
+        self.key_tree_expanded
+            .retain(|p| p != path && !p.starts_with(&nested));
+
+        // Widen the SCAN to the closest expanded ancestor
+        self.key_tree_scope = self
+            .key_tree_expanded
+            .iter()
+            .filter(|p| path.starts_with(&format!("{}{}", p, separator)))
+            .max_by_key(|p| p.len())
+            .cloned();
+        self.selected_tree_index = depth;
+        self.pagination.reset();
+        let pattern = self.key_filter_pattern();
+        self.start_key_load(pattern).await
+    }
+
     pub fn apply_filter(&mut self) {
-        if self.filter_text.is_empty() {
+        let filter = KeyFilter::parse(&self.filter_text);
+        if filter.is_empty() {
             self.scan_result = self.all_keys.clone();
         } else {
-            let filter = self.filter_text.to_lowercase();
             self.scan_result = self
                 .all_keys
                 .iter()
-                .filter(|k| k.key.to_lowercase().contains(&filter))
+                .filter(|k| filter.matches(k))
                 .cloned()
                 .collect();
         }
 
+        match self.key_sort {
+            KeySort::Scan => {}
+            KeySort::MemoryDesc => self
+                .scan_result
+                .sort_by_key(|k| std::cmp::Reverse(k.memory_usage)),
+            KeySort::MemoryAsc => self.scan_result.sort_by_key(|k| k.memory_usage),
+        }
+
         // Clear selection when filter changes - only keep selections that are still visible
         self.selected_keys
             .retain(|key| self.scan_result.iter().any(|k| &k.key == key));
@@ -795,168 +1581,1765 @@ impl App {
     }
 
     pub async fn delete_key(&mut self) -> Result<()> {
-        if let Some(pending) = &self.pending_action {
-            if let Some(con) = &mut self.connection {
-                let _: () = con.del(&pending.key).await?;
-            }
+        let Some(key) = self.pending_action.as_ref().map(|p| p.key.clone()) else {
+            return Ok(());
+        };
+        if let Some(con) = self.connection_for_key(&key) {
+            let _: () = con.del(&key).await?;
         }
         Ok(())
     }
 
-    /// Toggle selection of the currently highlighted key
-    pub fn toggle_key_selection(&mut self) {
-        if self.scan_result.is_empty() {
-            return;
-        }
-        let key = &self.scan_result[self.selected_key_index].key;
-        if self.selected_keys.contains(key) {
-            self.selected_keys.remove(key);
-        } else {
-            self.selected_keys.insert(key.clone());
+    /// Create the key from the new key dialog. Returns whether it was created; when the
+    /// key already exists the overwrite is handed to the confirm dialog instead.
+    pub async fn submit_new_key_dialog(&mut self, overwrite: bool) -> bool {
+        let dialog = self.new_key_dialog_state.clone();
+        if !dialog.is_valid() {
+            self.new_key_dialog_state
+                .form
+                .set_error("Key name is required".to_string());
+            return false;
         }
-    }
 
-    /// Select all currently filtered/visible keys
-    pub fn select_all_keys(&mut self) {
+        match self.create_key(&dialog, overwrite).await {
+            Ok(true) => {
+                crate::log!(
+                    crate::LogLevel::Info,
+                    "Created {} key {}",
+                    dialog.key_type(),
+                    dialog.name()
+                );
+                self.mode = Mode::Normal;
+                true
+            }
+            Ok(false) => {
+                self.pending_action = Some(PendingAction {
+                    key: dialog.name().to_string(),
+                    action_type: PendingActionType::OverwriteKey,
+                    selected_yes: false,
+                    matched_keys: Vec::new(),
+                    field_edit: None,
+                });
+                self.mode = Mode::Confirm;
+                false
+            }
+            Err(e) => {
+                self.new_key_dialog_state.form.set_error(e.to_string());
+                self.mode = Mode::NewKeyDialog;
+                false
+            }
+        }
+    }
+
+    /// Create a key in one transaction, including its TTL. Unless `overwrite` is set,
+    /// the key is checked under WATCH and `false` is returned if it already exists.
+    async fn create_key(&self, dialog: &NewKeyDialogState, overwrite: bool) -> Result<bool> {
+        let key = dialog.name();
+        let ttl = dialog.ttl()?;
+        let create = keys::create_command(key, dialog.key_type(), dialog.contents())?;
+
+        let mut con = self.dedicated_connection_for_key(key).await?;
+        if !overwrite {
+            redis::cmd("WATCH")
+                .arg(key)
+                .query_async::<()>(&mut con)
+                .await?;
+            let exists: bool = con.exists(key).await?;
+            if exists {
+                redis::cmd("UNWATCH").query_async::<()>(&mut con).await?;
+                return Ok(false);
+            }
+        }
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        if overwrite {
+            pipe.cmd("DEL").arg(key).ignore();
+        }
+        pipe.add_command(create).ignore();
+        if let Some(ttl) = ttl {
+            pipe.add_command(ttl.key_command(key)).ignore();
+        }
+
+        // A nil EXEC means the key was created by someone else after the check
+        let result: Option<()> = pipe.query_async(&mut con).await?;
+        Ok(result.is_some())
+    }
+
+    /// Toggle selection of the currently highlighted key
+    pub fn toggle_key_selection(&mut self) {
+        if self.scan_result.is_empty() {
+            return;
+        }
+        let key = &self.scan_result[self.selected_key_index].key;
+        if self.selected_keys.contains(key) {
+            self.selected_keys.remove(key);
+        } else {
+            self.selected_keys.insert(key.clone());
+        }
+    }
+
+    /// Select all currently filtered/visible keys
+    pub fn select_all_keys(&mut self) {
         for key_info in &self.scan_result {
             self.selected_keys.insert(key_info.key.clone());
         }
     }
 
-    /// Clear all key selections
-    pub fn clear_key_selection(&mut self) {
-        self.selected_keys.clear();
+    /// Clear all key selections
+    pub fn clear_key_selection(&mut self) {
+        self.selected_keys.clear();
+    }
+
+    /// Delete all selected keys
+    pub async fn delete_selected_keys(&mut self) -> Result<u64> {
+        let mut deleted_count: u64 = 0;
+
+        let keys_to_delete: Vec<String> = self.selected_keys.iter().cloned().collect();
+
+        // Multi-key DEL must not cross slots in a cluster, so group keys by slot first
+        let groups: Vec<Vec<String>> = if self.cluster.is_some() {
+            let mut by_slot: HashMap<u16, Vec<String>> = HashMap::new();
+            for key in &keys_to_delete {
+                by_slot
+                    .entry(cluster::key_slot(key))
+                    .or_default()
+                    .push(key.clone());
+            }
+            by_slot.into_values().collect()
+        } else {
+            vec![keys_to_delete.clone()]
+        };
+
+        let mut failures: Vec<String> = Vec::new();
+        let mut failed_keys: HashSet<String> = HashSet::new();
+        for keys in groups {
+            // Delete in batches to avoid blocking Redis for too long
+            for chunk in keys.chunks(100) {
+                let mut result = self.del_keys(chunk).await;
+                if let Err(e) = &result {
+                    if self.handle_cluster_redirect(e).await {
+                        result = self.del_keys(chunk).await;
+                    }
+                }
+                match result {
+                    Ok(count) => deleted_count += count,
+                    Err(e) => {
+                        for key in chunk {
+                            failures.push(format!("{} ({})", key, e));
+                            failed_keys.insert(key.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        for failure in &failures {
+            crate::log!(crate::LogLevel::Warn, "Delete failed: {}", failure);
+        }
+        if !failures.is_empty() {
+            self.key_message = Some(format!(
+                "Deleted {} of {} keys, failed: {}",
+                deleted_count,
+                keys_to_delete.len(),
+                failures.join(", ")
+            ));
+        }
+
+        // Keep only the keys that could not be deleted selected
+        self.selected_keys.retain(|key| failed_keys.contains(key));
+
+        Ok(deleted_count)
+    }
+
+    /// DEL `keys` on the node that owns them; they must all hash to the same slot in a cluster
+    async fn del_keys(&mut self, keys: &[String]) -> Result<u64> {
+        let Some(first) = keys.first() else {
+            return Ok(0);
+        };
+        let Some(con) = self.connection_for_key(first) else {
+            anyhow::bail!("no primary serves slot {}", cluster::key_slot(first));
+        };
+        Ok(con.del(keys).await?)
+    }
+
+    pub async fn fetch_key_value(&mut self) -> Result<()> {
+        if self.scan_result.is_empty() {
+            return Ok(());
+        }
+
+        let key_info = &self.scan_result[self.selected_key_index];
+        let key = key_info.key.clone();
+        let key_type = key_info.key_type.clone();
+        self.describe_key = Some(key.clone());
+        self.describe_message = None;
+        self.clear_describe_search();
+
+        if key_type == "string" {
+            self.describe_window = None;
+            self.stream_details = None;
+            if let Some(con) = self.connection_for_key(&key) {
+                match con.get::<_, Vec<u8>>(&key).await {
+                    Ok(raw) => {
+                        self.describe_viewer =
+                            if decoders::decoder_for(&self.decoders, &key).is_some() {
+                                ValueViewer::Decoded
+                            } else {
+                                ValueViewer::detect(&raw)
+                            };
+                        self.set_describe_bytes(raw);
+                    }
+                    Err(e) => self.describe_data = KeyValue::Error(e.to_string()),
+                }
+            }
+            return Ok(());
+        }
+
+        self.load_first_window(key, key_type).await
+    }
+
+    /// Start windowed loading of a collection: its size, then the first window
+    async fn load_first_window(&mut self, key: String, key_type: String) -> Result<()> {
+        self.describe_field_ttls.clear();
+        self.describe_window = None;
+        self.stream_details = None;
+
+        if !matches!(
+            key_type.as_str(),
+            "list" | "set" | "zset" | "hash" | "stream"
+        ) {
+            self.describe_data = KeyValue::Error(format!("Unsupported type: {}", key_type));
+            return Ok(());
+        }
+
+        let Some(con) = self.connection_for_key(&key) else {
+            return Ok(());
+        };
+        let mut window = DescribeWindow::new(key, key_type);
+        window.total = values::fetch_total(con, &window.key, &window.key_type).await;
+        let (data, field_ttls) = match fetch_window_with_ttls(con, &mut window).await {
+            Ok((fetched, field_ttls)) => {
+                // Start from an empty value so SCAN duplicates within the window are dropped too
+                let mut data = values::empty_value(&window.key_type);
+                window.append(&mut data, fetched);
+                (data, field_ttls)
+            }
+            Err(e) => (KeyValue::Error(e.to_string()), HashMap::new()),
+        };
+        self.stream_details = if window.key_type == "stream" {
+            Some(streams::fetch_details(con, &window.key).await)
+        } else {
+            None
+        };
+
+        self.describe_data = data;
+        self.describe_field_ttls = field_ttls;
+        self.describe_window = Some(window);
+        Ok(())
+    }
+
+    /// Fetch the next window once the selection gets close to the end of the loaded rows
+    pub async fn describe_load_more(&mut self) -> Result<()> {
+        if self.describe_search_results.is_some() {
+            return Ok(());
+        }
+        let loaded = self.collection_rows().len();
+        if self.describe_selected + values::PREFETCH_MARGIN < loaded {
+            return Ok(());
+        }
+        self.load_next_window().await
+    }
+
+    async fn load_next_window(&mut self) -> Result<()> {
+        let Some(mut window) = self.describe_window.take() else {
+            return Ok(());
+        };
+        if window.is_done() {
+            self.describe_window = Some(window);
+            return Ok(());
+        }
+
+        let result = match self.connection_for_key(&window.key) {
+            Some(con) => fetch_window_with_ttls(con, &mut window).await,
+            None => Err(anyhow::anyhow!("Not connected")),
+        };
+        let appended = result.map(|(data, field_ttls)| {
+            window.append(&mut self.describe_data, data);
+            self.describe_field_ttls.extend(field_ttls);
+        });
+        self.describe_window = Some(window);
+        appended
+    }
+
+    /// Show a raw string value with the current viewer
+    fn set_describe_bytes(&mut self, raw: Vec<u8>) {
+        self.describe_data = KeyValue::Bytes(raw);
+        self.render_describe_view();
+    }
+
+    fn render_describe_view(&mut self) {
+        let KeyValue::Bytes(raw) = &self.describe_data else {
+            return;
+        };
+        let rendered = match (self.describe_viewer, self.describe_decoder()) {
+            (ValueViewer::Decoded, Some(decoder)) => decoder.decode(raw),
+            (viewer, _) => viewer.render(raw),
+        };
+        self.describe_view = match rendered {
+            Ok(text) => text,
+            Err(e) => format!(
+                "Cannot show value as {}: {:#}",
+                self.describe_viewer_label(),
+                e
+            ),
+        };
+    }
+
+    /// Decoder configured for the described key
+    pub fn describe_decoder(&self) -> Option<&decoders::Decoder> {
+        self.describe_key
+            .as_deref()
+            .and_then(|key| decoders::decoder_for(&self.decoders, key))
+    }
+
+    pub fn describe_viewer_label(&self) -> String {
+        match (self.describe_viewer, self.describe_decoder()) {
+            (ValueViewer::Decoded, Some(decoder)) => decoder.label.clone(),
+            (viewer, _) => viewer.label().to_string(),
+        }
+    }
+
+    /// Switch the describe view of a string value to the next viewer.
+    /// The decoded view is skipped for keys without a configured decoder.
+    pub fn cycle_describe_viewer(&mut self) {
+        if !matches!(self.describe_data, KeyValue::Bytes(_)) {
+            return;
+        }
+        self.describe_viewer = self.describe_viewer.next();
+        if self.describe_viewer == ValueViewer::Decoded && self.describe_decoder().is_none() {
+            self.describe_viewer = self.describe_viewer.next();
+        }
+        self.describe_scroll = 0;
+        self.render_describe_view();
+        self.refresh_describe_search_matches();
+    }
+
+    /// Text shown for values that are not tables
+    pub fn describe_text(&self) -> String {
+        match &self.describe_data {
+            KeyValue::String(s) => s.clone(),
+            KeyValue::Bytes(_) => self.describe_view.clone(),
+            KeyValue::None => "No data loaded.".to_string(),
+            KeyValue::Error(e) => format!("Error: {}", e),
+            _ => String::new(),
+        }
+    }
+
+    /// Focused pane of a described stream
+    pub fn stream_pane(&self) -> Option<StreamPane> {
+        self.stream_details.as_ref().map(|d| d.pane)
+    }
+
+    /// Whether navigation keys go to the groups, consumers or pending entries of a described stream
+    pub fn stream_pane_focused(&self) -> bool {
+        self.stream_details
+            .as_ref()
+            .is_some_and(|d| d.pane != StreamPane::Entries)
+    }
+
+    /// Move focus between the entries, groups, consumers and pending entries of a described stream
+    pub fn cycle_stream_pane(&mut self) {
+        if let Some(details) = self.stream_details.as_mut() {
+            details.pane = details.pane.next();
+        }
+    }
+
+    /// Select the next (or previous) group, consumer or pending entry, loading the
+    /// consumers and pending entries of a newly selected group
+    pub async fn stream_pane_move(&mut self, forward: bool) -> Result<()> {
+        let Some(mut details) = self.stream_details.take() else {
+            return Ok(());
+        };
+        let step = |selected: usize, len: usize| {
+            if forward {
+                (selected + 1).min(len.saturating_sub(1))
+            } else {
+                selected.saturating_sub(1)
+            }
+        };
+
+        let mut result = Ok(());
+        match details.pane {
+            StreamPane::Groups => {
+                let selected = step(details.selected_group, details.groups.len());
+                if selected != details.selected_group {
+                    details.selected_group = selected;
+                    details.selected_consumer = 0;
+                    details.selected_pending = 0;
+                    if let Some(con) = self.connection_for_key(&details.key) {
+                        result = streams::refresh_consumers(con, &mut details).await;
+                    }
+                }
+            }
+            StreamPane::Consumers => {
+                details.selected_consumer =
+                    step(details.selected_consumer, details.consumers.len());
+            }
+            StreamPane::Pending => {
+                details.selected_pending = step(details.selected_pending, details.pending.len());
+            }
+            StreamPane::Entries => {}
+        }
+        self.stream_details = Some(details);
+        result
+    }
+
+    /// Reload the XINFO summary, groups and consumers of the described stream
+    pub async fn refresh_stream_details(&mut self) -> Result<()> {
+        let Some(mut details) = self.stream_details.take() else {
+            return Ok(());
+        };
+        let result = match self.connection_for_key(&details.key) {
+            Some(con) => streams::refresh_details(con, &mut details).await,
+            None => Ok(()),
+        };
+        if let Err(e) = &result {
+            details.error = Some(e.to_string());
+        }
+        self.stream_details = Some(details);
+        result
+    }
+
+    /// Ask for the name and start ID of a new consumer group of the described stream
+    pub fn open_create_group_dialog(&mut self) {
+        if self.stream_details.is_none() {
+            return;
+        }
+        self.field_dialog = Some(
+            FieldDialogState::new(
+                "Create consumer group",
+                PendingActionType::StreamCreateGroup,
+                String::new(),
+            )
+            .with_field("Group", String::new())
+            .with_field("Start ID", "$".to_string())
+            .with_hint("$ delivers new entries only, 0 the whole stream"),
+        );
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Ask for the ID the selected group continues after (XGROUP SETID)
+    pub fn open_set_group_id_dialog(&mut self) {
+        let Some(group) = self
+            .stream_details
+            .as_ref()
+            .and_then(|d| d.selected_group())
+        else {
+            return;
+        };
+        self.field_dialog = Some(
+            FieldDialogState::new(
+                &format!("Set last delivered ID of '{}'", group.name),
+                PendingActionType::StreamSetGroupId,
+                group.name.clone(),
+            )
+            .with_field("ID", group.last_delivered_id.clone())
+            .with_hint("Entries after this ID are delivered next, $ skips to the end"),
+        );
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Ask which consumer receives the selected pending entry, or with `auto`
+    /// every entry of the group idle for long enough (XAUTOCLAIM)
+    pub fn open_claim_dialog(&mut self, auto: bool) {
+        let Some(details) = self.stream_details.as_ref() else {
+            return;
+        };
+        if details.selected_group().is_none() {
+            return;
+        }
+        let consumer = details
+            .selected_consumer()
+            .map(|c| c.name.clone())
+            .unwrap_or_default();
+
+        let dialog = if auto {
+            FieldDialogState::new(
+                "Claim idle pending entries",
+                PendingActionType::StreamAutoClaim,
+                String::new(),
+            )
+            .with_field("Consumer", consumer)
+            .with_field("Min idle (ms)", "60000".to_string())
+        } else {
+            let Some(entry) = details.selected_pending() else {
+                return;
+            };
+            FieldDialogState::new(
+                &format!("Claim {} from {}", entry.id, entry.consumer),
+                PendingActionType::StreamClaim,
+                entry.id.clone(),
+            )
+            .with_field("Consumer", consumer)
+        };
+        self.field_dialog = Some(dialog.with_hint("A consumer that doesn't exist is created"));
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Ask for confirmation before destroying the selected group or deleting the selected consumer
+    pub fn request_stream_delete(&mut self) {
+        let Some(details) = self.stream_details.as_ref() else {
+            return;
+        };
+        let (action_type, edit) = match details.pane {
+            StreamPane::Groups => {
+                let Some(group) = details.selected_group() else {
+                    return;
+                };
+                let edit = FieldEdit {
+                    target: group.name.clone(),
+                    value: group.pending.to_string(),
+                };
+                (PendingActionType::StreamDestroyGroup, edit)
+            }
+            StreamPane::Consumers => {
+                let Some(consumer) = details.selected_consumer() else {
+                    return;
+                };
+                let edit = FieldEdit {
+                    target: consumer.name.clone(),
+                    value: consumer.pending.to_string(),
+                };
+                (PendingActionType::StreamDeleteConsumer, edit)
+            }
+            _ => return,
+        };
+        self.pending_action = Some(PendingAction {
+            key: details.key.clone(),
+            action_type,
+            selected_yes: false,
+            matched_keys: Vec::new(),
+            field_edit: Some(edit),
+        });
+        self.mode = Mode::Confirm;
+    }
+
+    /// Ask for confirmation before acknowledging the selected pending entry
+    pub fn request_stream_ack(&mut self) {
+        let Some(details) = self.stream_details.as_ref() else {
+            return;
+        };
+        let Some(entry) = details.selected_pending() else {
+            return;
+        };
+        self.pending_action = Some(PendingAction {
+            key: details.key.clone(),
+            action_type: PendingActionType::StreamAck,
+            selected_yes: false,
+            matched_keys: Vec::new(),
+            field_edit: Some(FieldEdit {
+                target: entry.id.clone(),
+                value: entry.consumer.clone(),
+            }),
+        });
+        self.mode = Mode::Confirm;
+    }
+
+    /// Start editing the described string value in the TUI editor.
+    /// Values that are not UTF-8 can't be edited as text.
+    pub fn open_value_editor(&mut self) -> bool {
+        let KeyValue::Bytes(raw) = &self.describe_data else {
+            return false;
+        };
+        let Some(key) = self.describe_key.clone() else {
+            return false;
+        };
+        let Ok(value) = String::from_utf8(raw.clone()) else {
+            self.describe_message = Some("Binary value, can't be edited as text".to_string());
+            return false;
+        };
+        self.value_editor = Some(ValueEditor::new(key, value));
+        self.mode = Mode::EditValue;
+        true
+    }
+
+    /// Write `value` to `key`, keeping its TTL, unless the key no longer holds `original`.
+    /// Runs WATCH/GET/MULTI/SET KEEPTTL/EXEC on a dedicated connection, since
+    /// WATCH state would be shared with other commands on the multiplexed one.
+    pub async fn save_string_value(
+        &self,
+        key: &str,
+        original: &[u8],
+        value: &str,
+    ) -> Result<SaveOutcome> {
+        let mut con = self.dedicated_connection_for_key(key).await?;
+
+        redis::cmd("WATCH")
+            .arg(key)
+            .query_async::<()>(&mut con)
+            .await?;
+        let current: Option<Vec<u8>> = con.get(key).await?;
+        if current.as_deref() != Some(original) {
+            redis::cmd("UNWATCH").query_async::<()>(&mut con).await?;
+            return Ok(SaveOutcome::Conflict(current));
+        }
+
+        let result: Option<()> = redis::pipe()
+            .atomic()
+            .cmd("SET")
+            .arg(key)
+            .arg(value)
+            .arg("KEEPTTL")
+            .query_async(&mut con)
+            .await?;
+
+        match result {
+            Some(()) => Ok(SaveOutcome::Saved),
+            // EXEC aborted, the key was modified between GET and EXEC
+            None => {
+                let current: Option<Vec<u8>> = con.get(key).await?;
+                Ok(SaveOutcome::Conflict(current))
+            }
+        }
+    }
+
+    /// Save the TUI editor contents, closing the editor on success.
+    /// On a conflict the editor stays open and a second save overwrites the new value.
+    pub async fn save_value_editor(&mut self) {
+        let Some(editor) = self.value_editor.as_ref() else {
+            return;
+        };
+        let key = editor.key.clone();
+        let original = editor.original.clone();
+        let value = editor.editor.text();
+
+        let outcome = self.save_string_value(&key, &original, &value).await;
+        let Some(editor) = self.value_editor.as_mut() else {
+            return;
+        };
+        match outcome {
+            Ok(SaveOutcome::Saved) => {
+                crate::log!(crate::LogLevel::Info, "Saved {}", key);
+                self.set_describe_bytes(value.into_bytes());
+                self.refresh_describe_search_matches();
+                self.value_editor = None;
+                self.mode = Mode::Describe;
+            }
+            Ok(SaveOutcome::Conflict(current)) => {
+                crate::log!(crate::LogLevel::Warn, "{} changed since it was loaded", key);
+                editor.conflict(current);
+            }
+            Err(e) => {
+                crate::log!(crate::LogLevel::Error, "Failed to save {}: {}", key, e);
+                editor.message = Some(format!("Save failed: {}", e));
+            }
+        }
+    }
+
+    /// Open the TTL dialog for the selected keys, or the highlighted key when none are selected
+    pub fn open_ttl_dialog(&mut self) {
+        let dialog = if !self.selected_keys.is_empty() {
+            FieldDialogState::new(
+                &format!("Set TTL of {} selected keys", self.selected_keys.len()),
+                PendingActionType::SetTtl,
+                String::new(),
+            )
+            .with_field("TTL", String::new())
+        } else if let Some(info) = self.scan_result.get(self.selected_key_index) {
+            let current = if info.pttl >= 0 {
+                (info.pttl / 1000).to_string()
+            } else {
+                String::new()
+            };
+            FieldDialogState::new(
+                &format!("Set TTL of '{}'", info.key),
+                PendingActionType::SetTtl,
+                info.key.clone(),
+            )
+            .with_field("TTL", current)
+        } else {
+            return;
+        };
+
+        self.field_dialog = Some(dialog.with_hint(TTL_HINT));
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Open the rename dialog for the highlighted key
+    pub fn open_rename_dialog(&mut self) {
+        let Some(info) = self.scan_result.get(self.selected_key_index) else {
+            return;
+        };
+        self.field_dialog = Some(
+            FieldDialogState::new(
+                &format!("Rename '{}'", info.key),
+                PendingActionType::RenameKey,
+                info.key.clone(),
+            )
+            .with_field("New name", info.key.clone()),
+        );
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Open the copy dialog for the highlighted key, defaulting to the current database
+    pub fn open_copy_dialog(&mut self) {
+        let Some(info) = self.scan_result.get(self.selected_key_index) else {
+            return;
+        };
+        self.field_dialog = Some(
+            FieldDialogState::new(
+                &format!("Copy '{}'", info.key),
+                PendingActionType::CopyKey,
+                info.key.clone(),
+            )
+            .with_field("New name", info.key.clone())
+            .with_field("DB", self.connection_config.db.to_string())
+            .with_hint("COPY needs Redis 6.2+; another DB is not available in cluster mode"),
+        );
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Open the move dialog for the selected keys, or the highlighted key when none are selected
+    pub fn open_move_dialog(&mut self) {
+        let (title, target) = if !self.selected_keys.is_empty() {
+            (
+                format!("Move {} selected keys", self.selected_keys.len()),
+                String::new(),
+            )
+        } else if let Some(info) = self.scan_result.get(self.selected_key_index) {
+            (format!("Move '{}'", info.key), info.key.clone())
+        } else {
+            return;
+        };
+        self.field_dialog = Some(
+            FieldDialogState::new(&title, PendingActionType::MoveKeys, target)
+                .with_field("DB", String::new())
+                .with_hint("Target database index"),
+        );
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Rename the pending key with RENAMENX, or RENAME when `overwrite` is set.
+    /// When the new name is taken, the overwrite is handed to the confirm dialog.
+    pub async fn rename_key(&mut self, overwrite: bool) -> Result<()> {
+        let Some(pending) = self.pending_action.as_ref() else {
+            return Ok(());
+        };
+        let key = pending.key.clone();
+        let edit = pending.field_edit.clone().unwrap_or_default();
+        let Some(con) = self.connection_for_key(&key) else {
+            anyhow::bail!("Not connected");
+        };
+
+        let renamed = if overwrite {
+            con.rename::<_, _, ()>(&key, &edit.target).await?;
+            true
+        } else {
+            con.rename_nx(&key, &edit.target).await?
+        };
+
+        if renamed {
+            self.key_message = Some(format!("Renamed '{}' to '{}'", key, edit.target));
+        } else {
+            self.pending_action = Some(PendingAction {
+                key,
+                action_type: PendingActionType::RenameKeyOverwrite,
+                selected_yes: false,
+                matched_keys: Vec::new(),
+                field_edit: Some(edit),
+            });
+        }
+        Ok(())
+    }
+
+    /// Copy the pending key, optionally into another database. Without `replace`
+    /// an existing destination is handed to the confirm dialog instead.
+    pub async fn copy_key(&mut self, replace: bool) -> Result<()> {
+        let Some(pending) = self.pending_action.as_ref() else {
+            return Ok(());
+        };
+        let key = pending.key.clone();
+        let edit = pending.field_edit.clone().unwrap_or_default();
+        let db = edit.value.parse::<i64>().ok();
+        let current_db = self.connection_config.db;
+        let Some(con) = self.connection_for_key(&key) else {
+            anyhow::bail!("Not connected");
+        };
+
+        let mut cmd = redis::cmd("COPY");
+        cmd.arg(&key).arg(&edit.target);
+        if let Some(db) = db.filter(|db| *db != current_db) {
+            cmd.arg("DB").arg(db);
+        }
+        if replace {
+            cmd.arg("REPLACE");
+        }
+        let copied: bool = cmd.query_async(con).await?;
+
+        if copied {
+            self.key_message = Some(format!(
+                "Copied '{}' to '{}' (db {})",
+                key,
+                edit.target,
+                db.unwrap_or(current_db)
+            ));
+        } else {
+            self.pending_action = Some(PendingAction {
+                key,
+                action_type: PendingActionType::CopyKeyReplace,
+                selected_yes: false,
+                matched_keys: Vec::new(),
+                field_edit: Some(edit),
+            });
+        }
+        Ok(())
+    }
+
+    /// MOVE the pending key or selection to another database. Every key is tried,
+    /// failures are collected per key and summarized in `key_message`.
+    pub async fn move_keys(&mut self) -> Result<()> {
+        let Some(pending) = self.pending_action.as_ref() else {
+            return Ok(());
+        };
+        let db: i64 = pending
+            .field_edit
+            .as_ref()
+            .map(|e| e.value.parse())
+            .transpose()?
+            .unwrap_or_default();
+        let keys = if pending.matched_keys.is_empty() {
+            vec![pending.key.clone()]
+        } else {
+            pending.matched_keys.clone()
+        };
+
+        let mut moved = Vec::new();
+        let mut failures: Vec<String> = Vec::new();
+        for key in &keys {
+            let Some(con) = self.connection_for_key(key) else {
+                failures.push(format!("{} (no connection)", key));
+                continue;
+            };
+            let result: redis::RedisResult<bool> =
+                redis::cmd("MOVE").arg(key).arg(db).query_async(con).await;
+            match result {
+                Ok(true) => moved.push(key),
+                Ok(false) => failures.push(format!("{} (exists in db {} or gone)", key, db)),
+                Err(e) => failures.push(format!("{} ({})", key, e)),
+            }
+        }
+
+        for failure in &failures {
+            crate::log!(crate::LogLevel::Warn, "Move failed: {}", failure);
+        }
+        self.key_message = Some(if failures.is_empty() {
+            format!("Moved {} keys to db {}", moved.len(), db)
+        } else {
+            format!(
+                "Moved {} of {} keys to db {}, failed: {}",
+                moved.len(),
+                keys.len(),
+                db,
+                failures.join(", ")
+            )
+        });
+        for key in moved {
+            self.selected_keys.remove(key);
+        }
+        Ok(())
+    }
+
+    /// Open the TTL dialog for the selected hash field
+    pub fn open_field_ttl_dialog(&mut self) {
+        if !matches!(self.describe_data, KeyValue::Hash(_)) || self.describe_key.is_none() {
+            return;
+        }
+        if self.refuse_binary_row() {
+            return;
+        }
+        let Some((field, _)) = self.collection_rows().get(self.describe_selected).cloned() else {
+            return;
+        };
+        let current = match self.describe_field_ttls.get(&field) {
+            Some(ttl) if *ttl >= 0 => ttl.to_string(),
+            _ => String::new(),
+        };
+        self.field_dialog = Some(
+            FieldDialogState::new(
+                &format!("Set TTL of field '{}'", field),
+                PendingActionType::HashFieldTtl,
+                field,
+            )
+            .with_field("TTL", current)
+            .with_hint(TTL_HINT),
+        );
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Apply the confirmed TTL change to its key or to every selected key, and
+    /// report how many were changed, skipped or failed in the key message.
+    /// Keys redirected with MOVED are sent again once, after reloading the slot map.
+    pub async fn apply_ttl_change(&mut self) -> Result<usize> {
+        let Some(pending) = self.pending_action.as_ref() else {
+            return Ok(0);
+        };
+        let Some(edit) = pending.field_edit.as_ref() else {
+            return Ok(0);
+        };
+        let change = TtlChange::parse(&edit.value)?;
+        let keys = if pending.matched_keys.is_empty() {
+            vec![pending.key.clone()]
+        } else {
+            pending.matched_keys.clone()
+        };
+
+        let mut report = TtlReport::new(change, keys.len());
+        let mut remaining = keys;
+        for attempt in 0..2 {
+            let moved = self.send_ttl_change(change, remaining, &mut report).await?;
+            if moved.is_empty() {
+                break;
+            }
+            if attempt > 0 || !self.reload_cluster_map().await {
+                for key in moved {
+                    report.failures.push(format!("{} (slot moved)", key));
+                }
+                break;
+            }
+            remaining = moved;
+        }
+
+        for failure in &report.failures {
+            crate::log!(crate::LogLevel::Warn, "TTL change failed: {}", failure);
+        }
+        self.key_message = Some(report.summary());
+        Ok(report.changed)
+    }
+
+    /// Pipeline the TTL change per node, keys must be sent to the primary owning
+    /// them. Returns the keys answered with MOVED, which were not changed.
+    async fn send_ttl_change(
+        &mut self,
+        change: TtlChange,
+        keys: Vec<String>,
+        report: &mut TtlReport,
+    ) -> Result<Vec<String>> {
+        let mut by_node: HashMap<usize, Vec<String>> = HashMap::new();
+        for key in keys {
+            let node = match &self.cluster {
+                Some(cluster) => match cluster.primary_for_slot(cluster::key_slot(&key)) {
+                    Some(idx) => idx,
+                    None => {
+                        report.failures.push(format!(
+                            "{} (no primary serves slot {})",
+                            key,
+                            cluster::key_slot(&key)
+                        ));
+                        continue;
+                    }
+                },
+                None => 0,
+            };
+            by_node.entry(node).or_default().push(key);
+        }
+
+        let mut moved = Vec::new();
+        for (node, keys) in by_node {
+            let con = match &mut self.cluster {
+                Some(cluster) => &mut cluster.primaries[node].connection,
+                None => match &mut self.connection {
+                    Some(con) => con,
+                    None => anyhow::bail!("Not connected"),
+                },
+            };
+            for chunk in keys.chunks(100) {
+                let mut pipe = redis::pipe();
+                pipe.ignore_errors();
+                for key in chunk {
+                    pipe.add_command(change.key_command(key));
+                }
+                let results: Vec<redis::RedisResult<i64>> = match pipe.query_async(con).await {
+                    Ok(results) => results,
+                    Err(e) => {
+                        for key in chunk {
+                            report.failures.push(format!("{} ({})", key, e));
+                        }
+                        continue;
+                    }
+                };
+                for (key, result) in chunk.iter().zip(results) {
+                    match result {
+                        Ok(1) => report.changed += 1,
+                        Ok(_) => report.skipped += 1,
+                        Err(e) if is_moved(&e) => moved.push(key.clone()),
+                        Err(e) => report.failures.push(format!("{} ({})", key, e)),
+                    }
+                }
+            }
+        }
+        Ok(moved)
+    }
+
+    /// Rows of the described collection as (field/index/member, value/score) pairs,
+    /// in the order they are displayed
+    pub fn collection_rows(&self) -> Vec<(String, String)> {
+        match self
+            .describe_search_results
+            .as_ref()
+            .unwrap_or(&self.describe_data)
+        {
+            KeyValue::Hash(h) => h.clone(),
+            KeyValue::List(l) => l
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v.clone()))
+                .collect(),
+            KeyValue::Set(s) => s.iter().map(|m| (m.clone(), String::new())).collect(),
+            KeyValue::ZSet(z) => z.iter().map(|(m, s)| (m.clone(), s.to_string())).collect(),
+            KeyValue::Stream(entries) => entries
+                .iter()
+                .map(|e| {
+                    let mut fields: Vec<_> = e.fields.iter().collect();
+                    fields.sort();
+                    let fields: Vec<String> =
+                        fields.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+                    (e.id.clone(), fields.join(" "))
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether the describe view shows a table of rows
+    pub fn describe_is_table(&self) -> bool {
+        matches!(
+            self.describe_data,
+            KeyValue::Hash(_)
+                | KeyValue::List(_)
+                | KeyValue::Set(_)
+                | KeyValue::ZSet(_)
+                | KeyValue::Stream(_)
+        )
+    }
+
+    /// Whether the describe view shows a table of editable rows
+    pub fn describe_is_collection(&self) -> bool {
+        self.describe_key.is_some()
+            && matches!(
+                self.describe_data,
+                KeyValue::Hash(_) | KeyValue::List(_) | KeyValue::Set(_) | KeyValue::ZSet(_)
+            )
+    }
+
+    pub fn describe_next(&mut self) {
+        let len = self.collection_rows().len();
+        if self.describe_selected + 1 < len {
+            self.describe_selected += 1;
+        }
+    }
+
+    pub fn describe_previous(&mut self) {
+        self.describe_selected = self.describe_selected.saturating_sub(1);
+    }
+
+    /// Rows holding bytes that aren't UTF-8 are shown with `\xHH` escapes, so writing
+    /// them back through the text dialogs would target the wrong element. Explains that
+    /// and returns true when the selected row is one of them.
+    fn refuse_binary_row(&mut self) -> bool {
+        let binary = match (
+            self.collection_rows().get(self.describe_selected),
+            &self.describe_window,
+        ) {
+            (Some((target, _)), Some(window)) => window.binary.contains(target),
+            _ => false,
+        };
+        if binary {
+            self.describe_message =
+                Some("Element is not UTF-8 and can't be edited here".to_string());
+        }
+        binary
+    }
+
+    /// Open the dialog to edit the selected row, or to add one when `add` is set
+    pub fn open_field_dialog(&mut self, add: bool) {
+        if !self.describe_is_collection() {
+            return;
+        }
+        let selected = self.collection_rows().get(self.describe_selected).cloned();
+        if !add && (selected.is_none() || self.refuse_binary_row()) {
+            return;
+        }
+        let (target, value) = selected.unwrap_or_default();
+
+        let dialog = match (&self.describe_data, add) {
+            (KeyValue::Hash(_), false) => FieldDialogState::new(
+                &format!("Edit field '{}'", target),
+                PendingActionType::HashSetField,
+                target,
+            )
+            .with_field("Value", value),
+            (KeyValue::Hash(_), true) => {
+                FieldDialogState::new("Add field", PendingActionType::HashAddField, String::new())
+                    .with_field("Field", String::new())
+                    .with_field("Value", String::new())
+            }
+            (KeyValue::List(_), false) => FieldDialogState::new(
+                &format!("Edit element {}", target),
+                PendingActionType::ListSetItem,
+                target,
+            )
+            .with_field("Value", value),
+            (KeyValue::List(_), true) => FieldDialogState::new(
+                "Append element",
+                PendingActionType::ListPushItem,
+                String::new(),
+            )
+            .with_field("Value", String::new()),
+            (KeyValue::Set(_), false) => FieldDialogState::new(
+                "Edit member",
+                PendingActionType::SetReplaceMember,
+                target.clone(),
+            )
+            .with_field("Member", target),
+            (KeyValue::Set(_), true) => {
+                FieldDialogState::new("Add member", PendingActionType::SetAddMember, String::new())
+                    .with_field("Member", String::new())
+            }
+            (KeyValue::ZSet(_), false) => FieldDialogState::new(
+                &format!("Edit score of '{}'", target),
+                PendingActionType::ZSetUpdateScore,
+                target,
+            )
+            .with_field("Score", value),
+            (KeyValue::ZSet(_), true) => FieldDialogState::new(
+                "Add member",
+                PendingActionType::ZSetAddMember,
+                String::new(),
+            )
+            .with_field("Member", String::new())
+            .with_field("Score", "0".to_string()),
+            _ => return,
+        };
+
+        self.field_dialog = Some(dialog);
+        self.mode = Mode::FieldDialog;
+    }
+
+    /// Validate the field dialog and hand its change to the confirm dialog
+    pub fn submit_field_dialog(&mut self) {
+        let Some(dialog) = self.field_dialog.as_mut() else {
+            return;
+        };
+        // TTL changes from the keys view carry their own key, the others edit the described key
+        let key = if !dialog.action_type.is_field_edit() {
+            dialog.target.clone()
+        } else if dialog.action_type.is_stream_group_action() {
+            // Streams described from the streams view have no described key
+            let Some(details) = self.stream_details.as_ref() else {
+                return;
+            };
+            details.key.clone()
+        } else {
+            let Some(key) = self.describe_key.clone() else {
+                return;
+            };
+            key
+        };
+
+        let edit = match dialog.action_type {
+            PendingActionType::HashAddField => FieldEdit {
+                target: dialog.value("Field").to_string(),
+                value: dialog.value("Value").to_string(),
+            },
+            PendingActionType::ZSetAddMember => FieldEdit {
+                target: dialog.value("Member").to_string(),
+                value: dialog.value("Score").trim().to_string(),
+            },
+            PendingActionType::RenameKey | PendingActionType::CopyKey => FieldEdit {
+                target: dialog.value("New name").to_string(),
+                value: dialog.value("DB").trim().to_string(),
+            },
+            PendingActionType::StreamCreateGroup => FieldEdit {
+                target: dialog.value("Group").to_string(),
+                value: dialog.value("Start ID").trim().to_string(),
+            },
+            PendingActionType::StreamAutoClaim => FieldEdit {
+                target: dialog.value("Min idle (ms)").trim().to_string(),
+                value: dialog.value("Consumer").to_string(),
+            },
+            PendingActionType::ConsumeStreams => FieldEdit {
+                target: dialog.value("Group").trim().to_string(),
+                value: dialog.value("Consumer").trim().to_string(),
+            },
+            _ => FieldEdit {
+                target: dialog.target.clone(),
+                value: dialog
+                    .fields
+                    .first()
+                    .map(|f| f.value.clone())
+                    .unwrap_or_default(),
+            },
+        };
+
+        match dialog.action_type {
+            PendingActionType::HashAddField if edit.target.is_empty() => {
+                dialog.set_error("Field name is required".to_string());
+                return;
+            }
+            PendingActionType::SetAddMember | PendingActionType::SetReplaceMember
+                if edit.value.is_empty() =>
+            {
+                dialog.set_error("Member is required".to_string());
+                return;
+            }
+            PendingActionType::ZSetAddMember if edit.target.is_empty() => {
+                dialog.set_error("Member is required".to_string());
+                return;
+            }
+            PendingActionType::ZSetUpdateScore | PendingActionType::ZSetAddMember
+                if edit.value.parse::<f64>().is_err() =>
+            {
+                dialog.set_error(format!("Invalid score: {}", edit.value));
+                return;
+            }
+            PendingActionType::RenameKey | PendingActionType::CopyKey if edit.target.is_empty() => {
+                dialog.set_error("New name is required".to_string());
+                return;
+            }
+            PendingActionType::RenameKey if edit.target == key => {
+                dialog.set_error("New name is the same as the current one".to_string());
+                return;
+            }
+            PendingActionType::CopyKey | PendingActionType::MoveKeys
+                if !edit.value.is_empty() && edit.value.parse::<u32>().is_err() =>
+            {
+                dialog.set_error(format!("Invalid database index: {}", edit.value));
+                return;
+            }
+            PendingActionType::MoveKeys
+                if edit.value.is_empty()
+                    || edit.value.parse::<i64>().ok() == Some(self.connection_config.db) =>
+            {
+                dialog.set_error("Enter another database index".to_string());
+                return;
+            }
+            PendingActionType::SetTtl | PendingActionType::HashFieldTtl => {
+                if let Err(e) = TtlChange::parse(&edit.value) {
+                    dialog.set_error(e.to_string());
+                    return;
+                }
+            }
+            PendingActionType::StreamCreateGroup if edit.target.is_empty() => {
+                dialog.set_error("Group name is required".to_string());
+                return;
+            }
+            PendingActionType::StreamCreateGroup | PendingActionType::StreamSetGroupId
+                if edit.value.trim().is_empty() =>
+            {
+                dialog.set_error("ID is required".to_string());
+                return;
+            }
+            PendingActionType::StreamClaim | PendingActionType::StreamAutoClaim
+                if edit.value.is_empty() =>
+            {
+                dialog.set_error("Consumer is required".to_string());
+                return;
+            }
+            PendingActionType::StreamAutoClaim if edit.target.parse::<u64>().is_err() => {
+                dialog.set_error(format!("Invalid idle time: {}", edit.target));
+                return;
+            }
+            PendingActionType::ConsumeStreams if edit.target.is_empty() => {
+                dialog.set_error("Group name is required".to_string());
+                return;
+            }
+            PendingActionType::ConsumeStreams if edit.value.is_empty() => {
+                dialog.set_error("Consumer is required".to_string());
+                return;
+            }
+            _ => {}
+        }
+
+        // Bulk TTL changes and moves apply to the whole selection
+        let matched_keys = if matches!(
+            dialog.action_type,
+            PendingActionType::SetTtl | PendingActionType::MoveKeys
+        ) && key.is_empty()
+        {
+            self.selected_keys.iter().cloned().collect()
+        } else {
+            Vec::new()
+        };
+
+        self.pending_action = Some(PendingAction {
+            key,
+            action_type: dialog.action_type.clone(),
+            selected_yes: false,
+            matched_keys,
+            field_edit: Some(edit),
+        });
+        self.field_dialog = None;
+        self.mode = Mode::Confirm;
+    }
+
+    /// Ask for confirmation before deleting the selected row
+    pub fn request_field_delete(&mut self) {
+        if !self.describe_is_collection() || self.refuse_binary_row() {
+            return;
+        }
+        let Some(key) = self.describe_key.clone() else {
+            return;
+        };
+        let Some((target, value)) = self.collection_rows().get(self.describe_selected).cloned()
+        else {
+            return;
+        };
+
+        let action_type = match self.describe_data {
+            KeyValue::Hash(_) => PendingActionType::HashDeleteField,
+            KeyValue::List(_) => PendingActionType::ListRemoveItem,
+            KeyValue::Set(_) => PendingActionType::SetRemoveMember,
+            _ => PendingActionType::ZSetRemoveMember,
+        };
+
+        self.pending_action = Some(PendingAction {
+            key,
+            action_type,
+            selected_yes: false,
+            matched_keys: Vec::new(),
+            field_edit: Some(FieldEdit { target, value }),
+        });
+        self.mode = Mode::Confirm;
+    }
+
+    /// Run the confirmed field change and reload the described key
+    pub async fn apply_field_edit(&mut self) -> Result<()> {
+        let Some(pending) = self.pending_action.as_ref() else {
+            return Ok(());
+        };
+        let Some(edit) = pending.field_edit.clone() else {
+            return Ok(());
+        };
+        let key = pending.key.clone();
+        let action_type = pending.action_type.clone();
+        self.describe_message = None;
+
+        if action_type.is_stream_group_action() {
+            return self
+                .apply_stream_group_edit(&key, &action_type, &edit)
+                .await;
+        }
+        if action_type == PendingActionType::ListRemoveItem {
+            self.remove_list_item(&key, &edit).await?;
+        } else if action_type == PendingActionType::ListSetItem {
+            self.set_list_item(&key, &edit).await?;
+        } else {
+            let Some(con) = self.connection_for_key(&key) else {
+                anyhow::bail!("Not connected");
+            };
+            let (target, value) = (&edit.target, &edit.value);
+            match action_type {
+                PendingActionType::HashSetField | PendingActionType::HashAddField => {
+                    con.hset::<_, _, _, ()>(&key, target, value).await?
+                }
+                PendingActionType::HashDeleteField => con.hdel::<_, _, ()>(&key, target).await?,
+                PendingActionType::ListPushItem => con.rpush::<_, _, ()>(&key, value).await?,
+                PendingActionType::SetReplaceMember => {
+                    redis::pipe()
+                        .atomic()
+                        .cmd("SREM")
+                        .arg(&key)
+                        .arg(target)
+                        .cmd("SADD")
+                        .arg(&key)
+                        .arg(value)
+                        .query_async::<()>(con)
+                        .await?
+                }
+                PendingActionType::SetAddMember => con.sadd::<_, _, ()>(&key, value).await?,
+                PendingActionType::SetRemoveMember => con.srem::<_, _, ()>(&key, target).await?,
+                PendingActionType::ZSetUpdateScore => {
+                    let score: f64 = value.parse()?;
+                    redis::cmd("ZADD")
+                        .arg(&key)
+                        .arg("XX")
+                        .arg(score)
+                        .arg(target)
+                        .query_async::<()>(con)
+                        .await?
+                }
+                PendingActionType::ZSetAddMember => {
+                    let score: f64 = value.parse()?;
+                    con.zadd::<_, _, _, ()>(&key, target, score).await?
+                }
+                PendingActionType::ZSetRemoveMember => con.zrem::<_, _, ()>(&key, target).await?,
+                PendingActionType::HashFieldTtl => {
+                    let change = TtlChange::parse(value)?;
+                    let codes: Vec<i64> = change
+                        .hash_field_command(&key, target)
+                        .query_async(con)
+                        .await?;
+                    if codes.first() == Some(&-2) {
+                        anyhow::bail!("Field '{}' no longer exists", target);
+                    }
+                }
+                _ => {}
+            }
+        }
+        crate::log!(crate::LogLevel::Info, "{:?} on {}", action_type, key);
+
+        self.reload_described_key().await
+    }
+
+    /// Run a confirmed consumer group action on the described stream and reload its groups.
+    /// Consumer and pending entry actions apply to the selected group.
+    async fn apply_stream_group_edit(
+        &mut self,
+        key: &str,
+        action_type: &PendingActionType,
+        edit: &FieldEdit,
+    ) -> Result<()> {
+        let group = self
+            .stream_details
+            .as_ref()
+            .and_then(|d| d.selected_group())
+            .map(|g| g.name.clone())
+            .unwrap_or_default();
+        let Some(con) = self.connection_for_key(key) else {
+            anyhow::bail!("Not connected");
+        };
+        let (target, value) = (edit.target.as_str(), edit.value.as_str());
+        match action_type {
+            PendingActionType::StreamCreateGroup => {
+                streams::create_group(con, key, target, value).await?
+            }
+            PendingActionType::StreamDestroyGroup => {
+                streams::destroy_group(con, key, target).await?
+            }
+            PendingActionType::StreamSetGroupId => {
+                streams::set_group_id(con, key, target, value).await?
+            }
+            PendingActionType::StreamDeleteConsumer => {
+                let pending = streams::delete_consumer(con, key, &group, target).await?;
+                crate::log!(
+                    crate::LogLevel::Info,
+                    "Deleted consumer {} of {} with {} pending entries",
+                    target,
+                    group,
+                    pending
+                );
+            }
+            PendingActionType::StreamClaim => {
+                let claimed = streams::claim(con, key, &group, value, target).await?;
+                if !claimed {
+                    anyhow::bail!("{} is no longer pending in {}", target, group);
+                }
+            }
+            PendingActionType::StreamAutoClaim => {
+                let claimed = streams::auto_claim(con, key, &group, value, target.parse()?).await?;
+                crate::log!(
+                    crate::LogLevel::Info,
+                    "Claimed {} entries of {} for {}",
+                    claimed,
+                    group,
+                    value
+                );
+            }
+            PendingActionType::StreamAck => {
+                let acked = streams::ack(con, key, &group, target).await?;
+                if !acked {
+                    anyhow::bail!("{} is no longer pending in {}", target, group);
+                }
+            }
+            _ => {}
+        }
+        crate::log!(crate::LogLevel::Info, "{:?} on {}", action_type, key);
+
+        self.refresh_stream_details().await
+    }
+
+    /// Remove the element at a list index. Redis has no delete-by-index, so the
+    /// element is swapped for a unique placeholder that LREM then removes. The
+    /// element is checked under WATCH first, in case the list shifted since it was loaded.
+    async fn remove_list_item(&self, key: &str, edit: &FieldEdit) -> Result<()> {
+        let mut con = self.dedicated_connection_for_key(key).await?;
+        let index: isize = edit.target.parse()?;
+        watch_list_element(&mut con, key, index, &edit.value).await?;
+
+        let placeholder = format!(
+            "__tredis_removed_{}__",
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        );
+        let result: Option<()> = redis::pipe()
+            .atomic()
+            .cmd("LSET")
+            .arg(key)
+            .arg(index)
+            .arg(&placeholder)
+            .cmd("LREM")
+            .arg(key)
+            .arg(1)
+            .arg(&placeholder)
+            .query_async(&mut con)
+            .await?;
+        if result.is_none() {
+            anyhow::bail!("{} changed while removing element {}", key, index);
+        }
+        Ok(())
     }
 
-    /// Delete all selected keys
-    pub async fn delete_selected_keys(&mut self) -> Result<u64> {
-        let mut deleted_count: u64 = 0;
+    /// Replace the element at a list index, checked under WATCH like `remove_list_item`
+    /// against the element shown at that index
+    async fn set_list_item(&self, key: &str, edit: &FieldEdit) -> Result<()> {
+        let Some((_, expected)) = self
+            .collection_rows()
+            .into_iter()
+            .find(|(index, _)| *index == edit.target)
+        else {
+            anyhow::bail!("Element {} of {} is no longer loaded", edit.target, key);
+        };
+        let mut con = self.dedicated_connection_for_key(key).await?;
+        let index: isize = edit.target.parse()?;
+        watch_list_element(&mut con, key, index, &expected).await?;
+
+        let result: Option<()> = redis::pipe()
+            .atomic()
+            .cmd("LSET")
+            .arg(key)
+            .arg(index)
+            .arg(&edit.value)
+            .query_async(&mut con)
+            .await?;
+        if result.is_none() {
+            anyhow::bail!("{} changed while setting element {}", key, index);
+        }
+        Ok(())
+    }
 
-        if let Some(con) = &mut self.connection {
-            let keys_to_delete: Vec<String> = self.selected_keys.iter().cloned().collect();
-            // Delete in batches to avoid blocking Redis for too long
-            for chunk in keys_to_delete.chunks(100) {
-                if !chunk.is_empty() {
-                    let count: u64 = con.del(chunk).await?;
-                    deleted_count += count;
-                }
+    /// Fetch the described key again after a change, keeping the selected row in range
+    async fn reload_described_key(&mut self) -> Result<()> {
+        let Some(key) = self.describe_key.clone() else {
+            return Ok(());
+        };
+        if self
+            .scan_result
+            .get(self.selected_key_index)
+            .is_some_and(|k| k.key == key)
+        {
+            let search_text = self.describe_search_text.clone();
+            self.fetch_key_value().await?;
+            // Load back up to the row that was selected
+            while self.collection_rows().len() <= self.describe_selected
+                && self.describe_window.as_ref().is_some_and(|w| !w.is_done())
+            {
+                self.load_next_window().await?;
             }
+            // Keep highlighting the previous search without moving the selection
+            self.describe_search_text = search_text;
+            self.refresh_describe_search_matches();
         }
+        let len = self.collection_rows().len();
+        self.describe_selected = self.describe_selected.min(len.saturating_sub(1));
+        Ok(())
+    }
 
-        // Clear selection after deletion
-        self.selected_keys.clear();
+    /// Fresh connection to the node owning `key`, not shared with other tasks
+    async fn dedicated_connection_for_key(
+        &self,
+        key: &str,
+    ) -> Result<redis::aio::MultiplexedConnection> {
+        use std::time::Duration;
+        use tokio::time::timeout;
 
-        Ok(deleted_count)
+        let Some(base_uri) = self.connection_uri.as_deref() else {
+            anyhow::bail!("Not connected");
+        };
+        let uri = match &self.cluster {
+            Some(cluster) => {
+                let idx = cluster
+                    .primary_for_slot(cluster::key_slot(key))
+                    .ok_or_else(|| anyhow::anyhow!("No primary serves the slot of {}", key))?;
+                let node = &cluster.primaries[idx].node;
+                connection::node_uri(base_uri, &node.host, node.port)
+            }
+            None => base_uri.to_string(),
+        };
+
+        let client = redis::Client::open(uri)?;
+        let con = timeout(
+            Duration::from_secs(30),
+            client.get_multiplexed_async_connection(),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Connection timed out after 30 seconds"))??;
+        Ok(con)
     }
 
-    pub async fn fetch_key_value(&mut self) -> Result<()> {
-        if self.scan_result.is_empty() {
+    pub async fn fetch_stream_entries(&mut self) -> Result<()> {
+        if self.streams.is_empty() {
             return Ok(());
         }
 
-        let key_info = &self.scan_result[self.selected_key_index];
-        let key = &key_info.key;
-        let key_type = &key_info.key_type;
+        let stream_name = self.streams[self.selected_stream_index].name.clone();
+        self.describe_key = None;
+        self.describe_message = None;
+        self.clear_describe_search();
+        self.load_first_window(stream_name, "stream".to_string())
+            .await
+    }
 
-        if let Some(con) = &mut self.connection {
-            self.describe_data = match key_type.as_str() {
-                "string" => {
-                    let val: String = con
-                        .get(key)
-                        .await
-                        .unwrap_or_else(|e| format!("Error: {}", e));
-                    KeyValue::String(val)
-                }
-                "list" => {
-                    let val: Vec<String> = con.lrange(key, 0, -1).await.unwrap_or_default();
-                    KeyValue::List(val)
-                }
-                "set" => {
-                    let val: Vec<String> = con.smembers(key).await.unwrap_or_default();
-                    KeyValue::Set(val)
-                }
-                "zset" => {
-                    let val: Vec<(String, f64)> =
-                        con.zrange_withscores(key, 0, -1).await.unwrap_or_default();
-                    KeyValue::ZSet(val)
-                }
-                "hash" => {
-                    let val: HashMap<String, String> = con.hgetall(key).await.unwrap_or_default();
-                    KeyValue::Hash(val)
-                }
-                "stream" => {
-                    let entries: Vec<(String, Vec<(String, String)>)> = redis::cmd("XRANGE")
-                        .arg(key)
-                        .arg("-")
-                        .arg("+")
-                        .query_async(con)
-                        .await
-                        .unwrap_or_default();
+    /// Mark or unmark the highlighted stream to be read together with others
+    pub fn toggle_stream_selection(&mut self) {
+        let Some(stream) = self.streams.get(self.selected_stream_index) else {
+            return;
+        };
+        if !self.selected_streams.remove(&stream.name) {
+            self.selected_streams.insert(stream.name.clone());
+        }
+    }
 
-                    let stream_entries: Vec<StreamEntry> = entries
-                        .into_iter()
-                        .map(|(id, fields)| {
-                            let mut field_map = HashMap::new();
-                            for (k, v) in fields {
-                                field_map.insert(k, v);
-                            }
-                            StreamEntry {
-                                id,
-                                fields: field_map,
-                            }
-                        })
-                        .collect();
-
-                    KeyValue::Stream(stream_entries)
-                }
-                _ => KeyValue::Error(format!("Unsupported type: {}", key_type)),
-            };
+    /// The marked streams, or the highlighted one when none is marked
+    pub fn streams_to_read(&self) -> Vec<String> {
+        if self.selected_streams.is_empty() {
+            self.streams
+                .get(self.selected_stream_index)
+                .map(|s| vec![s.name.clone()])
+                .unwrap_or_default()
+        } else {
+            let mut streams: Vec<String> = self.selected_streams.iter().cloned().collect();
+            streams.sort();
+            streams
         }
-        Ok(())
     }
 
-    pub async fn fetch_stream_entries(&mut self) -> Result<()> {
-        if self.streams.is_empty() {
-            return Ok(());
+    /// Ask for the ID to tail the streams from
+    pub fn open_tail_dialog(&mut self) {
+        let streams = self.streams_to_read();
+        if streams.is_empty() {
+            return;
         }
+        self.field_dialog = Some(
+            FieldDialogState::new(
+                &format!("Tail {}", streams.join(", ")),
+                PendingActionType::TailStreams,
+                String::new(),
+            )
+            .with_field("Start ID", "$".to_string())
+            .with_hint("Entries after this ID are read first, $ waits for new ones"),
+        );
+        self.mode = Mode::FieldDialog;
+    }
 
-        let stream = &self.streams[self.selected_stream_index];
-        let stream_name = &stream.name;
+    /// Validate the tail dialog and close it, returning how to read the streams
+    pub fn submit_tail_dialog(&mut self) -> Option<StreamReadMode> {
+        let dialog = self.field_dialog.as_mut()?;
+        let start_id = dialog.value("Start ID").trim().to_string();
+        if start_id.is_empty() {
+            dialog.set_error("Start ID is required".to_string());
+            return None;
+        }
+        self.field_dialog = None;
+        self.mode = Mode::Normal;
+        Some(StreamReadMode::Tail { start_id })
+    }
 
-        if let Some(con) = &mut self.connection {
-            let entries: Vec<(String, Vec<(String, String)>)> = redis::cmd("XRANGE")
-                .arg(stream_name)
-                .arg("-")
-                .arg("+")
-                .query_async(con)
-                .await
-                .unwrap_or_default();
+    /// Ask for the group and consumer to read the streams as. Reading through a
+    /// group changes its state, so it is confirmed before it starts.
+    pub fn open_consume_dialog(&mut self) {
+        let streams = self.streams_to_read();
+        if streams.is_empty() {
+            return;
+        }
+        self.field_dialog = Some(
+            FieldDialogState::new(
+                &format!("Consume {} through a group", streams.join(", ")),
+                PendingActionType::ConsumeStreams,
+                String::new(),
+            )
+            .with_field("Group", self.stream_consumer_group.clone())
+            .with_field("Consumer", self.stream_consumer_group.clone())
+            .with_hint("Every entry read is acknowledged (XACK)"),
+        );
+        self.mode = Mode::FieldDialog;
+    }
 
-            let stream_entries: Vec<StreamEntry> = entries
-                .into_iter()
-                .map(|(id, fields)| {
-                    let mut field_map = HashMap::new();
-                    for (k, v) in fields {
-                        field_map.insert(k, v);
-                    }
-                    StreamEntry {
-                        id,
-                        fields: field_map,
-                    }
-                })
-                .collect();
+    /// Switch the streams view to the messages of a new reader, returning the streams to read
+    pub fn start_stream_read(&mut self, mode: StreamReadMode) -> Vec<String> {
+        self.stop_stream_consumer();
+        let streams = self.streams_to_read();
+        if streams.is_empty() {
+            return streams;
+        }
+        self.stream_active = true;
+        self.stream_scroll = 0;
+        self.stream_read_mode = Some(mode);
+        self.stream_read_streams = streams.clone();
+        streams
+    }
+
+    /// Where to read `streams` from. In a cluster XREAD only works on keys of one
+    /// slot, so the streams must share a slot and are read from its primary.
+    pub fn stream_reader_factory(&self, streams: &[String]) -> Result<ConnectionFactory> {
+        let Some(cluster) = &self.cluster else {
+            return Ok(self.connection_factory());
+        };
+        let Some(slot) = crate::cluster::shared_slot(streams) else {
+            anyhow::bail!(
+                "streams in different hash slots can't be read together in a cluster, use a common {{hash tag}}"
+            );
+        };
+        let Some(idx) = cluster.primary_for_slot(slot) else {
+            anyhow::bail!("no primary serves slot {}", slot);
+        };
+        let node = &cluster.primaries[idx].node;
+        let base_uri = self
+            .connection_uri
+            .clone()
+            .unwrap_or_else(|| self.connection_url());
+        Ok(ConnectionFactory::new(connection::node_uri(
+            &base_uri, &node.host, node.port,
+        )))
+    }
 
-            self.describe_data = KeyValue::Stream(stream_entries);
+    /// Add a message of the reader started as `generation`, newest first. In group
+    /// mode the entry is handed back to the reader for XACK only once it is shown here.
+    pub fn receive_stream_message(
+        &mut self,
+        generation: u64,
+        stream: String,
+        entry: crate::model::StreamEntry,
+    ) {
+        if !self.stream_active || generation != self.stream_generation {
+            return;
+        }
+        if let Some(acks) = &self.stream_acks {
+            let _ = acks.send((stream.clone(), entry.id.clone()));
+        }
+        self.stream_messages.insert(0, (stream, entry));
+        // Keep only last 1000 entries
+        if self.stream_messages.len() > 1000 {
+            self.stream_messages.pop();
+        }
+    }
+
+    /// Record why the reader started as `generation` stopped
+    pub fn receive_stream_error(&mut self, generation: u64, message: String) {
+        if self.stream_active && generation == self.stream_generation {
+            self.stream_read_error = Some(message);
         }
-        Ok(())
     }
 
     pub fn stop_stream_consumer(&mut self) {
         self.stream_active = false;
+        self.stream_generation += 1;
+        self.stream_acks = None;
         if let Some(task) = self.stream_task.take() {
             task.abort();
         }
         self.stream_messages.clear();
+        self.stream_read_mode = None;
+        self.stream_read_streams.clear();
+        self.stream_read_error = None;
     }
 
     pub fn update_command_suggestions(&mut self) {
@@ -1006,6 +3389,122 @@ impl App {
         }
     }
 
+    /// Recompute describe search matches over the loaded rows, or the lines of a string
+    fn refresh_describe_search_matches(&mut self) {
+        self.describe_search_matches.clear();
+        self.describe_search_current = 0;
+
+        if self.describe_search_text.is_empty() {
+            return;
+        }
+
+        let search_lower = self.describe_search_text.to_lowercase();
+
+        if self.describe_is_table() {
+            for (idx, (first, second)) in self.collection_rows().iter().enumerate() {
+                if first.to_lowercase().contains(&search_lower)
+                    || second.to_lowercase().contains(&search_lower)
+                {
+                    self.describe_search_matches.push(idx);
+                }
+            }
+        } else {
+            for (idx, line) in self.describe_text().lines().enumerate() {
+                if line.to_lowercase().contains(&search_lower) {
+                    self.describe_search_matches.push(idx);
+                }
+            }
+        }
+    }
+
+    /// Update describe search matches based on the current search text
+    pub fn update_describe_search(&mut self) {
+        self.refresh_describe_search_matches();
+        self.describe_jump_to_match();
+    }
+
+    /// Search the whole set, sorted set or hash on the server with SSCAN/ZSCAN/HSCAN MATCH
+    /// and show the hits on their own until the search is cleared, so the loaded rows keep
+    /// their order
+    pub async fn search_describe_on_server(&mut self) -> Result<()> {
+        let text = self.describe_search_text.clone();
+        let Some(window) = self.describe_window.clone() else {
+            return Ok(());
+        };
+        if text.is_empty() || !matches!(window.key_type.as_str(), "set" | "zset" | "hash") {
+            return Ok(());
+        }
+
+        let Some(con) = self.connection_for_key(&window.key) else {
+            return Ok(());
+        };
+        let hits = values::scan_matches(con, &window.key, &window.key_type, &text).await?;
+        if let KeyValue::Hash(fields) = &hits.rows {
+            let field_ttls = fetch_field_ttls(con, &window.key, fields).await;
+            self.describe_field_ttls.extend(field_ttls);
+        }
+        if let Some(window) = self.describe_window.as_mut() {
+            window.binary.extend(hits.binary);
+        }
+        self.describe_search_results = Some(hits.rows);
+        self.describe_search_truncated = hits.truncated;
+        self.describe_selected = 0;
+        self.update_describe_search();
+        Ok(())
+    }
+
+    fn describe_jump_to_match(&mut self) {
+        let Some(&idx) = self
+            .describe_search_matches
+            .get(self.describe_search_current)
+        else {
+            return;
+        };
+        if self.describe_is_table() {
+            self.describe_selected = idx;
+        } else {
+            self.describe_scroll = idx;
+        }
+    }
+
+    /// Go to next describe search match
+    pub fn describe_search_next(&mut self) {
+        if self.describe_search_matches.is_empty() {
+            return;
+        }
+
+        self.describe_search_current =
+            (self.describe_search_current + 1) % self.describe_search_matches.len();
+        self.describe_jump_to_match();
+    }
+
+    /// Go to previous describe search match
+    pub fn describe_search_prev(&mut self) {
+        if self.describe_search_matches.is_empty() {
+            return;
+        }
+
+        if self.describe_search_current == 0 {
+            self.describe_search_current = self.describe_search_matches.len() - 1;
+        } else {
+            self.describe_search_current -= 1;
+        }
+        self.describe_jump_to_match();
+    }
+
+    /// Clear describe search
+    pub fn clear_describe_search(&mut self) {
+        self.describe_search_active = false;
+        self.describe_search_text.clear();
+        self.describe_search_matches.clear();
+        self.describe_search_current = 0;
+        // Back to the loaded rows
+        if self.describe_search_results.take().is_some() {
+            self.describe_search_truncated = false;
+            self.describe_selected = 0;
+        }
+    }
+
     /// Go to next search match
     pub fn info_search_next(&mut self) {
         if self.info_search_matches.is_empty() {
@@ -1055,7 +3554,149 @@ impl App {
         if let Some(task) = self.monitor_task.take() {
             task.abort();
         }
+        self.clear_monitor();
+        self.monitor_paused = false;
+        self.monitor_recorder = None;
+    }
+
+    /// Start or stop writing the monitor feed to a recording file
+    pub fn toggle_monitor_recording(&mut self) -> Result<()> {
+        match self.monitor_recorder.take() {
+            Some(recorder) => {
+                crate::log!(
+                    crate::LogLevel::Info,
+                    "[MONITOR] Recorded {} commands to {}",
+                    recorder.count,
+                    recorder.path.display()
+                );
+            }
+            None => {
+                let recorder = MonitorRecorder::start()?;
+                crate::log!(
+                    crate::LogLevel::Info,
+                    "[MONITOR] Recording to {}",
+                    recorder.path.display()
+                );
+                self.monitor_recorder = Some(recorder);
+            }
+        }
+        Ok(())
+    }
+
+    /// Show a monitor recording offline, without any server connection
+    pub fn open_replay(
+        &mut self,
+        file: std::path::PathBuf,
+        entries: Vec<crate::model::MonitorEntry>,
+    ) {
+        self.monitor_entries = entries.into();
+        self.monitor_replay = Some(file);
+        self.active_resource = "monitor".to_string();
+        self.mode = Mode::Normal;
+        self.update_monitor_filter();
+    }
+
+    /// Drop captured entries and statistics, keeping the filter
+    pub fn clear_monitor(&mut self) {
         self.monitor_entries.clear();
+        self.monitor_buffer.clear();
+        self.monitor_stats = MonitorStats::default();
+        self.monitor_last_seen = None;
+        self.selected_monitor_index = 0;
+        self.monitor_scroll = 0;
+    }
+
+    /// Add a command received from MONITOR, holding it back while paused
+    pub fn push_monitor_entry(&mut self, entry: crate::model::MonitorEntry) {
+        if let Some(recorder) = &mut self.monitor_recorder {
+            if let Err(e) = recorder.record(&entry) {
+                crate::log!(crate::LogLevel::Error, "[MONITOR] Recording failed: {}", e);
+                self.monitor_recorder = None;
+            }
+        }
+
+        if self.monitor_filter.matches(&entry) {
+            self.monitor_stats.record(&entry);
+        }
+        self.monitor_last_seen = Some((entry.time, std::time::Instant::now()));
+
+        if self.monitor_paused {
+            self.monitor_buffer.push_back(entry);
+            if self.monitor_buffer.len() > MONITOR_BUFFER_LIMIT {
+                self.monitor_buffer.pop_front();
+            }
+            return;
+        }
+        self.insert_monitor_entry(entry);
+    }
+
+    fn insert_monitor_entry(&mut self, entry: crate::model::MonitorEntry) {
+        let visible = self.monitor_filter.matches(&entry);
+        // Prepend to beginning of list (newest first)
+        self.monitor_entries.push_front(entry);
+        if self.monitor_entries.len() > MONITOR_ENTRY_LIMIT {
+            self.monitor_entries.pop_back();
+        }
+
+        // Keep the selection on the same entry unless the user is following the top
+        if visible && (self.selected_monitor_index > 0 || self.monitor_scroll > 0) {
+            self.selected_monitor_index += 1;
+            self.monitor_scroll += 1;
+        }
+        let count = self.visible_monitor_entries().len();
+        self.selected_monitor_index = self.selected_monitor_index.min(count.saturating_sub(1));
+    }
+
+    pub fn toggle_monitor_pause(&mut self) {
+        self.monitor_paused = !self.monitor_paused;
+        if !self.monitor_paused {
+            let buffered = std::mem::take(&mut self.monitor_buffer);
+            for entry in buffered {
+                self.insert_monitor_entry(entry);
+            }
+        }
+    }
+
+    /// Re-parse the monitor filter; counters restart since they follow the filter
+    pub fn update_monitor_filter(&mut self) {
+        self.monitor_filter = MonitorFilter::parse(&self.monitor_filter_text);
+        self.monitor_stats = MonitorStats::default();
+        for entry in self.monitor_entries.iter().rev() {
+            if self.monitor_filter.matches(entry) {
+                self.monitor_stats.record(entry);
+            }
+        }
+        self.selected_monitor_index = 0;
+        self.monitor_scroll = 0;
+    }
+
+    /// Current rate of monitored commands matching the filter
+    pub fn monitor_ops_per_sec(&self) -> f64 {
+        if self.monitor_replay.is_some() {
+            // Average over the whole recording
+            let entries = self.visible_monitor_entries();
+            return match (entries.last(), entries.first()) {
+                (Some(first), Some(last)) if last.time > first.time => {
+                    entries.len() as f64 / (last.time - first.time)
+                }
+                _ => 0.0,
+            };
+        }
+        // Measure on the server's clock: the newest entry's timestamp plus the time since it
+        // arrived, so a skewed local clock doesn't push every entry out of the window
+        let Some((last_time, received)) = self.monitor_last_seen else {
+            return 0.0;
+        };
+        let now = last_time + received.elapsed().as_secs_f64();
+        self.monitor_stats.ops_per_sec(now)
+    }
+
+    /// Monitor entries passing the current filter, newest first
+    pub fn visible_monitor_entries(&self) -> Vec<&crate::model::MonitorEntry> {
+        self.monitor_entries
+            .iter()
+            .filter(|e| self.monitor_filter.matches(e))
+            .collect()
     }
 
     pub async fn fetch_streams(&mut self) -> Result<()> {
@@ -1110,6 +3751,8 @@ impl App {
                     });
                 }
             }
+            self.selected_streams
+                .retain(|name| streams.iter().any(|s| &s.name == name));
             self.streams = streams;
         }
         Ok(())
@@ -1158,3 +3801,415 @@ impl App {
         Ok(())
     }
 }
+
+/// Next window of the described collection, with the field TTLs of hash windows
+async fn fetch_window_with_ttls(
+    con: &mut redis::aio::MultiplexedConnection,
+    window: &mut DescribeWindow,
+) -> Result<(KeyValue, HashMap<String, i64>)> {
+    let fetched = values::fetch_window(con, window).await?;
+    let field_ttls = match &fetched {
+        KeyValue::Hash(fields) => fetch_field_ttls(con, &window.key, fields).await,
+        _ => HashMap::new(),
+    };
+    Ok((fetched, field_ttls))
+}
+
+/// Per-field TTLs of a hash in seconds, empty on servers without HTTL (before Redis 7.4)
+async fn fetch_field_ttls(
+    con: &mut redis::aio::MultiplexedConnection,
+    key: &str,
+    hash: &[(String, String)],
+) -> HashMap<String, i64> {
+    let fields: Vec<&String> = hash.iter().map(|(f, _)| f).collect();
+    let mut ttls = HashMap::new();
+    for chunk in fields.chunks(500) {
+        let result: redis::RedisResult<Vec<i64>> = redis::cmd("HTTL")
+            .arg(key)
+            .arg("FIELDS")
+            .arg(chunk.len())
+            .arg(chunk)
+            .query_async(con)
+            .await;
+        let Ok(values) = result else {
+            return HashMap::new();
+        };
+        for (field, ttl) in chunk.iter().zip(values) {
+            ttls.insert((*field).clone(), ttl);
+        }
+    }
+    ttls
+}
+
+fn is_moved(error: &redis::RedisError) -> bool {
+    error.kind() == redis::ErrorKind::Server(redis::ServerErrorKind::Moved)
+}
+
+/// WATCH `key` and check that the element at `index` is still `expected`, so a
+/// change by index doesn't hit another element after the list shifted
+async fn watch_list_element(
+    con: &mut redis::aio::MultiplexedConnection,
+    key: &str,
+    index: isize,
+    expected: &str,
+) -> Result<()> {
+    redis::cmd("WATCH").arg(key).query_async::<()>(con).await?;
+    let current: Option<String> = con.lindex(key, index).await?;
+    if current.as_deref() != Some(expected) {
+        redis::cmd("UNWATCH").query_async::<()>(con).await?;
+        anyhow::bail!("Element {} of {} changed since it was loaded", index, key);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn render_to_text(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+        terminal.draw(|f| crate::ui::render(f, app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn replay_shows_the_monitor_view() {
+        let path = std::env::temp_dir().join(format!("tredis-replay-{}.jsonl", std::process::id()));
+        std::fs::write(
+            &path,
+            "{\"timestamp\":1700000000.5,\"db\":\"0\",\"client\":\"127.0.0.1:5000\",\"args\":[\"SET\",\"replayed:key\",\"v\"]}\n\
+             {\"timestamp\":1700000001.5,\"db\":\"0\",\"client\":\"127.0.0.1:5000\",\"args\":[\"GET\",\"replayed:key\"]}\n",
+        )
+        .unwrap();
+        let entries = crate::monitor::load_recording(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut app = App::new();
+        app.open_replay(path, entries);
+
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.active_resource, "monitor");
+        assert_eq!(app.monitor_entries.len(), 2);
+        // Newest first
+        assert_eq!(app.monitor_entries[0].args[0], "GET");
+        let screen = render_to_text(&app);
+        assert!(screen.contains("replayed:key"));
+    }
+
+    fn stream_info(name: &str) -> crate::model::StreamInfo {
+        crate::model::StreamInfo {
+            name: name.to_string(),
+            length: 0,
+            first_entry_id: "-".to_string(),
+            last_entry_id: "-".to_string(),
+        }
+    }
+
+    fn entry(id: &str) -> crate::model::StreamEntry {
+        crate::model::StreamEntry {
+            id: id.to_string(),
+            fields: Default::default(),
+        }
+    }
+
+    #[test]
+    fn stream_messages_of_a_stopped_reader_are_dropped() {
+        let mut app = App::new();
+        app.streams = vec![stream_info("s")];
+        app.start_stream_read(StreamReadMode::Tail {
+            start_id: "$".to_string(),
+        });
+        let old = app.stream_generation;
+        app.receive_stream_message(old, "s".to_string(), entry("1-0"));
+
+        app.start_stream_read(StreamReadMode::Tail {
+            start_id: "0".to_string(),
+        });
+        app.receive_stream_message(old, "s".to_string(), entry("2-0"));
+        app.receive_stream_error(old, "gone".to_string());
+        assert!(app.stream_messages.is_empty());
+        assert_eq!(app.stream_read_error, None);
+
+        app.receive_stream_message(app.stream_generation, "s".to_string(), entry("3-0"));
+        assert_eq!(app.stream_messages.len(), 1);
+        assert_eq!(app.stream_messages[0].1.id, "3-0");
+    }
+
+    #[test]
+    fn tail_dialog_starts_at_new_entries() {
+        let mut app = App::new();
+        app.streams = vec![stream_info("s")];
+        app.open_tail_dialog();
+        assert_eq!(
+            app.submit_tail_dialog(),
+            Some(StreamReadMode::Tail {
+                start_id: "$".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn group_entries_are_acked_only_once_shown() {
+        let mut app = App::new();
+        app.streams = vec![stream_info("s")];
+        app.start_stream_read(StreamReadMode::Group {
+            group: "g".to_string(),
+            consumer: "c".to_string(),
+        });
+        let (ack_tx, mut acks) = tokio::sync::mpsc::unbounded_channel();
+        app.stream_acks = Some(ack_tx);
+
+        app.receive_stream_message(app.stream_generation - 1, "s".to_string(), entry("1-0"));
+        assert!(acks.try_recv().is_err());
+
+        app.receive_stream_message(app.stream_generation, "s".to_string(), entry("2-0"));
+        assert_eq!(
+            acks.try_recv().unwrap(),
+            ("s".to_string(), "2-0".to_string())
+        );
+
+        app.stop_stream_consumer();
+        assert!(app.stream_acks.is_none());
+    }
+
+    /// App connected to `server`, describing the list `l` holding `a b`, with
+    /// element 1 confirmed to be set to `c`
+    fn list_edit_app(server: &crate::fake_redis::FakeRedis) -> App {
+        let mut app = App::new();
+        app.connection_uri = Some(server.uri.clone());
+        app.describe_key = Some("l".to_string());
+        app.describe_data = KeyValue::List(vec!["a".to_string(), "b".to_string()]);
+        app.pending_action = Some(PendingAction {
+            key: "l".to_string(),
+            action_type: PendingActionType::ListSetItem,
+            selected_yes: true,
+            matched_keys: Vec::new(),
+            field_edit: Some(FieldEdit {
+                target: "1".to_string(),
+                value: "c".to_string(),
+            }),
+        });
+        app
+    }
+
+    #[tokio::test]
+    async fn list_set_refuses_an_element_that_moved() {
+        use crate::fake_redis::{bulk, FakeRedis};
+        // Someone pushed to the head of the list, index 1 now holds `a`
+        let server = FakeRedis::start(|cmd| match cmd[0].as_str() {
+            "LINDEX" => Some(bulk("a")),
+            _ => None,
+        })
+        .await;
+        let mut app = list_edit_app(&server);
+
+        let err = app.apply_field_edit().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Element 1 of l changed since it was loaded"
+        );
+        let names = server.command_names();
+        assert!(names.contains(&"UNWATCH".to_string()));
+        assert!(!names.contains(&"LSET".to_string()));
+    }
+
+    #[tokio::test]
+    async fn list_set_writes_an_unchanged_element() {
+        use crate::fake_redis::{bulk, FakeRedis};
+        let server = FakeRedis::start(|cmd| match cmd[0].as_str() {
+            "LINDEX" => Some(bulk("b")),
+            "LSET" => Some("+QUEUED\r\n".to_string()),
+            "EXEC" => Some("*1\r\n+OK\r\n".to_string()),
+            _ => None,
+        })
+        .await;
+        let app = list_edit_app(&server);
+
+        app.set_list_item(
+            "l",
+            &FieldEdit {
+                target: "1".to_string(),
+                value: "c".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let names = server.command_names();
+        let tail: Vec<&str> = names
+            .iter()
+            .rev()
+            .take(5)
+            .rev()
+            .map(|s| s.as_str())
+            .collect();
+        assert_eq!(tail, vec!["WATCH", "LINDEX", "MULTI", "LSET", "EXEC"]);
+    }
+
+    #[tokio::test]
+    async fn ttl_change_resends_only_moved_keys() {
+        use crate::fake_redis::FakeRedis;
+        // One key on each half of the slots, which move from `a` to `b`
+        let key_in = |half: std::ops::Range<u16>| {
+            (0..)
+                .map(|n| format!("key{}", n))
+                .find(|k| half.contains(&cluster::key_slot(k)))
+                .unwrap()
+        };
+        let (stays, moves) = (key_in(0..8192), key_in(8192..16384));
+
+        let b = FakeRedis::start(|cmd| (cmd[0] == "EXPIRE").then(|| ":1\r\n".to_string())).await;
+        let b_addr = b.uri.trim_start_matches("redis://").to_string();
+        let a = {
+            let (moves, b_addr) = (moves.clone(), b_addr.clone());
+            let a_addr = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+            let nodes_addr = a_addr.clone();
+            let server = FakeRedis::start(move |cmd| match cmd[0].as_str() {
+                "EXPIRE" if cmd[1] == moves => Some(format!(
+                    "-MOVED {} {}\r\n",
+                    cluster::key_slot(&moves),
+                    b_addr
+                )),
+                "EXPIRE" => Some(":1\r\n".to_string()),
+                "CLUSTER" => Some(crate::fake_redis::bulk(&format!(
+                    "a {}@1 master - 0 0 1 connected 0-8191\nb {}@1 master - 0 0 2 connected 8192-16383\n",
+                    nodes_addr.lock().unwrap(),
+                    b_addr
+                ))),
+                _ => None,
+            })
+            .await;
+            *a_addr.lock().unwrap() = server.uri.trim_start_matches("redis://").to_string();
+            server
+        };
+
+        let a_addr = a.uri.trim_start_matches("redis://").to_string();
+        let (host, port) = a_addr.rsplit_once(':').unwrap();
+        let connection = redis::Client::open(a.uri.as_str())
+            .unwrap()
+            .get_multiplexed_async_connection()
+            .await
+            .unwrap();
+        let mut app = App::new();
+        app.connection_uri = Some(a.uri.clone());
+        app.cluster = Some(cluster::ClusterConnections {
+            primaries: vec![cluster::PrimaryConnection {
+                node: ClusterNode {
+                    id: "a".to_string(),
+                    addr: a_addr.clone(),
+                    host: host.to_string(),
+                    port: port.parse().unwrap(),
+                    flags: "master".to_string(),
+                    master_id: "-".to_string(),
+                    link_state: "connected".to_string(),
+                    slots: vec![(0, 16383)],
+                },
+                connection,
+            }],
+        });
+        app.pending_action = Some(PendingAction {
+            key: String::new(),
+            action_type: PendingActionType::SetTtl,
+            selected_yes: true,
+            matched_keys: vec![stays.clone(), moves.clone()],
+            field_edit: Some(FieldEdit {
+                target: String::new(),
+                value: "60".to_string(),
+            }),
+        });
+
+        assert_eq!(app.apply_ttl_change().await.unwrap(), 2);
+        assert_eq!(
+            app.key_message.as_deref(),
+            Some("Changed the TTL of 2 of 2 keys")
+        );
+        let expires = |server: &FakeRedis| -> Vec<String> {
+            server
+                .commands()
+                .into_iter()
+                .filter(|c| c[0] == "EXPIRE")
+                .map(|c| c[1].clone())
+                .collect()
+        };
+        assert_eq!(expires(&a), vec![stays.clone(), moves.clone()]);
+        assert_eq!(expires(&b), vec![moves]);
+    }
+
+    #[test]
+    fn memory_sort_cycles_and_orders_the_keys() {
+        let key = |name: &str, memory_usage| KeyInfo {
+            key: name.to_string(),
+            key_type: "string".to_string(),
+            pttl: -1,
+            memory_usage,
+            encoding: "embstr".to_string(),
+        };
+        let mut app = App::new();
+        app.all_keys = vec![key("b", 200), key("a", 50), key("c", 1000)];
+        let names =
+            |app: &App| -> Vec<String> { app.scan_result.iter().map(|k| k.key.clone()).collect() };
+
+        app.apply_filter();
+        assert_eq!(names(&app), ["b", "a", "c"]);
+
+        app.key_sort = app.key_sort.next();
+        assert_eq!(app.key_sort, KeySort::MemoryDesc);
+        app.apply_filter();
+        assert_eq!(names(&app), ["c", "b", "a"]);
+
+        app.key_sort = app.key_sort.next();
+        app.apply_filter();
+        assert_eq!(names(&app), ["a", "b", "c"]);
+
+        app.key_sort = app.key_sort.next();
+        assert_eq!(app.key_sort, KeySort::Scan);
+        app.apply_filter();
+        assert_eq!(names(&app), ["b", "a", "c"]);
+    }
+
+    #[tokio::test]
+    async fn move_keys_reports_each_failed_key() {
+        use crate::fake_redis::FakeRedis;
+        let server = FakeRedis::start(|cmd| match (cmd[0].as_str(), cmd[1].as_str()) {
+            ("MOVE", "done") => Some(":1\r\n".to_string()),
+            ("MOVE", "taken") => Some(":0\r\n".to_string()),
+            ("MOVE", _) => Some("-ERR source and destination objects are the same\r\n".to_string()),
+            _ => None,
+        })
+        .await;
+        let client = redis::Client::open(server.uri.as_str()).unwrap();
+        let mut app = App::new();
+        app.connection = Some(client.get_multiplexed_async_connection().await.unwrap());
+        let keys = ["done", "taken", "same"].map(String::from);
+        app.selected_keys = keys.iter().cloned().collect();
+        app.pending_action = Some(PendingAction {
+            key: String::new(),
+            action_type: PendingActionType::MoveKeys,
+            selected_yes: true,
+            matched_keys: keys.to_vec(),
+            field_edit: Some(FieldEdit {
+                target: String::new(),
+                value: "2".to_string(),
+            }),
+        });
+
+        app.move_keys().await.unwrap();
+        assert_eq!(
+            app.key_message.as_deref(),
+            Some(
+                "Moved 1 of 3 keys to db 2, failed: taken (exists in db 2 or gone), \
+                 same (ResponseError: source and destination objects are the same)"
+            )
+        );
+        // Keys that failed stay selected for another attempt
+        let mut selected: Vec<&str> = app.selected_keys.iter().map(|k| k.as_str()).collect();
+        selected.sort();
+        assert_eq!(selected, ["same", "taken"]);
+    }
+}
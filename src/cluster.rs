@@ -0,0 +1,301 @@
+use crate::connection::node_uri;
+use crate::model::ClusterNode;
+use anyhow::{bail, Result};
+use redis::aio::MultiplexedConnection;
+
+/// Number of hash slots in a Redis Cluster
+pub const SLOT_COUNT: u16 = 16384;
+
+/// A connection to a single cluster primary together with the slots it serves
+#[derive(Clone)]
+pub struct PrimaryConnection {
+    pub node: ClusterNode,
+    pub connection: MultiplexedConnection,
+}
+
+/// Connections to every primary of a cluster, used to fan out SCAN and
+/// to route key commands to the node that owns the key's slot
+#[derive(Clone, Default)]
+pub struct ClusterConnections {
+    pub primaries: Vec<PrimaryConnection>,
+}
+
+impl ClusterConnections {
+    /// Discover the cluster topology through `con` and open a connection to each primary.
+    /// Returns `None` when the server is not running in cluster mode.
+    pub async fn discover(con: &mut MultiplexedConnection, base_uri: &str) -> Result<Option<Self>> {
+        let nodes_str: String = match redis::cmd("CLUSTER").arg("NODES").query_async(con).await {
+            Ok(s) => s,
+            Err(_) => return Ok(None),
+        };
+
+        let primaries = connect_primaries(&nodes_str, base_uri, Vec::new()).await?;
+        if primaries.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { primaries }))
+    }
+
+    /// Reload the slot map after a MOVED redirect, asking the first primary that answers
+    /// and keeping the connections to primaries that are still part of the cluster
+    pub async fn refresh(&mut self, base_uri: &str) -> Result<()> {
+        let mut nodes_str = None;
+        for primary in &mut self.primaries {
+            if let Ok(s) = redis::cmd("CLUSTER")
+                .arg("NODES")
+                .query_async::<String>(&mut primary.connection)
+                .await
+            {
+                nodes_str = Some(s);
+                break;
+            }
+        }
+        let Some(nodes_str) = nodes_str else {
+            bail!("No cluster primary answered CLUSTER NODES");
+        };
+
+        let existing = std::mem::take(&mut self.primaries);
+        let primaries = connect_primaries(&nodes_str, base_uri, existing).await?;
+        if primaries.is_empty() {
+            bail!("Cluster has no primary serving slots");
+        }
+        self.primaries = primaries;
+        Ok(())
+    }
+
+    /// Index of the primary serving `slot`
+    pub fn primary_for_slot(&self, slot: u16) -> Option<usize> {
+        self.primaries.iter().position(|p| {
+            p.node
+                .slots
+                .iter()
+                .any(|(start, end)| slot >= *start && slot <= *end)
+        })
+    }
+
+    /// Connection to the primary that owns `key`
+    pub fn connection_for_key(&mut self, key: &str) -> Option<&mut MultiplexedConnection> {
+        let idx = self.primary_for_slot(key_slot(key))?;
+        Some(&mut self.primaries[idx].connection)
+    }
+}
+
+/// Open a connection to every healthy primary that serves slots, reusing the
+/// connection from `existing` when the primary was already known
+async fn connect_primaries(
+    nodes_str: &str,
+    base_uri: &str,
+    mut existing: Vec<PrimaryConnection>,
+) -> Result<Vec<PrimaryConnection>> {
+    let mut primaries = Vec::new();
+    for node in parse_cluster_nodes(nodes_str) {
+        if !node.is_primary() || node.slots.is_empty() || node.is_failed() {
+            continue;
+        }
+        let connection = match existing.iter().position(|p| p.node.addr == node.addr) {
+            Some(idx) => existing.swap_remove(idx).connection,
+            None => {
+                let client = redis::Client::open(node_uri(base_uri, &node.host, node.port))?;
+                client.get_multiplexed_async_connection().await?
+            }
+        };
+        primaries.push(PrimaryConnection { node, connection });
+    }
+
+    // Keep a stable order so composite scan cursors stay valid across refreshes
+    primaries.sort_by(|a, b| a.node.addr.cmp(&b.node.addr));
+    Ok(primaries)
+}
+
+/// Parse the output of `CLUSTER NODES`
+pub fn parse_cluster_nodes(output: &str) -> Vec<ClusterNode> {
+    let mut nodes = Vec::new();
+
+    for line in output.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 8 {
+            continue;
+        }
+
+        // Address format: ip:port@cport[,hostname]
+        let addr = parts[1].split(['@', ',']).next().unwrap_or("");
+        let (host, port) = match addr.rfind(':') {
+            Some(colon_pos) => (
+                addr[..colon_pos].to_string(),
+                addr[colon_pos + 1..].parse().unwrap_or(0),
+            ),
+            None => (addr.to_string(), 0),
+        };
+
+        let mut slots = Vec::new();
+        for slot in &parts[8..] {
+            // Skip importing/migrating markers like [1234->-nodeid]
+            if slot.starts_with('[') {
+                continue;
+            }
+            if let Some((start, end)) = slot.split_once('-') {
+                if let (Ok(start), Ok(end)) = (start.parse(), end.parse()) {
+                    slots.push((start, end));
+                }
+            } else if let Ok(single) = slot.parse() {
+                slots.push((single, single));
+            }
+        }
+
+        nodes.push(ClusterNode {
+            id: parts[0].to_string(),
+            addr: addr.to_string(),
+            host,
+            port,
+            flags: parts[2].to_string(),
+            master_id: parts[3].to_string(),
+            link_state: parts[7].to_string(),
+            slots,
+        });
+    }
+
+    nodes
+}
+
+/// Primaries ordered by address, each followed by its replicas. Replicas whose
+/// primary is unknown (e.g. during a failover) come last.
+pub fn order_nodes(nodes: Vec<ClusterNode>) -> Vec<ClusterNode> {
+    let mut primaries: Vec<&ClusterNode> = nodes.iter().filter(|n| n.is_primary()).collect();
+    primaries.sort_by(|a, b| a.addr.cmp(&b.addr));
+
+    let mut ordered = Vec::new();
+    for primary in primaries {
+        ordered.push(primary.clone());
+        let mut replicas: Vec<&ClusterNode> =
+            nodes.iter().filter(|n| n.master_id == primary.id).collect();
+        replicas.sort_by(|a, b| a.addr.cmp(&b.addr));
+        ordered.extend(replicas.into_iter().cloned());
+    }
+    for node in &nodes {
+        if !ordered.iter().any(|n| n.id == node.id) {
+            ordered.push(node.clone());
+        }
+    }
+    ordered
+}
+
+/// Compute the hash slot for a key, honouring `{hash tags}`
+pub fn key_slot(key: &str) -> u16 {
+    let bytes = key.as_bytes();
+    let hashed = match bytes.iter().position(|&b| b == b'{') {
+        Some(open) => match bytes[open + 1..].iter().position(|&b| b == b'}') {
+            Some(len) if len > 0 => &bytes[open + 1..open + 1 + len],
+            _ => bytes,
+        },
+        None => bytes,
+    };
+    crc16(hashed) % SLOT_COUNT
+}
+
+/// The slot shared by all `keys`, which multi-key commands such as XREAD need
+/// in a cluster. `None` when they hash to different slots or there are none.
+pub fn shared_slot(keys: &[String]) -> Option<u16> {
+    let slot = key_slot(keys.first()?);
+    keys.iter().all(|k| key_slot(k) == slot).then_some(slot)
+}
+
+/// CRC16-CCITT (XMODEM) as used by Redis Cluster
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc16_matches_reference_vector() {
+        // Check value from the Redis Cluster specification
+        assert_eq!(crc16(b"123456789"), 0x31C3);
+        assert_eq!(crc16(b""), 0);
+    }
+
+    #[test]
+    fn key_slot_of_plain_keys() {
+        assert_eq!(key_slot("foo"), 12182);
+        assert_eq!(key_slot("bar"), 5061);
+        assert_eq!(key_slot("123456789"), 0x31C3 % SLOT_COUNT);
+    }
+
+    #[test]
+    fn key_slot_uses_hash_tag() {
+        assert_eq!(key_slot("{user1000}.following"), key_slot("user1000"));
+        assert_eq!(key_slot("{user1000}.followers"), key_slot("user1000"));
+        // Only the first {...} counts
+        assert_eq!(key_slot("foo{bar}{zap}"), key_slot("bar"));
+        assert_eq!(key_slot("foo{{bar}}zap"), key_slot("{bar"));
+    }
+
+    #[test]
+    fn key_slot_ignores_empty_or_unclosed_tags() {
+        assert_eq!(key_slot("foo{}{bar}"), crc16(b"foo{}{bar}") % SLOT_COUNT);
+        assert_eq!(key_slot("foo{bar"), crc16(b"foo{bar") % SLOT_COUNT);
+    }
+
+    #[test]
+    fn parse_cluster_nodes_reads_slots_and_addresses() {
+        let output = "07c37dfeb235213a872192d90877d0cd55635b91 127.0.0.1:30004@31004,host-a slave e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 0 1426238317239 4 connected\n\
+                      e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 127.0.0.1:30001@31001 myself,master - 0 0 1 connected 0-5460 5462 [5461->-67ed2db8d677e59ec4a4cefb06858cf2a1a89fa1]\n";
+        let nodes = parse_cluster_nodes(output);
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].addr, "127.0.0.1:30004");
+        assert_eq!(nodes[0].port, 30004);
+        assert!(nodes[0].slots.is_empty());
+        assert_eq!(nodes[1].host, "127.0.0.1");
+        assert_eq!(nodes[1].slots, vec![(0, 5460), (5462, 5462)]);
+    }
+
+    #[test]
+    fn shared_slot_needs_one_slot_for_all_keys() {
+        let keys = |names: &[&str]| names.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            shared_slot(&keys(&["{orders}:a", "{orders}:b"])),
+            Some(key_slot("orders"))
+        );
+        assert_eq!(shared_slot(&keys(&["foo", "bar"])), None);
+        assert_eq!(shared_slot(&[]), None);
+    }
+
+    #[test]
+    fn order_nodes_groups_replicas_under_their_primary() {
+        let output = "r2 10.0.0.9:7005@1 slave p1 0 0 1 connected\n\
+                      p2 10.0.0.2:7001@1 master - 0 0 2 connected 8192-16383\n\
+                      orphan 10.0.0.7:7006@1 slave gone 0 0 1 connected\n\
+                      r1 10.0.0.8:7004@1 slave p1 0 0 1 connected\n\
+                      p1 10.0.0.1:7000@1 myself,master - 0 0 1 connected 0-8190 8191\n";
+        let nodes = order_nodes(parse_cluster_nodes(output));
+        let ids: Vec<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["p1", "r1", "r2", "p2", "orphan"]);
+        assert_eq!(nodes[0].role(), "primary");
+        assert_eq!(nodes[1].role(), "replica");
+        assert_eq!(nodes[0].slot_ranges(), "0-8190,8191");
+        assert_eq!(nodes[1].slot_ranges(), "");
+    }
+
+    #[test]
+    fn failed_nodes_are_flagged() {
+        let nodes = parse_cluster_nodes(
+            "a 10.0.0.1:7000@1 master,fail - 0 0 1 disconnected 0-100\n\
+             b :0@0 master,noaddr - 0 0 1 disconnected\n\
+             c 10.0.0.3:7000@1 master - 0 0 1 connected 101-200\n",
+        );
+        let failed: Vec<bool> = nodes.iter().map(|n| n.is_failed()).collect();
+        assert_eq!(failed, vec![true, true, false]);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::model::DecoderConfig;
+use crate::viewer;
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use serde_json::Value;
+
+/// One step of a decoder chain such as `gzip+json`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DecodeStep {
+    Base64,
+    Gzip,
+    Zstd,
+    Lz4,
+    Json,
+    Msgpack,
+    Protobuf,
+}
+
+impl DecodeStep {
+    fn parse(name: &str) -> Result<Self> {
+        Ok(match name.trim().to_lowercase().as_str() {
+            "base64" => DecodeStep::Base64,
+            "gzip" => DecodeStep::Gzip,
+            "zstd" => DecodeStep::Zstd,
+            "lz4" => DecodeStep::Lz4,
+            "json" => DecodeStep::Json,
+            "msgpack" | "messagepack" => DecodeStep::Msgpack,
+            "protobuf" | "proto" => DecodeStep::Protobuf,
+            other => bail!("Unknown decoder step: {}", other),
+        })
+    }
+
+    /// Steps that turn bytes into a structure, which must come last in a chain
+    fn is_structured(&self) -> bool {
+        matches!(
+            self,
+            DecodeStep::Json | DecodeStep::Msgpack | DecodeStep::Protobuf
+        )
+    }
+}
+
+/// A decoder from the config with its chain parsed and, for protobuf, the
+/// message descriptor loaded once
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    pub pattern: String, // Key glob
+    pub label: String,   // The chain as written in the config
+    steps: Vec<DecodeStep>,
+    message: Option<MessageDescriptor>,
+}
+
+impl Decoder {
+    /// Check the chain of `config` and load its descriptor set
+    pub fn new(config: &DecoderConfig) -> Result<Self> {
+        let steps = config
+            .decoder
+            .split('+')
+            .map(DecodeStep::parse)
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(pos) = steps.iter().position(|s| s.is_structured()) {
+            if pos + 1 != steps.len() {
+                bail!("json, msgpack and protobuf must be the last step of a decoder");
+            }
+        }
+        let message = if steps.last() == Some(&DecodeStep::Protobuf) {
+            Some(load_message(config)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            pattern: config.pattern.clone(),
+            label: config.decoder.clone(),
+            steps,
+            message,
+        })
+    }
+
+    /// Decode `bytes` with the chain and render the result. Byte steps (base64,
+    /// decompression) run in order; a final json, msgpack or protobuf step is
+    /// rendered as pretty-printed JSON.
+    pub fn decode(&self, bytes: &[u8]) -> Result<String> {
+        let mut data = bytes.to_vec();
+        for step in &self.steps {
+            data = match step {
+                DecodeStep::Base64 => base64::engine::general_purpose::STANDARD
+                    .decode(data.trim_ascii())
+                    .context("base64")?,
+                DecodeStep::Gzip => viewer::gunzip(&data).context("gzip")?,
+                DecodeStep::Zstd => viewer::unzstd(&data).context("zstd")?,
+                DecodeStep::Lz4 => viewer::unlz4(&data).context("lz4")?,
+                DecodeStep::Json => {
+                    let value: Value = serde_json::from_slice(&data).context("json")?;
+                    return Ok(serde_json::to_string_pretty(&value)?);
+                }
+                DecodeStep::Msgpack => {
+                    let value =
+                        rmpv::decode::read_value(&mut data.as_slice()).context("msgpack")?;
+                    return Ok(serde_json::to_string_pretty(&msgpack_to_json(value))?);
+                }
+                DecodeStep::Protobuf => {
+                    let Some(descriptor) = &self.message else {
+                        bail!("protobuf descriptor not loaded");
+                    };
+                    let message = DynamicMessage::decode(descriptor.clone(), data.as_slice())
+                        .context("protobuf")?;
+                    return Ok(serde_json::to_string_pretty(&message)?);
+                }
+            };
+        }
+        Ok(viewer::render_decompressed(&data))
+    }
+}
+
+/// Build the decoders of the config. Decoders with an invalid chain or an
+/// unreadable descriptor set are left out and described in the returned errors.
+pub fn load(configs: &[DecoderConfig]) -> (Vec<Decoder>, Vec<String>) {
+    let mut decoders = Vec::new();
+    let mut errors = Vec::new();
+    for config in configs {
+        match Decoder::new(config) {
+            Ok(decoder) => decoders.push(decoder),
+            Err(e) => errors.push(format!(
+                "Decoder {} for {}: {:#}",
+                config.decoder, config.pattern, e
+            )),
+        }
+    }
+    (decoders, errors)
+}
+
+/// Decoder for `key`, the first whose pattern matches
+pub fn decoder_for<'a>(decoders: &'a [Decoder], key: &str) -> Option<&'a Decoder> {
+    decoders
+        .iter()
+        .find(|d| crate::glob::glob_match(&d.pattern, key))
+}
+
+/// Message descriptor from the descriptor set (`protoc --include_imports --descriptor_set_out`)
+/// and message name configured for a protobuf decoder
+fn load_message(config: &DecoderConfig) -> Result<MessageDescriptor> {
+    let (Some(path), Some(message)) = (&config.descriptor_set, &config.message) else {
+        bail!("protobuf decoders need `descriptor_set` and `message`");
+    };
+    let path = expand_home(path);
+    let descriptors = std::fs::read(&path)
+        .with_context(|| format!("Cannot read descriptor set {}", path.display()))?;
+    let pool = DescriptorPool::decode(descriptors.as_slice())?;
+    let Some(descriptor) = pool.get_message_by_name(message) else {
+        bail!("Message {} not found in {}", message, path.display());
+    };
+    Ok(descriptor)
+}
+
+fn expand_home(path: &str) -> std::path::PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => std::path::PathBuf::from(path),
+    }
+}
+
+/// MessagePack values as JSON. Binary and extension data become base64 strings
+/// and non-string map keys are written in their JSON form.
+fn msgpack_to_json(value: rmpv::Value) -> Value {
+    let b64 = |data: &[u8]| base64::engine::general_purpose::STANDARD.encode(data);
+    match value {
+        rmpv::Value::Nil => Value::Null,
+        rmpv::Value::Boolean(b) => Value::Bool(b),
+        rmpv::Value::Integer(i) => match (i.as_i64(), i.as_u64()) {
+            (Some(n), _) => Value::from(n),
+            (_, Some(n)) => Value::from(n),
+            _ => Value::from(i.as_f64()),
+        },
+        rmpv::Value::F32(f) => Value::from(f),
+        rmpv::Value::F64(f) => Value::from(f),
+        rmpv::Value::String(s) => match s.as_str() {
+            Some(s) => Value::String(s.to_string()),
+            None => Value::String(b64(s.as_bytes())),
+        },
+        rmpv::Value::Binary(data) => Value::String(b64(&data)),
+        rmpv::Value::Array(items) => Value::Array(items.into_iter().map(msgpack_to_json).collect()),
+        rmpv::Value::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(k, v)| {
+                    let key = match msgpack_to_json(k) {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    (key, msgpack_to_json(v))
+                })
+                .collect(),
+        ),
+        rmpv::Value::Ext(tag, data) => serde_json::json!({ "ext": tag, "data": b64(&data) }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn config(decoder: &str) -> DecoderConfig {
+        DecoderConfig {
+            pattern: "*".to_string(),
+            decoder: decoder.to_string(),
+            descriptor_set: None,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_steps() {
+        let err = Decoder::new(&config("gzip+yaml")).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown decoder step: yaml");
+    }
+
+    #[test]
+    fn rejects_structured_step_before_the_end() {
+        assert!(Decoder::new(&config("json+gzip")).is_err());
+        assert!(Decoder::new(&config("msgpack+json")).is_err());
+        assert!(Decoder::new(&config("base64+gzip+json")).is_ok());
+    }
+
+    #[test]
+    fn protobuf_needs_a_descriptor_set() {
+        let err = Decoder::new(&config("protobuf")).unwrap_err();
+        assert!(err.to_string().contains("descriptor_set"));
+    }
+
+    #[test]
+    fn load_keeps_valid_decoders_and_reports_the_rest() {
+        let (decoders, errors) = load(&[config("json"), config("nope")]);
+        assert_eq!(decoders.len(), 1);
+        assert_eq!(
+            errors,
+            vec!["Decoder nope for *: Unknown decoder step: nope"]
+        );
+    }
+
+    #[test]
+    fn decodes_base64_gzip_json_chain() {
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        gz.write_all(br#"{"a":1}"#).unwrap();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(gz.finish().unwrap());
+        let decoder = Decoder::new(&config("base64+gzip+json")).unwrap();
+        assert_eq!(
+            decoder.decode(encoded.as_bytes()).unwrap(),
+            "{\n  \"a\": 1\n}"
+        );
+    }
+
+    #[test]
+    fn decodes_msgpack_to_json() {
+        let mut buf = Vec::new();
+        rmpv::encode::write_value(
+            &mut buf,
+            &rmpv::Value::Map(vec![(
+                rmpv::Value::from("n"),
+                rmpv::Value::Binary(vec![1, 2]),
+            )]),
+        )
+        .unwrap();
+        let decoder = Decoder::new(&config("msgpack")).unwrap();
+        assert_eq!(decoder.decode(&buf).unwrap(), "{\n  \"n\": \"AQI=\"\n}");
+    }
+
+    #[test]
+    fn decoder_for_takes_first_match() {
+        let mut first = config("json");
+        first.pattern = "user:*".to_string();
+        let (decoders, _) = load(&[first, config("msgpack")]);
+        assert_eq!(decoder_for(&decoders, "user:1").unwrap().label, "json");
+        assert_eq!(decoder_for(&decoders, "other").unwrap().label, "msgpack");
+    }
+}
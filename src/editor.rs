@@ -0,0 +1,290 @@
+use anyhow::{bail, Result};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Minimal multiline text editor state used for editing values in the TUI.
+/// Cursor columns count characters, not bytes.
+#[derive(Debug, Clone, Default)]
+pub struct TextEditor {
+    pub lines: Vec<String>,
+    pub row: usize,
+    pub col: usize,
+    pub scroll: usize,
+}
+
+impl TextEditor {
+    pub fn new(text: &str) -> Self {
+        let mut lines: Vec<String> = text.split('\n').map(String::from).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        Self {
+            lines,
+            ..Self::default()
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    fn byte_index(&self) -> usize {
+        self.lines[self.row]
+            .char_indices()
+            .nth(self.col)
+            .map(|(i, _)| i)
+            .unwrap_or(self.lines[self.row].len())
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let idx = self.byte_index();
+        self.lines[self.row].insert(idx, c);
+        self.col += 1;
+    }
+
+    pub fn insert_newline(&mut self) {
+        let idx = self.byte_index();
+        let rest = self.lines[self.row].split_off(idx);
+        self.row += 1;
+        self.lines.insert(self.row, rest);
+        self.col = 0;
+    }
+
+    pub fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let idx = self.byte_index();
+            self.lines[self.row].remove(idx);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len(self.row);
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.col < self.line_len(self.row) {
+            let idx = self.byte_index();
+            self.lines[self.row].remove(idx);
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.line_len(self.row);
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.col < self.line_len(self.row) {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if self.row > 0 {
+            self.row -= 1;
+            self.col = self.col.min(self.line_len(self.row));
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = self.col.min(self.line_len(self.row));
+        }
+    }
+
+    pub fn move_home(&mut self) {
+        self.col = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.col = self.line_len(self.row);
+    }
+
+    /// Keep the cursor row inside a viewport of `height` lines
+    pub fn scroll_to_cursor(&mut self, height: usize) {
+        if self.row < self.scroll {
+            self.scroll = self.row;
+        } else if height > 0 && self.row >= self.scroll + height {
+            self.scroll = self.row + 1 - height;
+        }
+    }
+}
+
+/// A string value being edited, together with the value it was loaded with
+#[derive(Debug, Clone)]
+pub struct ValueEditor {
+    pub key: String,
+    pub original: Vec<u8>, // Value the edit is based on, compared byte for byte before saving
+    pub editor: TextEditor,
+    pub message: Option<String>, // Save error or conflict warning
+}
+
+impl ValueEditor {
+    pub fn new(key: String, original: String) -> Self {
+        let editor = TextEditor::new(&original);
+        Self {
+            key,
+            original: original.into_bytes(),
+            editor,
+            message: None,
+        }
+    }
+
+    /// Take a conflicting save into account: the edit is now based on `current`,
+    /// so saving again overwrites it. Nothing can be saved when the key is gone.
+    pub fn conflict(&mut self, current: Option<Vec<u8>>) {
+        match current {
+            Some(current) => {
+                self.message = Some(
+                    "Key changed on the server since it was loaded, save again to overwrite"
+                        .to_string(),
+                );
+                self.original = current;
+            }
+            None => self.message = Some("Key no longer exists, not saved".to_string()),
+        }
+    }
+}
+
+/// Result of writing an edited value back
+#[derive(Debug)]
+pub enum SaveOutcome {
+    Saved,
+    Conflict(Option<Vec<u8>>), // Value currently on the server, `None` if the key is gone
+}
+
+/// Open `text` in `$VISUAL` / `$EDITOR` (falling back to `vi`) through a temp file
+/// and return the edited contents. The caller must release the terminal first.
+pub fn edit_externally(key: &str, text: &str) -> Result<String> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    // Editors such as `code --wait` come with arguments
+    let mut parts = editor.split_whitespace();
+    let Some(program) = parts.next() else {
+        bail!("$EDITOR is empty");
+    };
+
+    let name: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .take(40)
+        .collect();
+    let (path, mut file) = create_private_file(&name)?;
+    let written = file.write_all(text.as_bytes());
+    drop(file);
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&path);
+        return Err(e.into());
+    }
+
+    let status = Command::new(program).args(parts).arg(&path).status();
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+
+    let status = status?;
+    if !status.success() {
+        bail!("{} exited with {}", program, status);
+    }
+    Ok(edited?)
+}
+
+/// Create a new temp file readable only by the user. The name is unpredictable
+/// and `create_new` refuses to follow a file or symlink already at that path.
+fn create_private_file(name: &str) -> Result<(PathBuf, File)> {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let dir = std::env::temp_dir();
+    for _ in 0..16 {
+        // RandomState is seeded randomly per process
+        let random = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        let path = dir.join(format!("tredis-{:016x}-{}.txt", random, name));
+        match options.open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    bail!("Cannot create a temp file in {}", dir.display())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_editor_inserts_and_splits_lines_by_character() {
+        let mut editor = TextEditor::new("héllo");
+        editor.col = 2;
+        editor.insert_char('X');
+        editor.insert_newline();
+        assert_eq!(editor.text(), "héX\nllo");
+        assert_eq!((editor.row, editor.col), (1, 0));
+        editor.backspace();
+        assert_eq!(editor.text(), "héXllo");
+        assert_eq!((editor.row, editor.col), (0, 3));
+    }
+
+    #[test]
+    fn conflict_rebases_on_the_raw_server_value() {
+        let mut editor = ValueEditor::new("k".to_string(), "old".to_string());
+        assert_eq!(editor.original, b"old");
+
+        let current = vec![0xff, 0xfe];
+        editor.conflict(Some(current.clone()));
+        assert!(editor.message.is_some());
+        // A second save compares against the same bytes, although they aren't UTF-8
+        assert_eq!(editor.original, current);
+
+        editor.conflict(None);
+        assert_eq!(
+            editor.message.as_deref(),
+            Some("Key no longer exists, not saved")
+        );
+    }
+
+    #[test]
+    fn private_files_are_new_and_owner_only() {
+        let (path, _) = create_private_file("test").unwrap();
+        let (other, _) = create_private_file("test").unwrap();
+        assert_ne!(path, other);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&other).unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+//! Minimal RESP server for tests of code that talks to Redis
+
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+type Handler = dyn Fn(&[String]) -> Option<String> + Send + Sync;
+
+/// A server answering each command with the raw RESP reply of a handler, `+OK`
+/// when the handler has none. Commands are recorded in the order received.
+pub struct FakeRedis {
+    pub uri: String,
+    commands: Arc<Mutex<Vec<Vec<String>>>>,
+}
+
+impl FakeRedis {
+    pub async fn start(
+        handler: impl Fn(&[String]) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = format!("redis://{}", listener.local_addr().unwrap());
+        let commands = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let recorded = commands.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let (handler, recorded) = (handler.clone(), recorded.clone());
+                tokio::spawn(async move {
+                    let (read, mut write) = socket.into_split();
+                    let mut read = BufReader::new(read);
+                    while let Some(command) = read_command(&mut read).await {
+                        let reply = handler(&command).unwrap_or_else(|| "+OK\r\n".to_string());
+                        recorded.lock().unwrap().push(command);
+                        if write.write_all(reply.as_bytes()).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+        Self { uri, commands }
+    }
+
+    /// Commands received so far with their arguments
+    pub fn commands(&self) -> Vec<Vec<String>> {
+        self.commands.lock().unwrap().clone()
+    }
+
+    /// Names of the commands received so far, uppercased
+    pub fn command_names(&self) -> Vec<String> {
+        self.commands
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|c| c.first().map(|name| name.to_uppercase()))
+            .collect()
+    }
+}
+
+/// A RESP bulk string
+pub fn bulk(value: &str) -> String {
+    format!("${}\r\n{}\r\n", value.len(), value)
+}
+
+/// Read one command sent as an array of bulk strings
+async fn read_command<R: AsyncBufReadExt + Unpin>(read: &mut R) -> Option<Vec<String>> {
+    let count: usize = read_line(read).await?.strip_prefix('*')?.parse().ok()?;
+    let mut args = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len: usize = read_line(read).await?.strip_prefix('$')?.parse().ok()?;
+        let mut data = vec![0; len + 2];
+        read.read_exact(&mut data).await.ok()?;
+        data.truncate(len);
+        args.push(String::from_utf8_lossy(&data).into_owned());
+    }
+    Some(args)
+}
+
+async fn read_line<R: AsyncBufReadExt + Unpin>(read: &mut R) -> Option<String> {
+    let mut line = String::new();
+    match read.read_line(&mut line).await {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim_end().to_string()),
+    }
+}
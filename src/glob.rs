@@ -0,0 +1,117 @@
+/// Match `text` against a Redis-style glob pattern (`*`, `?`, `[abc]`, `[^a-z]`, `\x`)
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    match_from(&pattern, &text)
+}
+
+fn match_from(pattern: &[char], text: &[char]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // Position to resume from after the last `*`: (pattern index, text index)
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        let mut matched = false;
+        let mut next_p = p;
+
+        if p < pattern.len() {
+            match pattern[p] {
+                '*' => {
+                    backtrack = Some((p, t));
+                    p += 1;
+                    continue;
+                }
+                '?' => {
+                    matched = true;
+                    next_p = p + 1;
+                }
+                '[' => {
+                    if let Some((is_match, end)) = match_class(pattern, p, text[t]) {
+                        matched = is_match;
+                        next_p = end;
+                    } else {
+                        // Unterminated class, treat '[' literally
+                        matched = text[t] == '[';
+                        next_p = p + 1;
+                    }
+                }
+                '\\' if p + 1 < pattern.len() => {
+                    matched = text[t] == pattern[p + 1];
+                    next_p = p + 2;
+                }
+                c => {
+                    matched = text[t] == c;
+                    next_p = p + 1;
+                }
+            }
+        }
+
+        if matched {
+            p = next_p;
+            t += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` swallow one more character
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Match `c` against the character class starting at `pattern[start] == '['`.
+/// Returns whether it matched and the index just past the closing `]`.
+fn match_class(pattern: &[char], start: usize, c: char) -> Option<(bool, usize)> {
+    let mut i = start + 1;
+    let negate = i < pattern.len() && pattern[i] == '^';
+    if negate {
+        i += 1;
+    }
+
+    let mut matched = false;
+    let mut first = true;
+    while i < pattern.len() {
+        if pattern[i] == ']' && !first {
+            return Some((matched != negate, i + 1));
+        }
+        first = false;
+
+        let lo = if pattern[i] == '\\' && i + 1 < pattern.len() {
+            i += 1;
+            pattern[i]
+        } else {
+            pattern[i]
+        };
+
+        if i + 2 < pattern.len() && pattern[i + 1] == '-' && pattern[i + 2] != ']' {
+            let hi = pattern[i + 2];
+            let (lo, hi) = if lo <= hi { (lo, hi) } else { (hi, lo) };
+            if c >= lo && c <= hi {
+                matched = true;
+            }
+            i += 3;
+        } else {
+            if c == lo {
+                matched = true;
+            }
+            i += 1;
+        }
+    }
+
+    None
+}
+
+/// Escape glob special characters so `text` matches only itself
+pub fn escape_glob(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
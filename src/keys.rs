@@ -0,0 +1,988 @@
+use crate::glob::{escape_glob, glob_match};
+use crate::model::KeyInfo;
+use anyhow::Result;
+use redis::aio::MultiplexedConnection;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Keys whose metadata is fetched in one pipelined round trip
+const METADATA_BATCH: usize = 25;
+
+/// Progress of a key page load, shared between the loading task and the UI
+#[derive(Debug, Default)]
+pub struct LoadProgress {
+    scans: AtomicUsize,
+    matched: AtomicUsize,
+    loaded: AtomicUsize,
+    total: AtomicUsize,
+}
+
+impl LoadProgress {
+    /// Number of SCAN calls made so far
+    pub fn scans(&self) -> usize {
+        self.scans.load(Ordering::Relaxed)
+    }
+
+    /// Keys collected for the page so far
+    pub fn matched(&self) -> usize {
+        self.matched.load(Ordering::Relaxed)
+    }
+
+    /// Keys with metadata fetched so far and keys to fetch in total
+    pub fn metadata(&self) -> (usize, usize) {
+        (
+            self.loaded.load(Ordering::Relaxed),
+            self.total.load(Ordering::Relaxed),
+        )
+    }
+}
+
+/// Condition on a key's time to live
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TtlCondition {
+    NoExpiry,
+    Expiring,
+    LessThan(i64), // Seconds, not negative
+    MoreThan(i64), // Seconds, not negative
+}
+
+impl TtlCondition {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "none" | "persist" => Some(TtlCondition::NoExpiry),
+            "any" | "expiring" => Some(TtlCondition::Expiring),
+            _ => {
+                if let Some(secs) = value.strip_prefix('<') {
+                    Self::seconds(secs).map(TtlCondition::LessThan)
+                } else if let Some(secs) = value.strip_prefix('>') {
+                    Self::seconds(secs).map(TtlCondition::MoreThan)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn seconds(value: &str) -> Option<i64> {
+        value.parse().ok().filter(|secs| *secs >= 0)
+    }
+
+    fn matches(self, pttl: i64) -> bool {
+        match self {
+            TtlCondition::NoExpiry => pttl == -1,
+            TtlCondition::Expiring => pttl >= 0,
+            TtlCondition::LessThan(secs) => pttl >= 0 && pttl < secs.saturating_mul(1000),
+            TtlCondition::MoreThan(secs) => pttl > secs.saturating_mul(1000),
+        }
+    }
+}
+
+/// Key filter parsed from the filter input, e.g. `user:*:session type:hash ttl:none`.
+///
+/// Text containing glob characters is sent to SCAN as is, other text matches
+/// anywhere in the key. `type:` maps to `SCAN ... TYPE`, `ttl:` (`none`, `any`,
+/// `<secs`, `>secs`) is checked against the fetched PTTL.
+#[derive(Debug, Clone, Default)]
+pub struct KeyFilter {
+    pub text: String,
+    pub key_type: Option<String>,
+    pub ttl: Option<TtlCondition>,
+    pub scope: Option<String>, // Key prefix of the expanded tree group
+}
+
+impl KeyFilter {
+    pub fn parse(input: &str) -> Self {
+        let mut filter = Self::default();
+        let mut words = Vec::new();
+
+        for token in input.split_whitespace() {
+            match token.split_once(':') {
+                Some(("type", t)) if !t.is_empty() => filter.key_type = Some(t.to_lowercase()),
+                Some(("ttl", t)) if TtlCondition::parse(t).is_some() => {
+                    filter.ttl = TtlCondition::parse(t)
+                }
+                _ => words.push(token),
+            }
+        }
+
+        filter.text = words.join(" ");
+        filter
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+            && self.key_type.is_none()
+            && self.ttl.is_none()
+            && self.scope.is_none()
+    }
+
+    fn is_glob(&self) -> bool {
+        self.text.contains(['*', '?', '['])
+    }
+
+    /// Pattern for `SCAN ... MATCH`. Within a scope a glob filter can't be merged into
+    /// the scope pattern, so SCAN only narrows to the scope and `matches_name` does the rest.
+    pub fn scan_pattern(&self) -> Option<String> {
+        let text = if self.is_glob() {
+            self.text.clone()
+        } else if self.text.is_empty() {
+            String::new()
+        } else {
+            format!("*{}*", self.text)
+        };
+        match &self.scope {
+            Some(scope) if self.is_glob() || text.is_empty() => {
+                Some(format!("{}*", escape_glob(scope)))
+            }
+            Some(scope) => Some(format!("{}{}", escape_glob(scope), text)),
+            None if text.is_empty() => None,
+            None => Some(text),
+        }
+    }
+
+    /// Client-side check of the key name against the scope and text
+    pub fn matches_name(&self, key: &str) -> bool {
+        let in_scope = self
+            .scope
+            .as_ref()
+            .is_none_or(|scope| key.starts_with(scope.as_str()));
+        let text_matches = if self.text.is_empty() {
+            true
+        } else if self.is_glob() {
+            glob_match(&self.text, key)
+        } else {
+            key.to_lowercase().contains(&self.text.to_lowercase())
+        };
+        in_scope && text_matches
+    }
+
+    /// Client-side check, used while typing and for conditions SCAN can't express
+    pub fn matches(&self, key: &KeyInfo) -> bool {
+        self.matches_name(&key.key)
+            && self.key_type.as_ref().is_none_or(|t| &key.key_type == t)
+            && self.ttl.is_none_or(|c| c.matches(key.pttl))
+    }
+}
+
+/// One page of keys returned by SCAN together with their metadata
+pub struct KeyPage {
+    pub keys: Vec<KeyInfo>,
+    pub next: Option<(usize, u64)>, // (node, cursor) of the following page, `None` once SCAN wrapped
+    pub total_keys: u64,            // DBSIZE summed over the connections
+}
+
+/// SCAN from `(node, cursor)` until `page_size` keys pass the filter or the
+/// keyspace is exhausted. `connections` holds one connection per cluster
+/// primary, or just the main connection.
+pub async fn load_page(
+    mut connections: Vec<MultiplexedConnection>,
+    start: (usize, u64),
+    filter: KeyFilter,
+    page_size: usize,
+    progress: Arc<LoadProgress>,
+) -> Result<KeyPage> {
+    let total_keys = dbsize(&mut connections).await;
+    let (mut node, mut cursor) = start;
+    let mut keys = Vec::new();
+
+    while let Some(con) = connections.get_mut(node) {
+        let mut cmd = redis::cmd("SCAN");
+        cmd.arg(cursor);
+
+        if let Some(p) = filter.scan_pattern() {
+            cmd.arg("MATCH").arg(p);
+        }
+        if let Some(t) = &filter.key_type {
+            cmd.arg("TYPE").arg(t);
+        }
+
+        cmd.arg("COUNT").arg(page_size);
+
+        let (next_cursor, mut batch): (u64, Vec<String>) = cmd.query_async(con).await?;
+        progress.scans.fetch_add(1, Ordering::Relaxed);
+        if filter.scope.is_some() {
+            batch.retain(|key| filter.matches_name(key));
+        }
+
+        let mut batch = fetch_metadata(con, batch, &progress).await?;
+        if let Some(condition) = filter.ttl {
+            batch.retain(|k| condition.matches(k.pttl));
+        }
+        keys.extend(batch);
+        progress.matched.store(keys.len(), Ordering::Relaxed);
+
+        // Continue on the next cluster primary once this one is exhausted
+        if next_cursor == 0 {
+            node += 1;
+            cursor = 0;
+        } else {
+            cursor = next_cursor;
+        }
+
+        if keys.len() >= page_size {
+            let next = (node < connections.len()).then_some((node, cursor));
+            return Ok(KeyPage {
+                keys,
+                next,
+                total_keys,
+            });
+        }
+    }
+
+    Ok(KeyPage {
+        keys,
+        next: None,
+        total_keys,
+    })
+}
+
+/// Total number of keys across `connections`
+async fn dbsize(connections: &mut [MultiplexedConnection]) -> u64 {
+    let mut total = 0;
+    for con in connections {
+        let size: u64 = redis::cmd("DBSIZE").query_async(con).await.unwrap_or(0);
+        total += size;
+    }
+    total
+}
+
+/// Fetch TYPE, PTTL, MEMORY USAGE and OBJECT ENCODING for `keys`,
+/// pipelining a batch of keys per round trip
+pub async fn fetch_metadata(
+    con: &mut MultiplexedConnection,
+    keys: Vec<String>,
+    progress: &LoadProgress,
+) -> Result<Vec<KeyInfo>> {
+    progress.total.fetch_add(keys.len(), Ordering::Relaxed);
+
+    let mut key_infos = Vec::with_capacity(keys.len());
+    for batch in keys.chunks(METADATA_BATCH) {
+        let mut pipe = redis::pipe();
+        // MEMORY USAGE and OBJECT may be disabled on managed servers, so don't let them fail the others
+        pipe.ignore_errors();
+        for key in batch {
+            pipe.cmd("TYPE").arg(key);
+            pipe.cmd("PTTL").arg(key);
+            pipe.cmd("MEMORY").arg("USAGE").arg(key);
+            pipe.cmd("OBJECT").arg("ENCODING").arg(key);
+        }
+
+        let results: Vec<redis::Value> = pipe.query_async(con).await?;
+        let mut results = results.into_iter();
+
+        for key in batch {
+            let key_type: String = results
+                .next()
+                .and_then(|v| redis::from_redis_value(v).ok())
+                .unwrap_or("unknown".to_string());
+            let pttl: i64 = results
+                .next()
+                .and_then(|v| redis::from_redis_value(v).ok())
+                .unwrap_or(-1);
+            let memory: Option<u64> = results.next().and_then(|v| redis::from_redis_value(v).ok());
+            let encoding: Option<String> =
+                results.next().and_then(|v| redis::from_redis_value(v).ok());
+
+            key_infos.push(KeyInfo {
+                key: key.clone(),
+                key_type,
+                pttl,
+                memory_usage: memory.unwrap_or(0),
+                encoding: encoding.unwrap_or_default(),
+            });
+        }
+
+        progress.loaded.fetch_add(batch.len(), Ordering::Relaxed);
+    }
+
+    Ok(key_infos)
+}
+
+/// A row of the namespace tree: a group of keys sharing a prefix, or a single key
+#[derive(Debug, Clone)]
+pub struct NamespaceRow {
+    pub depth: usize,
+    pub name: String, // Last segment, with a trailing separator for groups
+    pub path: String, // Full prefix for groups, the key itself for leaves
+    pub key_count: usize,
+    pub memory: u64,
+    pub key_index: Option<usize>, // Index into the listed keys for leaves
+    pub expanded: bool,
+}
+
+/// Group `keys` by `separator` into a flattened tree, descending into `expanded` prefixes.
+/// Groups come before keys on each level; `by_memory` orders groups by total memory.
+pub fn build_namespace_tree(
+    keys: &[KeyInfo],
+    separator: &str,
+    expanded: &HashSet<String>,
+    by_memory: bool,
+) -> Vec<NamespaceRow> {
+    let items: Vec<(usize, Vec<&str>)> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, k)| (i, k.key.split(separator).collect()))
+        .collect();
+    let items: Vec<&(usize, Vec<&str>)> = items.iter().collect();
+
+    let mut rows = Vec::new();
+    add_tree_level(keys, &items, 0, separator, expanded, by_memory, &mut rows);
+    rows
+}
+
+fn add_tree_level(
+    keys: &[KeyInfo],
+    items: &[&(usize, Vec<&str>)],
+    depth: usize,
+    separator: &str,
+    expanded: &HashSet<String>,
+    by_memory: bool,
+    rows: &mut Vec<NamespaceRow>,
+) {
+    let mut groups: BTreeMap<&str, Vec<&(usize, Vec<&str>)>> = BTreeMap::new();
+    let mut leaves = Vec::new();
+    for &item in items {
+        if item.1.len() == depth + 1 {
+            leaves.push(item);
+        } else {
+            groups.entry(item.1[depth]).or_default().push(item);
+        }
+    }
+
+    let mut groups: Vec<_> = groups
+        .into_iter()
+        .map(|(name, members)| {
+            let memory = members.iter().map(|m| keys[m.0].memory_usage).sum::<u64>();
+            (name, members, memory)
+        })
+        .collect();
+    if by_memory {
+        groups.sort_by_key(|g| std::cmp::Reverse(g.2));
+    }
+
+    for (name, members, memory) in groups {
+        let path = members[0].1[..=depth].join(separator);
+        let is_expanded = expanded.contains(&path);
+        rows.push(NamespaceRow {
+            depth,
+            name: format!("{}{}", name, separator),
+            path,
+            key_count: members.len(),
+            memory,
+            key_index: None,
+            expanded: is_expanded,
+        });
+        if is_expanded {
+            add_tree_level(
+                keys,
+                &members,
+                depth + 1,
+                separator,
+                expanded,
+                by_memory,
+                rows,
+            );
+        }
+    }
+
+    for (index, segments) in leaves {
+        rows.push(NamespaceRow {
+            depth,
+            name: segments[depth].to_string(),
+            path: keys[*index].key.clone(),
+            key_count: 1,
+            memory: keys[*index].memory_usage,
+            key_index: Some(*index),
+            expanded: false,
+        });
+    }
+}
+
+/// Prefix of the group containing a row at `depth`, `None` on the top level
+pub fn parent_prefix(path: &str, separator: &str, depth: usize) -> Option<String> {
+    if depth == 0 {
+        return None;
+    }
+    Some(
+        path.split(separator)
+            .take(depth)
+            .collect::<Vec<_>>()
+            .join(separator),
+    )
+}
+
+/// Split `input` into arguments like redis-cli does: on whitespace, with
+/// single or double quotes grouping words and `\` escaping inside double quotes
+pub fn split_args(input: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut arg = String::new();
+        while let Some(&c) = chars.peek() {
+            match c {
+                c if c.is_whitespace() => break,
+                '"' | '\'' => {
+                    chars.next();
+                    let quote = c;
+                    loop {
+                        match chars.next() {
+                            Some(c) if c == quote => break,
+                            Some('\\') if quote == '"' => match chars.next() {
+                                Some('n') => arg.push('\n'),
+                                Some('t') => arg.push('\t'),
+                                Some(c) => arg.push(c),
+                                None => anyhow::bail!("Unterminated quote"),
+                            },
+                            Some(c) => arg.push(c),
+                            None => anyhow::bail!("Unterminated quote"),
+                        }
+                    }
+                }
+                _ => {
+                    arg.push(c);
+                    chars.next();
+                }
+            }
+        }
+        args.push(arg);
+    }
+
+    Ok(args)
+}
+
+/// Command that creates `key` of `key_type` from the contents typed in the new key dialog
+pub fn create_command(key: &str, key_type: &str, contents: &str) -> Result<redis::Cmd> {
+    if key_type == "string" {
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(key).arg(contents);
+        return Ok(cmd);
+    }
+
+    let args = split_args(contents)?;
+    if args.is_empty() {
+        anyhow::bail!("A {} needs at least one element", key_type);
+    }
+
+    let mut cmd = match key_type {
+        "list" => redis::cmd("RPUSH"),
+        "set" => redis::cmd("SADD"),
+        "hash" => redis::cmd("HSET"),
+        "zset" => redis::cmd("ZADD"),
+        "stream" => redis::cmd("XADD"),
+        _ => anyhow::bail!("Unsupported type: {}", key_type),
+    };
+    cmd.arg(key);
+
+    match key_type {
+        "hash" | "stream" | "zset" if args.len() % 2 != 0 => {
+            anyhow::bail!("Contents of a {} must come in pairs", key_type)
+        }
+        "zset" => {
+            for pair in args.chunks(2) {
+                let score: f64 = pair[0]
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Invalid score: {}", pair[0]))?;
+                cmd.arg(score).arg(&pair[1]);
+            }
+            return Ok(cmd);
+        }
+        "stream" => {
+            cmd.arg("*");
+        }
+        _ => {}
+    }
+
+    cmd.arg(args);
+    Ok(cmd)
+}
+
+/// New expiry for a key or hash field, parsed from the TTL dialog
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TtlChange {
+    Expire(u64),   // Seconds from now
+    PExpire(u64),  // Milliseconds from now
+    ExpireAt(i64), // Unix timestamp in seconds
+    Persist,
+}
+
+impl TtlChange {
+    /// Parse `90`, `90s`, `15m`, `2h`, `7d`, `500ms`, `@1767225600`,
+    /// `@2026-01-01 00:00:00` (local time) or `persist`
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("persist") || input == "-1" {
+            return Ok(TtlChange::Persist);
+        }
+
+        if let Some(at) = input.strip_prefix('@') {
+            let at = at.trim();
+            if let Ok(timestamp) = at.parse::<i64>() {
+                return Ok(TtlChange::ExpireAt(timestamp));
+            }
+            let local = chrono::NaiveDateTime::parse_from_str(at, "%Y-%m-%d %H:%M:%S")
+                .or_else(|_| {
+                    chrono::NaiveDate::parse_from_str(at, "%Y-%m-%d")
+                        .map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default())
+                })
+                .map_err(|_| anyhow::anyhow!("Invalid date: {}", at))?;
+            let timestamp = local
+                .and_local_timezone(chrono::Local)
+                .earliest()
+                .ok_or_else(|| anyhow::anyhow!("Invalid local time: {}", at))?
+                .timestamp();
+            return Ok(TtlChange::ExpireAt(timestamp));
+        }
+
+        if let Some(ms) = input.strip_suffix("ms") {
+            return ms
+                .trim()
+                .parse()
+                .map(TtlChange::PExpire)
+                .map_err(|_| anyhow::anyhow!("Invalid TTL: {}", input));
+        }
+
+        let (number, unit) = match input.char_indices().last() {
+            Some((i, c)) if c.is_ascii_alphabetic() => (&input[..i], c),
+            _ => (input, 's'),
+        };
+        let multiplier = match unit {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => anyhow::bail!("Invalid TTL unit: {}", unit),
+        };
+        let seconds = number
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .and_then(|value| value.checked_mul(multiplier))
+            .ok_or_else(|| anyhow::anyhow!("Invalid TTL: {}", input))?;
+        Ok(TtlChange::Expire(seconds))
+    }
+
+    /// Whether applying the change removes the key (or field) right away:
+    /// a TTL of 0 or an expiry time that has already passed
+    pub fn expires_now(self) -> bool {
+        match self {
+            TtlChange::Expire(secs) => secs == 0,
+            TtlChange::PExpire(ms) => ms == 0,
+            TtlChange::ExpireAt(timestamp) => timestamp <= chrono::Utc::now().timestamp(),
+            TtlChange::Persist => false,
+        }
+    }
+
+    /// Command applying the change to `key`
+    pub fn key_command(self, key: &str) -> redis::Cmd {
+        let mut cmd = match self {
+            TtlChange::Expire(_) => redis::cmd("EXPIRE"),
+            TtlChange::PExpire(_) => redis::cmd("PEXPIRE"),
+            TtlChange::ExpireAt(_) => redis::cmd("EXPIREAT"),
+            TtlChange::Persist => redis::cmd("PERSIST"),
+        };
+        cmd.arg(key);
+        self.add_time(&mut cmd);
+        cmd
+    }
+
+    /// Command applying the change to `field` of hash `key` (Redis 7.4+)
+    pub fn hash_field_command(self, key: &str, field: &str) -> redis::Cmd {
+        let mut cmd = match self {
+            TtlChange::Expire(_) => redis::cmd("HEXPIRE"),
+            TtlChange::PExpire(_) => redis::cmd("HPEXPIRE"),
+            TtlChange::ExpireAt(_) => redis::cmd("HEXPIREAT"),
+            TtlChange::Persist => redis::cmd("HPERSIST"),
+        };
+        cmd.arg(key);
+        self.add_time(&mut cmd);
+        cmd.arg("FIELDS").arg(1).arg(field);
+        cmd
+    }
+
+    fn add_time(self, cmd: &mut redis::Cmd) {
+        match self {
+            TtlChange::Expire(secs) => cmd.arg(secs),
+            TtlChange::PExpire(ms) => cmd.arg(ms),
+            TtlChange::ExpireAt(timestamp) => cmd.arg(timestamp),
+            TtlChange::Persist => cmd,
+        };
+    }
+}
+
+/// Outcome of applying a TTL change to several keys
+#[derive(Debug)]
+pub struct TtlReport {
+    pub change: TtlChange,
+    pub total: usize,
+    pub changed: usize,
+    pub skipped: usize,        // Missing keys, or keys without a TTL to remove
+    pub failures: Vec<String>, // `key (reason)`
+}
+
+impl TtlReport {
+    pub fn new(change: TtlChange, total: usize) -> Self {
+        Self {
+            change,
+            total,
+            changed: 0,
+            skipped: 0,
+            failures: Vec::new(),
+        }
+    }
+
+    /// One line for the key message
+    pub fn summary(&self) -> String {
+        let mut summary = match self.change {
+            TtlChange::Persist => {
+                format!("Removed the TTL of {} of {} keys", self.changed, self.total)
+            }
+            change if change.expires_now() => {
+                format!("Expired {} of {} keys", self.changed, self.total)
+            }
+            _ => format!("Changed the TTL of {} of {} keys", self.changed, self.total),
+        };
+        if self.skipped > 0 {
+            let reason = match self.change {
+                TtlChange::Persist => "missing or without TTL",
+                _ => "missing",
+            };
+            summary.push_str(&format!(", {} skipped ({})", self.skipped, reason));
+        }
+        if !self.failures.is_empty() {
+            summary.push_str(&format!(", failed: {}", self.failures.join(", ")));
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str, key_type: &str, pttl: i64) -> KeyInfo {
+        KeyInfo {
+            key: name.to_string(),
+            key_type: key_type.to_string(),
+            pttl,
+            memory_usage: 0,
+            encoding: String::new(),
+        }
+    }
+
+    fn cmd_args(cmd: &redis::Cmd) -> Vec<String> {
+        cmd.args_iter()
+            .map(|arg| match arg {
+                redis::Arg::Simple(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+                _ => "<cursor>".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn split_args_handles_quotes_and_escapes() {
+        assert_eq!(
+            split_args(r#"a "b c" 'd "e"' "f\"g\nh""#).unwrap(),
+            vec!["a", "b c", r#"d "e""#, "f\"g\nh"]
+        );
+        assert_eq!(
+            split_args(r#"pre"fix"ed x"#).unwrap(),
+            vec!["prefixed", "x"]
+        );
+        assert_eq!(split_args(r#"'it\s'"#).unwrap(), vec![r"it\s"]);
+        assert!(split_args("   ").unwrap().is_empty());
+        assert!(split_args(r#""open"#).is_err());
+    }
+
+    #[test]
+    fn create_command_string_keeps_contents_verbatim() {
+        let cmd = create_command("k", "string", r#"  "quoted" value "#).unwrap();
+        assert_eq!(cmd_args(&cmd), vec!["SET", "k", r#"  "quoted" value "#]);
+    }
+
+    #[test]
+    fn create_command_collections() {
+        let cmd = create_command("k", "list", r#"a "b c""#).unwrap();
+        assert_eq!(cmd_args(&cmd), vec!["RPUSH", "k", "a", "b c"]);
+
+        let cmd = create_command("k", "hash", "name alice age 30").unwrap();
+        assert_eq!(
+            cmd_args(&cmd),
+            vec!["HSET", "k", "name", "alice", "age", "30"]
+        );
+
+        let cmd = create_command("k", "zset", "1 alice 2.5 bob").unwrap();
+        assert_eq!(
+            cmd_args(&cmd),
+            vec!["ZADD", "k", "1.0", "alice", "2.5", "bob"]
+        );
+
+        let cmd = create_command("k", "stream", "event login").unwrap();
+        assert_eq!(cmd_args(&cmd), vec!["XADD", "k", "*", "event", "login"]);
+    }
+
+    #[test]
+    fn create_command_rejects_bad_contents() {
+        assert!(create_command("k", "set", "  ").is_err());
+        assert!(create_command("k", "hash", "name").is_err());
+        assert!(create_command("k", "zset", "high alice").is_err());
+        assert!(create_command("k", "json", "{}").is_err());
+    }
+
+    #[test]
+    fn ttl_change_parse_units() {
+        assert_eq!(TtlChange::parse("90").unwrap(), TtlChange::Expire(90));
+        assert_eq!(TtlChange::parse(" 90s ").unwrap(), TtlChange::Expire(90));
+        assert_eq!(TtlChange::parse("15m").unwrap(), TtlChange::Expire(900));
+        assert_eq!(TtlChange::parse("2h").unwrap(), TtlChange::Expire(7200));
+        assert_eq!(TtlChange::parse("7d").unwrap(), TtlChange::Expire(604_800));
+        assert_eq!(TtlChange::parse("500ms").unwrap(), TtlChange::PExpire(500));
+    }
+
+    #[test]
+    fn ttl_change_parse_persist_and_timestamps() {
+        assert_eq!(TtlChange::parse("persist").unwrap(), TtlChange::Persist);
+        assert_eq!(TtlChange::parse("-1").unwrap(), TtlChange::Persist);
+        assert_eq!(
+            TtlChange::parse("@1767225600").unwrap(),
+            TtlChange::ExpireAt(1767225600)
+        );
+        assert!(matches!(
+            TtlChange::parse("@2030-01-01 12:00:00").unwrap(),
+            TtlChange::ExpireAt(_)
+        ));
+        assert!(TtlChange::parse("@tomorrow").is_err());
+    }
+
+    #[test]
+    fn ttl_change_parse_rejects_invalid_input() {
+        assert!(TtlChange::parse("").is_err());
+        assert!(TtlChange::parse("10w").is_err());
+        assert!(TtlChange::parse("-5").is_err());
+        assert!(TtlChange::parse("abc").is_err());
+    }
+
+    #[test]
+    fn ttl_change_parse_rejects_overflow() {
+        let err = TtlChange::parse("999999999999999999d").unwrap_err();
+        assert!(err.to_string().starts_with("Invalid TTL"));
+        assert!(TtlChange::parse("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn ttl_change_expires_now() {
+        assert!(TtlChange::parse("0").unwrap().expires_now());
+        assert!(TtlChange::parse("0ms").unwrap().expires_now());
+        assert!(TtlChange::parse("@1").unwrap().expires_now());
+        assert!(!TtlChange::parse("1").unwrap().expires_now());
+        assert!(!TtlChange::parse("@4102444800").unwrap().expires_now());
+        assert!(!TtlChange::Persist.expires_now());
+    }
+
+    #[test]
+    fn key_filter_parse_splits_conditions_from_text() {
+        let filter = KeyFilter::parse("user:*:session type:HASH ttl:none");
+        assert_eq!(filter.text, "user:*:session");
+        assert_eq!(filter.key_type.as_deref(), Some("hash"));
+        assert_eq!(filter.ttl, Some(TtlCondition::NoExpiry));
+    }
+
+    #[test]
+    fn key_filter_parse_reads_ttl_bounds() {
+        assert_eq!(
+            KeyFilter::parse("ttl:<60").ttl,
+            Some(TtlCondition::LessThan(60))
+        );
+        assert_eq!(
+            KeyFilter::parse("ttl:>3600").ttl,
+            Some(TtlCondition::MoreThan(3600))
+        );
+        assert_eq!(
+            KeyFilter::parse("ttl:any").ttl,
+            Some(TtlCondition::Expiring)
+        );
+    }
+
+    #[test]
+    fn key_filter_parse_keeps_unknown_conditions_as_text() {
+        let filter = KeyFilter::parse("session ttl:soon type:");
+        assert_eq!(filter.text, "session ttl:soon type:");
+        assert!(filter.key_type.is_none());
+        assert!(filter.ttl.is_none());
+    }
+
+    #[test]
+    fn key_filter_parse_empty_input() {
+        let filter = KeyFilter::parse("   ");
+        assert!(filter.is_empty());
+        assert_eq!(filter.scan_pattern(), None);
+    }
+
+    #[test]
+    fn key_filter_scan_pattern_wraps_plain_text() {
+        assert_eq!(
+            KeyFilter::parse("session").scan_pattern().as_deref(),
+            Some("*session*")
+        );
+        assert_eq!(
+            KeyFilter::parse("user:?").scan_pattern().as_deref(),
+            Some("user:?")
+        );
+    }
+
+    #[test]
+    fn key_filter_scope_combines_with_plain_text() {
+        let mut filter = KeyFilter::parse("session");
+        filter.scope = Some("app*:".to_string());
+        assert_eq!(filter.scan_pattern().as_deref(), Some("app\\*:*session*"));
+        assert!(filter.matches_name("app*:session:1"));
+        assert!(!filter.matches_name("other:session:1"));
+    }
+
+    #[test]
+    fn key_filter_scope_checks_glob_text_client_side() {
+        let mut filter = KeyFilter::parse("*:42");
+        filter.scope = Some("user:".to_string());
+        assert_eq!(filter.scan_pattern().as_deref(), Some("user:*"));
+        assert!(filter.matches_name("user:42"));
+        assert!(!filter.matches_name("user:43"));
+        assert!(!filter.matches_name("order:42"));
+    }
+
+    #[test]
+    fn key_filter_matches_all_conditions() {
+        let filter = KeyFilter::parse("Session type:hash ttl:<60");
+        assert!(filter.matches(&key("app:session:1", "hash", 30_000)));
+        assert!(!filter.matches(&key("app:session:1", "string", 30_000)));
+        assert!(!filter.matches(&key("app:session:1", "hash", -1)));
+        assert!(!filter.matches(&key("app:user:1", "hash", 30_000)));
+    }
+
+    #[test]
+    fn ttl_condition_rejects_negative_seconds() {
+        assert_eq!(TtlCondition::parse("<-5"), None);
+        assert_eq!(TtlCondition::parse(">-1"), None);
+        assert_eq!(TtlCondition::parse("<0"), Some(TtlCondition::LessThan(0)));
+        // Left in the text, so it is matched against key names
+        assert_eq!(KeyFilter::parse("ttl:<-5").text, "ttl:<-5");
+    }
+
+    #[test]
+    fn ttl_condition_saturates_huge_seconds() {
+        let max = format!("<{}", i64::MAX);
+        let less = TtlCondition::parse(&max).unwrap();
+        assert!(less.matches(i64::MAX - 1));
+        assert!(!less.matches(-1));
+        let more = TtlCondition::parse(&format!(">{}", i64::MAX)).unwrap();
+        assert!(!more.matches(i64::MAX));
+        assert!(TtlCondition::MoreThan(10).matches(10_001));
+    }
+
+    #[test]
+    fn ttl_report_counts_skipped_and_failed_keys() {
+        let mut report = TtlReport::new(TtlChange::Expire(60), 4);
+        report.changed = 2;
+        report.skipped = 1;
+        report
+            .failures
+            .push("c (no primary serves slot 1)".to_string());
+        assert_eq!(
+            report.summary(),
+            "Changed the TTL of 2 of 4 keys, 1 skipped (missing), failed: c (no primary serves slot 1)"
+        );
+
+        let mut report = TtlReport::new(TtlChange::Persist, 2);
+        report.changed = 1;
+        report.skipped = 1;
+        assert_eq!(
+            report.summary(),
+            "Removed the TTL of 1 of 2 keys, 1 skipped (missing or without TTL)"
+        );
+        assert_eq!(
+            TtlReport::new(TtlChange::Expire(0), 3).summary(),
+            "Expired 0 of 3 keys"
+        );
+    }
+
+    #[tokio::test]
+    async fn load_page_counts_keys_in_the_background_task() {
+        use crate::fake_redis::{bulk, FakeRedis};
+        let server = FakeRedis::start(|cmd| match cmd[0].as_str() {
+            "DBSIZE" => Some(":42\r\n".to_string()),
+            "SCAN" => Some(format!("*2\r\n{}*1\r\n{}", bulk("0"), bulk("k"))),
+            "TYPE" => Some("+string\r\n".to_string()),
+            "PTTL" => Some(":-1\r\n".to_string()),
+            "MEMORY" => Some(":56\r\n".to_string()),
+            "OBJECT" => Some(bulk("embstr")),
+            _ => None,
+        })
+        .await;
+        let con = redis::Client::open(server.uri.as_str())
+            .unwrap()
+            .get_multiplexed_async_connection()
+            .await
+            .unwrap();
+
+        let progress = Arc::new(LoadProgress::default());
+        let page = load_page(vec![con], (0, 0), KeyFilter::default(), 10, progress)
+            .await
+            .unwrap();
+        assert_eq!(page.total_keys, 42);
+        assert_eq!(page.next, None);
+        assert_eq!(page.keys.len(), 1);
+        assert_eq!(page.keys[0].memory_usage, 56);
+        assert_eq!(page.keys[0].encoding, "embstr");
+    }
+
+    #[tokio::test]
+    async fn load_page_scans_until_the_page_is_full() {
+        use crate::fake_redis::{bulk, FakeRedis};
+        let server = FakeRedis::start(|cmd| match cmd[0].as_str() {
+            "SCAN" => Some(match cmd[1].as_str() {
+                "0" => format!("*2\r\n{}*0\r\n", bulk("5")),
+                "5" => format!("*2\r\n{}*2\r\n{}{}", bulk("9"), bulk("k1"), bulk("k2")),
+                _ => format!("*2\r\n{}*1\r\n{}", bulk("0"), bulk("k3")),
+            }),
+            "DBSIZE" => Some(":3\r\n".to_string()),
+            "TYPE" => Some("+string\r\n".to_string()),
+            "PTTL" => Some(":-1\r\n".to_string()),
+            "MEMORY" => Some(":64\r\n".to_string()),
+            "OBJECT" => Some(bulk("embstr")),
+            _ => None,
+        })
+        .await;
+        let client = redis::Client::open(server.uri.as_str()).unwrap();
+        let con = client.get_multiplexed_async_connection().await.unwrap();
+        let names =
+            |page: &KeyPage| -> Vec<String> { page.keys.iter().map(|k| k.key.clone()).collect() };
+
+        let progress = Arc::new(LoadProgress::default());
+        let page = load_page(
+            vec![con.clone()],
+            (0, 0),
+            KeyFilter::default(),
+            2,
+            progress.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(names(&page), ["k1", "k2"]);
+        assert_eq!(page.next, Some((0, 9)));
+        assert_eq!(page.total_keys, 3);
+        assert_eq!(progress.scans(), 2);
+        assert_eq!(page.keys[0].memory_usage, 64);
+        assert_eq!(page.keys[0].encoding, "embstr");
+
+        let page = load_page(vec![con], (0, 9), KeyFilter::default(), 2, Arc::default())
+            .await
+            .unwrap();
+        assert_eq!(names(&page), ["k3"]);
+        assert_eq!(page.next, None);
+    }
+}
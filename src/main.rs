@@ -1,10 +1,25 @@
+mod analyze;
 mod app;
+mod cluster;
+mod connection;
+mod decoders;
+mod editor;
+#[cfg(test)]
+mod fake_redis;
+mod glob;
+mod keys;
 mod model;
+mod monitor;
+mod sentinel;
+mod streams;
 mod ui;
+mod values;
+mod viewer;
 
 use anyhow::Result;
 use app::{App, Mode, PendingAction, PendingActionType};
 use clap::Parser;
+use connection::ConnectionFactory;
 use crossterm::{
     event::{Event, KeyCode, KeyModifiers},
     execute,
@@ -19,6 +34,7 @@ use std::{
     sync::OnceLock,
     time::{Duration, Instant},
 };
+use streams::{StreamPane, StreamReadMode};
 use tokio::sync::mpsc;
 
 pub const VERSION: &str = match option_env!("TREDIS_VERSION") {
@@ -87,6 +103,18 @@ pub struct Args {
     /// Log level (off, error, warn, info, debug) [env: TREDIS_LOG_LEVEL, LOG_LEVEL]
     #[arg(short, long, default_value_t = parse_log_level_from_env().unwrap_or(LogLevel::Off), value_enum)]
     pub log_level: LogLevel,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+    /// Open a recorded MONITOR session offline in the monitor view
+    Replay {
+        /// Recording file (JSON lines) written from the monitor view
+        file: PathBuf,
+    },
 }
 
 pub fn get_log_path() -> PathBuf {
@@ -125,6 +153,20 @@ macro_rules! log {
     };
 }
 
+/// Run a key operation on `app`, and once more after reloading the cluster slot map
+/// when it failed because the key's slot moved to another primary
+macro_rules! retry_on_redirect {
+    ($app:ident . $($op:tt)*) => {{
+        let mut result = $app.$($op)*;
+        if let Err(e) = &result {
+            if $app.handle_cluster_redirect(e).await {
+                result = $app.$($op)*;
+            }
+        }
+        result
+    }};
+}
+
 /// Mask password in a Redis URI for safe logging
 fn mask_uri(uri: &str) -> String {
     // Match pattern: redis[s]://[user:password@]host:port[/db]
@@ -172,6 +214,14 @@ async fn main() -> Result<()> {
     log!(LogLevel::Info, "TRedis v{} started", VERSION);
     log!(LogLevel::Info, "Log level: {:?}", args.log_level);
 
+    // Load the recording before touching the terminal so errors are readable
+    let replay = match args.command {
+        Some(Command::Replay { ref file }) => {
+            Some(monitor::load_recording(file).map(|entries| (file.clone(), entries))?)
+        }
+        None => None,
+    };
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -191,7 +241,16 @@ async fn main() -> Result<()> {
     // Check if we need to show server dialog (no servers configured and no CLI args override)
     let has_cli_override = args.host != "localhost" || args.port != 6379 || args.db != 0;
 
-    if has_cli_override {
+    if let Some((file, entries)) = replay {
+        // Offline replay, no server connection
+        log!(
+            LogLevel::Info,
+            "[REPLAY] {} commands from {}",
+            entries.len(),
+            file.display()
+        );
+        app.open_replay(file, entries);
+    } else if has_cli_override {
         // User provided CLI args, use them directly
         app.connection_config.host = args.host;
         app.connection_config.port = args.port;
@@ -206,6 +265,7 @@ async fn main() -> Result<()> {
                 app.connection_config.host, app.connection_config.port, app.connection_config.db
             ),
             info: None,
+            sentinel: None,
         });
 
         // Spawn connection task
@@ -279,10 +339,9 @@ async fn main() -> Result<()> {
                                 KeyCode::Enter => {
                                     app.filter_active = false;
                                     // Search on Enter - Reset pagination
-                                    app.pagination.cursor = 0;
-                                    app.pagination.cursor_stack.clear();
+                                    app.pagination.reset();
                                     if let Err(e) =
-                                        app.fetch_keys(Some(app.filter_text.clone())).await
+                                        app.start_key_load(Some(app.filter_text.clone())).await
                                     {
                                         eprintln!("Search error: {}", e);
                                     }
@@ -291,9 +350,8 @@ async fn main() -> Result<()> {
                                     app.filter_text.clear();
                                     app.filter_active = false;
                                     // Reset to default view - Reset pagination
-                                    app.pagination.cursor = 0;
-                                    app.pagination.cursor_stack.clear();
-                                    if let Err(e) = app.fetch_keys(None).await {
+                                    app.pagination.reset();
+                                    if let Err(e) = app.start_key_load(None).await {
                                         eprintln!("Error fetching keys: {}", e);
                                     }
                                 }
@@ -307,6 +365,27 @@ async fn main() -> Result<()> {
                                 }
                                 _ => {}
                             }
+                        } else if app.monitor_filter_active {
+                            // Monitor filter - applied live while typing
+                            match key.code {
+                                KeyCode::Enter => {
+                                    app.monitor_filter_active = false;
+                                }
+                                KeyCode::Esc => {
+                                    app.monitor_filter_text.clear();
+                                    app.monitor_filter_active = false;
+                                    app.update_monitor_filter();
+                                }
+                                KeyCode::Backspace => {
+                                    app.monitor_filter_text.pop();
+                                    app.update_monitor_filter();
+                                }
+                                KeyCode::Char(c) => {
+                                    app.monitor_filter_text.push(c);
+                                    app.update_monitor_filter();
+                                }
+                                _ => {}
+                            }
                         } else if app.pubsub_subscribe_mode {
                             // PubSub subscribe mode - input or listening
                             match key.code {
@@ -320,76 +399,60 @@ async fn main() -> Result<()> {
                                     app.pubsub_subscribe_input.clear();
                                     app.pubsub_messages.clear();
                                 }
-                                KeyCode::Enter => {
+                                KeyCode::Enter
                                     if app.pubsub_subscribe_channel.is_empty()
-                                        && !app.pubsub_subscribe_input.is_empty()
-                                    {
-                                        // Start subscription
-                                        let channel = app.pubsub_subscribe_input.clone();
-                                        app.pubsub_subscribe_channel = channel.clone();
-                                        app.pubsub_subscribe_input.clear();
-                                        app.pubsub_messages.clear();
-
-                                        // Start pubsub listener task
-                                        let uri = if let Some(ref server) = app.current_server {
-                                            server.uri.clone()
-                                        } else {
-                                            let scheme = if app.connection_config.tls {
-                                                "rediss"
-                                            } else {
-                                                "redis"
-                                            };
-                                            format!(
-                                                "{}://{}:{}/{}",
-                                                scheme,
-                                                app.connection_config.host,
-                                                app.connection_config.port,
-                                                app.connection_config.db
-                                            )
-                                        };
-                                        let tx_clone = tx.clone();
-
-                                        let task = tokio::spawn(async move {
-                                            if let Ok(client) = redis::Client::open(uri) {
-                                                if let Ok(mut pubsub) =
-                                                    client.get_async_pubsub().await
-                                                {
-                                                    let _ = pubsub.subscribe(&channel).await;
-                                                    let mut pubsub_stream = pubsub.on_message();
+                                        && !app.pubsub_subscribe_input.is_empty() =>
+                                {
+                                    // Start subscription
+                                    let channel = app.pubsub_subscribe_input.clone();
+                                    app.pubsub_subscribe_channel = channel.clone();
+                                    app.pubsub_subscribe_input.clear();
+                                    app.pubsub_messages.clear();
 
-                                                    while let Some(msg) = pubsub_stream.next().await
-                                                    {
-                                                        let payload: String =
-                                                            msg.get_payload().unwrap_or_default();
-                                                        let timestamp = chrono::Local::now()
-                                                            .format("%Y-%m-%d %H:%M:%S")
-                                                            .to_string();
-
-                                                        let entry = model::PubSubMessage {
-                                                            timestamp,
-                                                            channel: channel.clone(),
-                                                            message: payload,
-                                                        };
-                                                        let _ = tx_clone
-                                                            .send(AppEvent::PubSubMessage(entry))
-                                                            .await;
-                                                    }
+                                    // Start pubsub listener task
+                                    let factory = app.connection_factory();
+                                    let tx_clone = tx.clone();
+
+                                    let task = tokio::spawn(async move {
+                                        match factory.pubsub().await {
+                                            Ok(mut pubsub) => {
+                                                let _ = pubsub.subscribe(&channel).await;
+                                                let mut pubsub_stream = pubsub.on_message();
+
+                                                while let Some(msg) = pubsub_stream.next().await {
+                                                    let payload: String =
+                                                        msg.get_payload().unwrap_or_default();
+                                                    let timestamp = chrono::Local::now()
+                                                        .format("%Y-%m-%d %H:%M:%S")
+                                                        .to_string();
+
+                                                    let entry = model::PubSubMessage {
+                                                        timestamp,
+                                                        channel: channel.clone(),
+                                                        message: payload,
+                                                    };
+                                                    let _ = tx_clone
+                                                        .send(AppEvent::PubSubMessage(entry))
+                                                        .await;
                                                 }
                                             }
-                                        });
+                                            Err(e) => {
+                                                log!(
+                                                    LogLevel::Error,
+                                                    "[PUBSUB] Connection error: {}",
+                                                    e
+                                                );
+                                            }
+                                        }
+                                    });
 
-                                        app.pubsub_task = Some(task);
-                                    }
+                                    app.pubsub_task = Some(task);
                                 }
-                                KeyCode::Backspace => {
-                                    if app.pubsub_subscribe_channel.is_empty() {
-                                        app.pubsub_subscribe_input.pop();
-                                    }
+                                KeyCode::Backspace if app.pubsub_subscribe_channel.is_empty() => {
+                                    app.pubsub_subscribe_input.pop();
                                 }
-                                KeyCode::Char(c) => {
-                                    if app.pubsub_subscribe_channel.is_empty() {
-                                        app.pubsub_subscribe_input.push(c);
-                                    }
+                                KeyCode::Char(c) if app.pubsub_subscribe_channel.is_empty() => {
+                                    app.pubsub_subscribe_input.push(c);
                                 }
                                 _ => {}
                             }
@@ -403,12 +466,30 @@ async fn main() -> Result<()> {
                                     {
                                         app.clear_info_search();
                                     }
+                                    // Cancel a running keyspace analysis
+                                    else if app.active_resource == "analyze"
+                                        && app.analysis_running()
+                                    {
+                                        app.stop_analysis();
+                                    }
+                                    // Cancel a key page that is still scanning
+                                    else if app.active_resource == "keys"
+                                        && app.key_load.is_some()
+                                    {
+                                        app.cancel_key_load();
+                                    }
                                     // Clear key selection if any keys are selected
                                     else if app.active_resource == "keys"
                                         && !app.selected_keys.is_empty()
                                     {
                                         app.clear_key_selection();
                                     }
+                                    // Dismiss the outcome of the last key operation
+                                    else if app.active_resource == "keys"
+                                        && app.key_message.is_some()
+                                    {
+                                        app.key_message = None;
+                                    }
                                     // Stop stream consumer if active
                                     else if app.stream_active {
                                         app.stop_stream_consumer();
@@ -458,10 +539,8 @@ async fn main() -> Result<()> {
                                             }
                                         }
                                         "monitor" => {
-                                            if !app.monitor_entries.is_empty()
-                                                && app.selected_monitor_index
-                                                    < app.monitor_entries.len() - 1
-                                            {
+                                            let count = app.visible_monitor_entries().len();
+                                            if count > 0 && app.selected_monitor_index < count - 1 {
                                                 app.selected_monitor_index += 1;
                                                 // Only scroll if needed (selected item goes out of view)
                                                 if app.selected_monitor_index
@@ -472,6 +551,27 @@ async fn main() -> Result<()> {
                                                 }
                                             }
                                         }
+                                        "sentinel" => {
+                                            if !app.sentinel_entries.is_empty()
+                                                && app.selected_sentinel_index
+                                                    < app.sentinel_entries.len() - 1
+                                            {
+                                                app.selected_sentinel_index += 1;
+                                            }
+                                        }
+                                        "nodes" => {
+                                            if !app.cluster_nodes.is_empty()
+                                                && app.selected_cluster_node_index
+                                                    < app.cluster_nodes.len() - 1
+                                            {
+                                                app.selected_cluster_node_index += 1;
+                                            }
+                                        }
+                                        "streams" if app.stream_active => {
+                                            if app.stream_scroll + 1 < app.stream_messages.len() {
+                                                app.stream_scroll += 1;
+                                            }
+                                        }
                                         "streams" => {
                                             if !app.streams.is_empty()
                                                 && app.selected_stream_index < app.streams.len() - 1
@@ -487,6 +587,11 @@ async fn main() -> Result<()> {
                                                 app.selected_pubsub_index += 1;
                                             }
                                         }
+                                        "analyze" => {
+                                            app.analysis_scroll =
+                                                app.analysis_scroll.saturating_add(1);
+                                        }
+                                        "keys" if app.key_tree_view => app.tree_next(),
                                         _ => app.next(),
                                     }
                                 }
@@ -530,6 +635,19 @@ async fn main() -> Result<()> {
                                                 }
                                             }
                                         }
+                                        "sentinel" => {
+                                            if app.selected_sentinel_index > 0 {
+                                                app.selected_sentinel_index -= 1;
+                                            }
+                                        }
+                                        "nodes" => {
+                                            if app.selected_cluster_node_index > 0 {
+                                                app.selected_cluster_node_index -= 1;
+                                            }
+                                        }
+                                        "streams" if app.stream_active => {
+                                            app.stream_scroll = app.stream_scroll.saturating_sub(1);
+                                        }
                                         "streams" => {
                                             if app.selected_stream_index > 0 {
                                                 app.selected_stream_index -= 1;
@@ -540,6 +658,11 @@ async fn main() -> Result<()> {
                                                 app.selected_pubsub_index -= 1;
                                             }
                                         }
+                                        "analyze" => {
+                                            app.analysis_scroll =
+                                                app.analysis_scroll.saturating_sub(1);
+                                        }
+                                        "keys" if app.key_tree_view => app.tree_previous(),
                                         _ => app.previous(),
                                     }
                                 }
@@ -591,7 +714,16 @@ async fn main() -> Result<()> {
                                         }
                                         "monitor" => {
                                             /* Monitor is real-time, cleared on refresh */
-                                            app.monitor_entries.clear();
+                                            if app.monitor_replay.is_none() {
+                                                app.clear_monitor();
+                                            }
+                                        }
+                                        "nodes" => {
+                                            let _ = app.fetch_cluster_nodes().await;
+                                        }
+                                        "analyze" => app.start_analysis(),
+                                        "sentinel" => {
+                                            let _ = app.fetch_sentinel_entries().await;
                                         }
                                         "streams" => {
                                             let _ = app.fetch_streams().await;
@@ -600,7 +732,7 @@ async fn main() -> Result<()> {
                                             let _ = app.fetch_pubsub_channels().await;
                                         }
                                         _ => {
-                                            let _ = app.fetch_keys(None).await;
+                                            let _ = app.start_key_load(None).await;
                                         }
                                     }
                                 }
@@ -616,6 +748,9 @@ async fn main() -> Result<()> {
                                         app.filter_active = true;
                                         app.filter_text.clear();
                                         app.apply_filter();
+                                    } else if app.active_resource == "monitor" {
+                                        // Live filter for monitored commands
+                                        app.monitor_filter_active = true;
                                     } else if app.active_resource == "info" {
                                         // Search for info resource
                                         app.info_search_active = true;
@@ -624,21 +759,19 @@ async fn main() -> Result<()> {
                                         app.info_search_current = 0;
                                     }
                                 }
-                                KeyCode::Char('n') => {
-                                    // Next search match (vim-style) - only for info
+                                // Next search match (vim-style) - only for info
+                                KeyCode::Char('n')
                                     if app.active_resource == "info"
-                                        && !app.info_search_text.is_empty()
-                                    {
-                                        app.info_search_next();
-                                    }
+                                        && !app.info_search_text.is_empty() =>
+                                {
+                                    app.info_search_next();
                                 }
-                                KeyCode::Char('N') => {
-                                    // Previous search match (vim-style with Shift) - only for info
+                                // Previous search match (vim-style with Shift) - only for info
+                                KeyCode::Char('N')
                                     if app.active_resource == "info"
-                                        && !app.info_search_text.is_empty()
-                                    {
-                                        app.info_search_prev();
-                                    }
+                                        && !app.info_search_text.is_empty() =>
+                                {
+                                    app.info_search_prev();
                                 }
                                 KeyCode::Char('s') => {
                                     // Subscribe to channel (in pubsub view)
@@ -648,6 +781,139 @@ async fn main() -> Result<()> {
                                         app.pubsub_subscribe_channel.clear();
                                         app.pubsub_messages.clear();
                                     }
+                                    // Slowlog of the selected cluster node
+                                    else if app.active_resource == "nodes"
+                                        && !app.cluster_nodes.is_empty()
+                                    {
+                                        if let Err(e) = app.open_node_view("slowlog").await {
+                                            log!(
+                                                LogLevel::Error,
+                                                "Error opening node slowlog: {}",
+                                                e
+                                            );
+                                        }
+                                    }
+                                }
+                                // Export the keyspace analysis as JSON
+                                KeyCode::Char('e') if app.active_resource == "analyze" => {
+                                    match app.export_analysis() {
+                                        Ok(path) => log!(
+                                            LogLevel::Info,
+                                            "[ANALYZE] Report written to {}",
+                                            path.display()
+                                        ),
+                                        Err(e) => log!(LogLevel::Error, "[ANALYZE] {}", e),
+                                    }
+                                }
+                                KeyCode::Char('t') => {
+                                    // Toggle the namespace tree (in keys view)
+                                    if app.active_resource == "keys" {
+                                        if let Err(e) = app.toggle_key_tree_view().await {
+                                            log!(LogLevel::Error, "Error fetching keys: {}", e);
+                                        }
+                                    }
+                                    // Tail new entries of the streams (in streams view)
+                                    else if app.active_resource == "streams" && !app.stream_active
+                                    {
+                                        let mode = StreamReadMode::Tail {
+                                            start_id: "$".to_string(),
+                                        };
+                                        start_stream_reader(&mut app, &tx, mode);
+                                    }
+                                }
+                                // Expand the namespace group
+                                KeyCode::Char('l') | KeyCode::Right
+                                    if app.active_resource == "keys" && app.key_tree_view =>
+                                {
+                                    let rows = app.namespace_rows();
+                                    let collapsed = rows
+                                        .get(app.selected_tree_index)
+                                        .is_some_and(|r| r.key_index.is_none() && !r.expanded);
+                                    if collapsed {
+                                        if let Err(e) = app.toggle_tree_node().await {
+                                            log!(
+                                                LogLevel::Error,
+                                                "Error expanding namespace: {}",
+                                                e
+                                            );
+                                        }
+                                    }
+                                }
+                                // Collapse the namespace group
+                                KeyCode::Char('h') | KeyCode::Left
+                                    if app.active_resource == "keys" && app.key_tree_view =>
+                                {
+                                    if let Err(e) = app.collapse_tree_node().await {
+                                        log!(LogLevel::Error, "Error collapsing namespace: {}", e);
+                                    }
+                                }
+                                KeyCode::Char('T') => {
+                                    // Change the TTL of the selected keys or the highlighted key
+                                    if app.active_resource == "keys"
+                                        && (!app.selected_keys.is_empty()
+                                            || (!app.scan_result.is_empty()
+                                                && (!app.key_tree_view || app.tree_key_selected())))
+                                    {
+                                        app.open_ttl_dialog();
+                                    }
+                                    // Tail the streams from a chosen ID (in streams view)
+                                    else if app.active_resource == "streams" && !app.stream_active
+                                    {
+                                        app.open_tail_dialog();
+                                    }
+                                }
+                                // Cycle memory sort of the current page
+                                KeyCode::Char('M') if app.active_resource == "keys" => {
+                                    app.key_sort = app.key_sort.next();
+                                    app.apply_filter();
+                                }
+                                // Pause/resume the monitor feed, buffering new commands
+                                KeyCode::Char('p')
+                                    if app.active_resource == "monitor" && app.monitor_active =>
+                                {
+                                    app.toggle_monitor_pause();
+                                }
+                                KeyCode::Char('r') => {
+                                    // Start/stop recording the monitor feed to a file
+                                    if app.active_resource == "monitor" && app.monitor_active {
+                                        if let Err(e) = app.toggle_monitor_recording() {
+                                            log!(LogLevel::Error, "[MONITOR] {}", e);
+                                        }
+                                    }
+                                    // Rename the highlighted key
+                                    else if app.active_resource == "keys"
+                                        && !app.scan_result.is_empty()
+                                        && (!app.key_tree_view || app.tree_key_selected())
+                                    {
+                                        app.open_rename_dialog();
+                                    }
+                                }
+                                // Copy the highlighted key
+                                KeyCode::Char('y')
+                                    if app.active_resource == "keys"
+                                        && !app.scan_result.is_empty()
+                                        && (!app.key_tree_view || app.tree_key_selected()) =>
+                                {
+                                    app.open_copy_dialog();
+                                }
+                                // Move the selected keys or the highlighted key to another DB
+                                KeyCode::Char('m')
+                                    if app.active_resource == "keys"
+                                        && (!app.selected_keys.is_empty()
+                                            || (!app.scan_result.is_empty()
+                                                && (!app.key_tree_view
+                                                    || app.tree_key_selected()))) =>
+                                {
+                                    app.open_move_dialog();
+                                }
+                                // Info of the selected cluster node
+                                KeyCode::Char('i')
+                                    if app.active_resource == "nodes"
+                                        && !app.cluster_nodes.is_empty() =>
+                                {
+                                    if let Err(e) = app.open_node_view("info").await {
+                                        log!(LogLevel::Error, "Error opening node info: {}", e);
+                                    }
                                 }
                                 KeyCode::Char('c') => {
                                     // Connect to server (in servers view)
@@ -780,172 +1046,24 @@ async fn main() -> Result<()> {
                                             });
                                         }
                                     }
-                                    // Start stream consumer
+                                    // Read through a consumer group, asking for the group first
                                     else if app.active_resource == "streams"
                                         && !app.streams.is_empty()
+                                        && !app.stream_active
                                     {
-                                        eprintln!("[MAIN] Starting stream consumer...");
-                                        app.stream_active = true;
-                                        app.stream_messages.clear();
-
-                                        let stream = app.streams[app.selected_stream_index].clone();
-                                        let stream_name = stream.name.clone();
-                                        let consumer_group = app.stream_consumer_group.clone();
-                                        let config = app.connection_config.clone();
-                                        let tx_clone = tx.clone();
-
-                                        log!(
-                                            LogLevel::Debug,
-                                            "[MAIN] Spawning consumer task for stream: {}",
-                                            stream_name
-                                        );
-                                        let task = tokio::spawn(async move {
+                                        app.open_consume_dialog();
+                                    }
+                                    // Clients of the selected cluster node
+                                    else if app.active_resource == "nodes"
+                                        && !app.cluster_nodes.is_empty()
+                                    {
+                                        if let Err(e) = app.open_node_view("clients").await {
                                             log!(
-                                                LogLevel::Debug,
-                                                "[TASK] Consumer task started for stream: {}",
-                                                stream_name
+                                                LogLevel::Error,
+                                                "Error opening node clients: {}",
+                                                e
                                             );
-
-                                            log!(LogLevel::Debug, "[TASK] Connecting to Redis...");
-                                            if let Ok(client) = redis::Client::open(format!(
-                                                "redis://{}:{}/{}",
-                                                config.host, config.port, config.db
-                                            )) {
-                                                log!(
-                                                    LogLevel::Debug,
-                                                    "[TASK] Client created, getting connection..."
-                                                );
-                                                if let Ok(mut con) =
-                                                    client.get_multiplexed_async_connection().await
-                                                {
-                                                    log!(
-                                                        LogLevel::Info,
-                                                        "[TASK] *** Connection established! ***"
-                                                    );
-                                                    // Create consumer group (ignore error if exists)
-                                                    log!(
-                                                        LogLevel::Debug,
-                                                        "[TASK] Creating consumer group: {}",
-                                                        consumer_group
-                                                    );
-                                                    let result: Result<String, _> =
-                                                        redis::cmd("XGROUP")
-                                                            .arg("CREATE")
-                                                            .arg(&stream_name)
-                                                            .arg(&consumer_group)
-                                                            .arg("0")
-                                                            .arg("MKSTREAM")
-                                                            .query_async(&mut con)
-                                                            .await;
-                                                    log!(
-                                                        LogLevel::Debug,
-                                                        "[TASK] XGROUP CREATE result: {:?}",
-                                                        result
-                                                    );
-
-                                                    // Get hostname for consumer name
-                                                    let hostname = hostname::get()
-                                                        .ok()
-                                                        .and_then(|h| h.into_string().ok())
-                                                        .unwrap_or_else(|| "unknown".to_string());
-                                                    let consumer_name =
-                                                        format!("tredis_{}", hostname);
-
-                                                    log!(LogLevel::Info, "[TASK] *** Starting XREADGROUP loop with consumer: {} ***", consumer_name);
-
-                                                    // Start consuming messages (polling mode - no BLOCK)
-                                                    loop {
-                                                        #[allow(clippy::type_complexity)]
-                                                        let result: Result<
-                                                            Vec<(
-                                                                String,
-                                                                Vec<(
-                                                                    String,
-                                                                    Vec<(String, String)>,
-                                                                )>,
-                                                            )>,
-                                                            _,
-                                                        > = redis::cmd("XREADGROUP")
-                                                            .arg("GROUP")
-                                                            .arg(&consumer_group)
-                                                            .arg(&consumer_name)
-                                                            .arg("COUNT")
-                                                            .arg(10) // Read up to 10 messages at a time
-                                                            .arg("STREAMS")
-                                                            .arg(&stream_name)
-                                                            .arg(">")
-                                                            .query_async(&mut con)
-                                                            .await;
-
-                                                        // Sleep 500ms between polls to avoid busy loop
-                                                        tokio::time::sleep(
-                                                            tokio::time::Duration::from_millis(500),
-                                                        )
-                                                        .await;
-
-                                                        match result {
-                                                            Ok(streams) => {
-                                                                if !streams.is_empty() {
-                                                                    log!(LogLevel::Info, "[CONSUMER] *** Received {} streams ***", streams.len());
-                                                                }
-                                                                for (stream_key, messages) in
-                                                                    streams
-                                                                {
-                                                                    if !messages.is_empty() {
-                                                                        log!(LogLevel::Info, "[CONSUMER] Stream: {}, Messages: {}", stream_key, messages.len());
-                                                                    }
-                                                                    for (entry_id, fields) in
-                                                                        messages
-                                                                    {
-                                                                        let mut field_map = std::collections::HashMap::new();
-                                                                        for (k, v) in fields {
-                                                                            field_map.insert(k, v);
-                                                                        }
-
-                                                                        log!(LogLevel::Info, "[CONSUMER] Entry ID: {}, Fields: {:?}", entry_id, field_map);
-
-                                                                        let entry =
-                                                                            model::StreamEntry {
-                                                                                id: entry_id
-                                                                                    .clone(),
-                                                                                fields: field_map,
-                                                                            };
-
-                                                                        log!(LogLevel::Info, "[CONSUMER] Sending StreamMessage event to channel");
-                                                                        let _ = tx_clone.send(AppEvent::StreamMessage(entry)).await;
-
-                                                                        // ACK the message
-                                                                        let _: Result<i64, _> =
-                                                                            redis::cmd("XACK")
-                                                                                .arg(&stream_name)
-                                                                                .arg(
-                                                                                    &consumer_group,
-                                                                                )
-                                                                                .arg(&entry_id)
-                                                                                .query_async(
-                                                                                    &mut con,
-                                                                                )
-                                                                                .await;
-                                                                    }
-                                                                }
-                                                            }
-                                                            Err(e) => {
-                                                                // Timeout is normal - it means no new messages
-                                                                let err_str = format!("{:?}", e);
-                                                                if !err_str.contains("timed out") {
-                                                                    log!(LogLevel::Error, "[CONSUMER] *** XREADGROUP error (breaking loop): {:?} ***", e);
-                                                                    // Only break on real errors, not timeout
-                                                                    break;
-                                                                }
-                                                                // Timeout is normal, continue silently
-                                                            }
-                                                        }
-                                                    }
-                                                }
-                                            }
-                                        });
-
-                                        app.stream_task = Some(task);
+                                        }
                                     }
                                 }
                                 KeyCode::Char('d')
@@ -962,6 +1080,7 @@ async fn main() -> Result<()> {
                                             action_type: PendingActionType::DeleteServer,
                                             selected_yes: false,
                                             matched_keys: Vec::new(),
+                                            field_edit: None,
                                         });
                                         app.mode = Mode::Confirm;
                                     }
@@ -977,12 +1096,14 @@ async fn main() -> Result<()> {
                                             action_type: PendingActionType::DeleteSelected,
                                             selected_yes: false,
                                             matched_keys: selected_list,
+                                            field_edit: None,
                                         });
                                         app.mode = Mode::Confirm;
                                     }
                                     // Delete single key (in keys view, if none selected)
                                     else if app.active_resource == "keys"
                                         && !app.scan_result.is_empty()
+                                        && (!app.key_tree_view || app.tree_key_selected())
                                     {
                                         let key_info = &app.scan_result[app.selected_key_index];
                                         app.pending_action = Some(PendingAction {
@@ -990,32 +1111,47 @@ async fn main() -> Result<()> {
                                             action_type: PendingActionType::DeleteKey,
                                             selected_yes: false,
                                             matched_keys: Vec::new(),
+                                            field_edit: None,
                                         });
                                         app.mode = Mode::Confirm;
                                     }
                                 }
                                 KeyCode::Char(' ') => {
                                     // Toggle selection on current key (in keys view)
-                                    if app.active_resource == "keys" && !app.scan_result.is_empty()
+                                    if app.active_resource == "keys"
+                                        && !app.scan_result.is_empty()
+                                        && (!app.key_tree_view || app.tree_key_selected())
                                     {
                                         app.toggle_key_selection();
                                     }
+                                    // Mark streams to read together (in streams view)
+                                    else if app.active_resource == "streams" && !app.stream_active
+                                    {
+                                        app.toggle_stream_selection();
+                                    }
                                 }
+                                // Select all filtered keys (in keys view)
                                 KeyCode::Char('a')
-                                    if key.modifiers.contains(KeyModifiers::CONTROL) =>
+                                    if key.modifiers.contains(KeyModifiers::CONTROL)
+                                        && app.active_resource == "keys"
+                                        && !app.scan_result.is_empty() =>
                                 {
-                                    // Select all filtered keys (in keys view)
-                                    if app.active_resource == "keys" && !app.scan_result.is_empty()
-                                    {
-                                        app.select_all_keys();
-                                    }
+                                    app.select_all_keys();
                                 }
-                                KeyCode::Char('a') => {
-                                    // Add new server (only in servers view)
+                                KeyCode::Char('a')
+                                    if !key.modifiers.contains(KeyModifiers::CONTROL) =>
+                                {
+                                    // Add new server (in servers view) or new key (in keys view)
                                     if app.active_resource == "servers" {
                                         app.server_dialog_state =
                                             crate::ui::server_dialog::ServerDialogState::new();
                                         app.mode = Mode::ServerDialog;
+                                    } else if app.active_resource == "keys"
+                                        && app.connection.is_some()
+                                    {
+                                        app.new_key_dialog_state =
+                                            crate::ui::new_key_dialog::NewKeyDialogState::new();
+                                        app.mode = Mode::NewKeyDialog;
                                     }
                                 }
                                 KeyCode::Char('d') => {
@@ -1027,25 +1163,35 @@ async fn main() -> Result<()> {
                                             &app.tredis_config.servers[app.selected_server_index];
                                         app.describe_data =
                                             KeyValue::String(format_server_details(server));
+                                        app.describe_key = None;
+                                        app.describe_window = None;
+                                        app.clear_describe_search();
                                         app.mode = Mode::Describe;
                                         app.describe_scroll = 0;
                                     } else if app.active_resource == "keys"
                                         && !app.scan_result.is_empty()
+                                        && (!app.key_tree_view || app.tree_key_selected())
                                     {
-                                        if let Err(e) = app.fetch_key_value().await {
+                                        if let Err(e) =
+                                            retry_on_redirect!(app.fetch_key_value().await)
+                                        {
                                             eprintln!("Error fetching value: {}", e);
                                         } else {
                                             app.mode = Mode::Describe;
                                             app.describe_scroll = 0;
+                                            app.describe_selected = 0;
                                         }
                                     } else if app.active_resource == "streams"
                                         && !app.streams.is_empty()
                                     {
-                                        if let Err(e) = app.fetch_stream_entries().await {
+                                        if let Err(e) =
+                                            retry_on_redirect!(app.fetch_stream_entries().await)
+                                        {
                                             eprintln!("Error fetching stream entries: {}", e);
                                         } else {
                                             app.mode = Mode::Describe;
                                             app.describe_scroll = 0;
+                                            app.describe_selected = 0;
                                         }
                                     }
                                 }
@@ -1082,23 +1228,47 @@ async fn main() -> Result<()> {
                                                 let _ = tx_clone.send(AppEvent::Connect).await;
                                             });
                                         }
+                                    } else if app.active_resource == "keys"
+                                        && app.key_tree_view
+                                        && !app.tree_key_selected()
+                                    {
+                                        // Expand/collapse the namespace group
+                                        if let Err(e) = app.toggle_tree_node().await {
+                                            log!(
+                                                LogLevel::Error,
+                                                "Error expanding namespace: {}",
+                                                e
+                                            );
+                                        }
                                     } else if app.active_resource == "keys"
                                         && !app.scan_result.is_empty()
                                     {
-                                        if let Err(e) = app.fetch_key_value().await {
+                                        if let Err(e) =
+                                            retry_on_redirect!(app.fetch_key_value().await)
+                                        {
                                             eprintln!("Error fetching value: {}", e);
                                         } else {
                                             app.mode = Mode::Describe;
                                             app.describe_scroll = 0;
+                                            app.describe_selected = 0;
                                         }
                                     } else if app.active_resource == "streams"
                                         && !app.streams.is_empty()
                                     {
-                                        if let Err(e) = app.fetch_stream_entries().await {
+                                        if let Err(e) =
+                                            retry_on_redirect!(app.fetch_stream_entries().await)
+                                        {
                                             eprintln!("Error fetching stream entries: {}", e);
                                         } else {
                                             app.mode = Mode::Describe;
                                             app.describe_scroll = 0;
+                                            app.describe_selected = 0;
+                                        }
+                                    } else if app.active_resource == "nodes"
+                                        && !app.cluster_nodes.is_empty()
+                                    {
+                                        if let Err(e) = app.open_node_view("info").await {
+                                            log!(LogLevel::Error, "Error opening node info: {}", e);
                                         }
                                     }
                                 }
@@ -1112,8 +1282,17 @@ async fn main() -> Result<()> {
                     Mode::Confirm => {
                         match key.code {
                             KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
-                                app.pending_action = None;
-                                app.mode = Mode::Normal;
+                                app.mode = match app.pending_action.take() {
+                                    Some(p) if p.action_type.is_field_edit() => Mode::Describe,
+                                    // Back to the form to pick another name
+                                    Some(p) if p.action_type == PendingActionType::OverwriteKey => {
+                                        app.new_key_dialog_state
+                                            .form
+                                            .set_error(format!("Key '{}' already exists", p.key));
+                                        Mode::NewKeyDialog
+                                    }
+                                    _ => Mode::Normal,
+                                };
                             }
                             KeyCode::Left
                             | KeyCode::Right
@@ -1125,16 +1304,29 @@ async fn main() -> Result<()> {
                                 }
                             }
                             KeyCode::Enter => {
+                                let confirmed_type =
+                                    app.pending_action.as_ref().map(|p| p.action_type.clone());
                                 if let Some(ref pending) = app.pending_action {
                                     if pending.selected_yes {
                                         match pending.action_type {
                                             PendingActionType::DeleteKey => {
-                                                if let Err(e) = app.delete_key().await {
+                                                if let Err(e) =
+                                                    retry_on_redirect!(app.delete_key().await)
+                                                {
                                                     eprintln!("Error deleting key: {}", e);
                                                 }
                                                 // Refresh keys
                                                 let _ = app.fetch_keys(None).await;
                                             }
+                                            PendingActionType::ConsumeStreams => {
+                                                let edit =
+                                                    pending.field_edit.clone().unwrap_or_default();
+                                                let mode = StreamReadMode::Group {
+                                                    group: edit.target,
+                                                    consumer: edit.value,
+                                                };
+                                                start_stream_reader(&mut app, &tx, mode);
+                                            }
                                             PendingActionType::DeleteServer => {
                                                 let server_name = pending.key.clone();
                                                 if let Err(e) = app.delete_server(&server_name) {
@@ -1178,11 +1370,149 @@ async fn main() -> Result<()> {
                                                 };
                                                 let _ = app.fetch_keys(pattern).await;
                                             }
+                                            PendingActionType::SetTtl => {
+                                                // MOVED keys are retried per key inside
+                                                match app.apply_ttl_change().await {
+                                                    Ok(count) => {
+                                                        log!(
+                                                            LogLevel::Info,
+                                                            "Changed the TTL of {} keys",
+                                                            count
+                                                        );
+                                                    }
+                                                    Err(e) => {
+                                                        log!(
+                                                            LogLevel::Error,
+                                                            "Error changing TTL: {}",
+                                                            e
+                                                        );
+                                                        app.key_message = Some(format!(
+                                                            "TTL change failed: {}",
+                                                            e
+                                                        ));
+                                                    }
+                                                }
+                                                let _ =
+                                                    app.fetch_keys(app.key_filter_pattern()).await;
+                                            }
+                                            PendingActionType::RenameKey
+                                            | PendingActionType::RenameKeyOverwrite => {
+                                                let overwrite = pending.action_type
+                                                    == PendingActionType::RenameKeyOverwrite;
+                                                if let Err(e) = retry_on_redirect!(
+                                                    app.rename_key(overwrite).await
+                                                ) {
+                                                    log!(
+                                                        LogLevel::Error,
+                                                        "Error renaming key: {}",
+                                                        e
+                                                    );
+                                                    app.key_message =
+                                                        Some(format!("Rename failed: {}", e));
+                                                }
+                                                let _ =
+                                                    app.fetch_keys(app.key_filter_pattern()).await;
+                                            }
+                                            PendingActionType::CopyKey
+                                            | PendingActionType::CopyKeyReplace => {
+                                                let replace = pending.action_type
+                                                    == PendingActionType::CopyKeyReplace;
+                                                if let Err(e) =
+                                                    retry_on_redirect!(app.copy_key(replace).await)
+                                                {
+                                                    log!(
+                                                        LogLevel::Error,
+                                                        "Error copying key: {}",
+                                                        e
+                                                    );
+                                                    app.key_message =
+                                                        Some(format!("Copy failed: {}", e));
+                                                }
+                                                let _ =
+                                                    app.fetch_keys(app.key_filter_pattern()).await;
+                                            }
+                                            PendingActionType::MoveKeys => {
+                                                if let Err(e) = app.move_keys().await {
+                                                    log!(
+                                                        LogLevel::Error,
+                                                        "Error moving keys: {}",
+                                                        e
+                                                    );
+                                                    app.key_message =
+                                                        Some(format!("Move failed: {}", e));
+                                                }
+                                                let _ =
+                                                    app.fetch_keys(app.key_filter_pattern()).await;
+                                            }
+                                            PendingActionType::OverwriteKey => {
+                                                if app.submit_new_key_dialog(true).await {
+                                                    let _ = app
+                                                        .fetch_keys(app.key_filter_pattern())
+                                                        .await;
+                                                }
+                                            }
+                                            _ => {
+                                                let key = pending.key.clone();
+                                                if let Err(e) =
+                                                    retry_on_redirect!(app.apply_field_edit().await)
+                                                {
+                                                    app.describe_message = Some(e.to_string());
+                                                    log!(
+                                                        LogLevel::Error,
+                                                        "Error updating {}: {}",
+                                                        key,
+                                                        e
+                                                    );
+                                                }
+                                            }
                                         }
                                     }
                                 }
-                                app.pending_action = None;
-                                app.mode = Mode::Normal;
+                                // Rename and copy ask again when the destination exists
+                                let follow_up = app.pending_action.as_ref().is_some_and(|p| {
+                                    Some(&p.action_type) != confirmed_type.as_ref()
+                                });
+                                if !follow_up {
+                                    app.mode = match app.pending_action.take() {
+                                        Some(p) if p.action_type.is_field_edit() => Mode::Describe,
+                                        Some(p)
+                                            if p.action_type == PendingActionType::OverwriteKey
+                                                && !p.selected_yes =>
+                                        {
+                                            Mode::NewKeyDialog
+                                        }
+                                        // A failed overwrite stays in the form with its error
+                                        _ if app.mode == Mode::NewKeyDialog => Mode::NewKeyDialog,
+                                        _ => Mode::Normal,
+                                    };
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    Mode::Describe if app.describe_search_active => {
+                        // Describe search mode - typing search query
+                        match key.code {
+                            KeyCode::Enter => {
+                                // Collections are searched in full on the server
+                                app.describe_search_active = false;
+                                if let Err(e) =
+                                    retry_on_redirect!(app.search_describe_on_server().await)
+                                {
+                                    app.describe_message = Some(format!("Search failed: {}", e));
+                                }
+                            }
+                            KeyCode::Esc => {
+                                // Close search input but keep results highlighted
+                                app.describe_search_active = false;
+                            }
+                            KeyCode::Backspace => {
+                                app.describe_search_text.pop();
+                                app.update_describe_search();
+                            }
+                            KeyCode::Char(c) => {
+                                app.describe_search_text.push(c);
+                                app.update_describe_search();
                             }
                             _ => {}
                         }
@@ -1190,9 +1520,78 @@ async fn main() -> Result<()> {
                     Mode::Describe => {
                         let mut handled_g = false;
                         match key.code {
+                            KeyCode::Esc
+                                if !app.describe_search_text.is_empty()
+                                    || app.describe_search_results.is_some() =>
+                            {
+                                app.clear_describe_search();
+                            }
                             KeyCode::Esc | KeyCode::Char('q') => {
                                 app.mode = Mode::Normal;
                             }
+                            KeyCode::Char('/') => {
+                                app.clear_describe_search();
+                                app.describe_search_active = true;
+                            }
+                            KeyCode::Char('n') if !app.describe_search_text.is_empty() => {
+                                app.describe_search_next();
+                            }
+                            KeyCode::Char('N') if !app.describe_search_text.is_empty() => {
+                                app.describe_search_prev();
+                            }
+                            KeyCode::Tab => {
+                                app.cycle_stream_pane();
+                            }
+                            KeyCode::Char('R') => {
+                                if let Err(e) =
+                                    retry_on_redirect!(app.refresh_stream_details().await)
+                                {
+                                    app.describe_message = Some(e.to_string());
+                                }
+                            }
+                            KeyCode::Char('a') if app.stream_pane() == Some(StreamPane::Groups) => {
+                                app.open_create_group_dialog();
+                            }
+                            KeyCode::Char('s') if app.stream_pane() == Some(StreamPane::Groups) => {
+                                app.open_set_group_id_dialog();
+                            }
+                            KeyCode::Char('c')
+                                if app.stream_pane() == Some(StreamPane::Pending) =>
+                            {
+                                app.open_claim_dialog(false);
+                            }
+                            KeyCode::Char('C')
+                                if app.stream_pane() == Some(StreamPane::Pending) =>
+                            {
+                                app.open_claim_dialog(true);
+                            }
+                            KeyCode::Char('A')
+                                if app.stream_pane() == Some(StreamPane::Pending) =>
+                            {
+                                app.request_stream_ack();
+                            }
+                            KeyCode::Char('d')
+                                if key.modifiers.contains(KeyModifiers::CONTROL)
+                                    && app.stream_pane_focused() =>
+                            {
+                                app.request_stream_delete();
+                            }
+                            KeyCode::Char('j') | KeyCode::Down if app.stream_pane_focused() => {
+                                if let Err(e) = app.stream_pane_move(true).await {
+                                    app.describe_message = Some(e.to_string());
+                                }
+                            }
+                            KeyCode::Char('k') | KeyCode::Up if app.stream_pane_focused() => {
+                                if let Err(e) = app.stream_pane_move(false).await {
+                                    app.describe_message = Some(e.to_string());
+                                }
+                            }
+                            KeyCode::Char('j') | KeyCode::Down if app.describe_is_table() => {
+                                app.describe_next();
+                            }
+                            KeyCode::Char('k') | KeyCode::Up if app.describe_is_table() => {
+                                app.describe_previous();
+                            }
                             KeyCode::Char('j') | KeyCode::Down => {
                                 app.describe_scroll = app.describe_scroll.saturating_add(1);
                             }
@@ -1213,16 +1612,116 @@ async fn main() -> Result<()> {
                                 }
                                 handled_g = true;
                             }
-                            KeyCode::Char('G') | KeyCode::End => {
-                                app.describe_go_to_bottom(0);
-                            }
+                            KeyCode::Char('G') | KeyCode::End => app.describe_go_to_bottom(0),
                             KeyCode::Home => app.describe_go_to_top(),
+                            KeyCode::Char('e') | KeyCode::Enter if app.describe_is_collection() => {
+                                app.open_field_dialog(false);
+                            }
+                            KeyCode::Char('a') => {
+                                app.open_field_dialog(true);
+                            }
+                            KeyCode::Char('T') => {
+                                app.open_field_ttl_dialog();
+                            }
+                            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.request_field_delete();
+                            }
+                            KeyCode::Char('e') => {
+                                app.open_value_editor();
+                            }
+                            KeyCode::Char('v') => {
+                                app.cycle_describe_viewer();
+                            }
+                            KeyCode::Char('E') => {
+                                edit_value_externally(&mut app, &mut terminal).await;
+                            }
                             _ => {}
                         }
                         if !handled_g {
                             app.last_key_press = None;
                         }
                     }
+                    Mode::NewKeyDialog => {
+                        let dialog = &mut app.new_key_dialog_state;
+                        match key.code {
+                            KeyCode::Esc => app.mode = Mode::Normal,
+                            KeyCode::Tab | KeyCode::Down => dialog.form.toggle_field(),
+                            KeyCode::BackTab | KeyCode::Up => dialog.form.previous_field(),
+                            KeyCode::Left if dialog.form.choice_active() => {
+                                dialog.cycle_type(false)
+                            }
+                            KeyCode::Right if dialog.form.choice_active() => {
+                                dialog.cycle_type(true)
+                            }
+                            KeyCode::Backspace => dialog.form.pop_char(),
+                            KeyCode::Char(c) => dialog.push_char(c),
+                            KeyCode::Enter => {
+                                let created = app.submit_new_key_dialog(false).await;
+                                if created {
+                                    let _ = app.fetch_keys(app.key_filter_pattern()).await;
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    Mode::FieldDialog => {
+                        if let Some(dialog) = app.field_dialog.as_mut() {
+                            match key.code {
+                                KeyCode::Esc => {
+                                    app.mode = if dialog.action_type.is_field_edit() {
+                                        Mode::Describe
+                                    } else {
+                                        Mode::Normal
+                                    };
+                                    app.field_dialog = None;
+                                }
+                                KeyCode::Tab | KeyCode::Down | KeyCode::Up => dialog.toggle_field(),
+                                KeyCode::Backspace => dialog.pop_char(),
+                                KeyCode::Char(c) => dialog.push_char(c),
+                                KeyCode::Enter
+                                    if dialog.action_type == PendingActionType::TailStreams =>
+                                {
+                                    // Read-only, so it starts without confirmation
+                                    if let Some(mode) = app.submit_tail_dialog() {
+                                        start_stream_reader(&mut app, &tx, mode);
+                                    }
+                                }
+                                KeyCode::Enter => app.submit_field_dialog(),
+                                _ => {}
+                            }
+                        } else {
+                            app.mode = Mode::Describe;
+                        }
+                    }
+                    Mode::EditValue => {
+                        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+                        if let Some(value_editor) = app.value_editor.as_mut() {
+                            let editor = &mut value_editor.editor;
+                            match key.code {
+                                KeyCode::Esc => {
+                                    app.value_editor = None;
+                                    app.mode = Mode::Describe;
+                                }
+                                KeyCode::Char('s') if ctrl => {
+                                    app.save_value_editor().await;
+                                }
+                                KeyCode::Char(c) if !ctrl => editor.insert_char(c),
+                                KeyCode::Tab => editor.insert_char('\t'),
+                                KeyCode::Enter => editor.insert_newline(),
+                                KeyCode::Backspace => editor.backspace(),
+                                KeyCode::Delete => editor.delete(),
+                                KeyCode::Left => editor.move_left(),
+                                KeyCode::Right => editor.move_right(),
+                                KeyCode::Up => editor.move_up(),
+                                KeyCode::Down => editor.move_down(),
+                                KeyCode::Home => editor.move_home(),
+                                KeyCode::End => editor.move_end(),
+                                _ => {}
+                            }
+                        } else {
+                            app.mode = Mode::Describe;
+                        }
+                    }
                     Mode::Resources => {
                         match key.code {
                             KeyCode::Esc => {
@@ -1234,31 +1733,27 @@ async fn main() -> Result<()> {
                                 app.command_text.pop();
                                 app.update_command_suggestions();
                             }
-                            KeyCode::Down => {
-                                if !app.command_suggestions.is_empty() {
-                                    app.command_suggestion_selected =
-                                        (app.command_suggestion_selected + 1)
-                                            % app.command_suggestions.len();
-                                }
+                            KeyCode::Down if !app.command_suggestions.is_empty() => {
+                                app.command_suggestion_selected = (app.command_suggestion_selected
+                                    + 1)
+                                    % app.command_suggestions.len();
                             }
-                            KeyCode::Up => {
-                                if !app.command_suggestions.is_empty() {
-                                    if app.command_suggestion_selected > 0 {
-                                        app.command_suggestion_selected -= 1;
-                                    } else {
-                                        app.command_suggestion_selected =
-                                            app.command_suggestions.len() - 1;
-                                    }
+                            KeyCode::Up if !app.command_suggestions.is_empty() => {
+                                if app.command_suggestion_selected > 0 {
+                                    app.command_suggestion_selected -= 1;
+                                } else {
+                                    app.command_suggestion_selected =
+                                        app.command_suggestions.len() - 1;
                                 }
                             }
-                            KeyCode::Right | KeyCode::Tab => {
-                                if !app.command_suggestions.is_empty() {
-                                    if let Some(selected) =
-                                        app.command_suggestions.get(app.command_suggestion_selected)
-                                    {
-                                        app.command_text = selected.command.clone();
-                                        app.update_command_suggestions();
-                                    }
+                            KeyCode::Right | KeyCode::Tab
+                                if !app.command_suggestions.is_empty() =>
+                            {
+                                if let Some(selected) =
+                                    app.command_suggestions.get(app.command_suggestion_selected)
+                                {
+                                    app.command_text = selected.command.clone();
+                                    app.update_command_suggestions();
                                 }
                             }
                             KeyCode::Char(c) => {
@@ -1274,6 +1769,7 @@ async fn main() -> Result<()> {
                                     // Stop monitor/pubsub/stream consumers if switching away from them
                                     if app.active_resource == "monitor"
                                         && selected.command != "monitor"
+                                        && app.monitor_replay.is_none()
                                     {
                                         app.stop_monitor();
                                     }
@@ -1298,6 +1794,9 @@ async fn main() -> Result<()> {
                                         app.selected_keys.clear();
                                     }
 
+                                    // Resources picked from the menu always target the main connection
+                                    app.clear_node_focus();
+
                                     app.active_resource = selected.command.clone();
                                     app.mode = Mode::Normal;
                                     app.command_text.clear();
@@ -1306,7 +1805,7 @@ async fn main() -> Result<()> {
                                     // Trigger fetch based on resource
                                     match app.active_resource.as_str() {
                                         "keys" => {
-                                            let _ = app.fetch_keys(None).await;
+                                            let _ = app.start_key_load(None).await;
                                         }
                                         "clients" => {
                                             let _ = app.fetch_clients().await;
@@ -1323,64 +1822,50 @@ async fn main() -> Result<()> {
                                         "acl" => {
                                             let _ = app.fetch_acls().await;
                                         }
+                                        "nodes" => {
+                                            let _ = app.fetch_cluster_nodes().await;
+                                        }
+                                        // Keep an earlier report, R starts a new scan
+                                        "analyze" if app.analysis.is_none() => {
+                                            app.start_analysis();
+                                        }
+                                        "sentinel" => {
+                                            let _ = app.fetch_sentinel_entries().await;
+                                        }
+                                        "monitor" if app.monitor_replay.is_some() => {
+                                            // Replay keeps showing the loaded recording
+                                        }
                                         "monitor" => {
-                                            // Start monitor task using raw TCP connection
+                                            // Start monitor task on a dedicated connection
                                             app.monitor_active = true;
-                                            app.monitor_entries.clear();
-                                            let config = app.connection_config.clone();
+                                            app.clear_monitor();
+                                            let factory = app.connection_factory();
                                             let tx_clone = tx.clone();
 
                                             let task = tokio::spawn(async move {
-                                                use tokio::io::{
-                                                    AsyncBufReadExt, AsyncWriteExt, BufReader,
-                                                };
-                                                use tokio::net::TcpStream;
-
-                                                let addr =
-                                                    format!("{}:{}", config.host, config.port);
-
-                                                if let Ok(stream) = TcpStream::connect(&addr).await
-                                                {
-                                                    let (reader, mut writer) = stream.into_split();
-                                                    let mut reader = BufReader::new(reader);
-
-                                                    // Send MONITOR command using Redis protocol
-                                                    let monitor_cmd = "*1\r\n$7\r\nMONITOR\r\n";
-                                                    if writer
-                                                        .write_all(monitor_cmd.as_bytes())
-                                                        .await
-                                                        .is_ok()
-                                                    {
-                                                        // Read first response (+OK)
-                                                        let mut response = String::new();
-                                                        let _ =
-                                                            reader.read_line(&mut response).await;
-
-                                                        // Now read monitor stream
-                                                        loop {
-                                                            let mut line = String::new();
-                                                            match reader.read_line(&mut line).await
+                                                match factory.monitor().await {
+                                                    Ok(monitor) => {
+                                                        let mut lines =
+                                                            monitor.into_on_message::<String>();
+                                                        while let Some(line) = lines.next().await {
+                                                            if let Some(entry) =
+                                                                monitor::parse_monitor_output(&line)
                                                             {
-                                                                Ok(0) => break, // Connection closed
-                                                                Ok(_) => {
-                                                                    // Remove the leading '+' and trim
-                                                                    let line = line.trim();
-                                                                    if let Some(line) =
-                                                                        line.strip_prefix('+')
-                                                                    {
-                                                                        if let Some(entry) =
-                                                                            parse_monitor_output(
-                                                                                line,
-                                                                            )
-                                                                        {
-                                                                            let _ = tx_clone.send(AppEvent::MonitorCommand(entry)).await;
-                                                                        }
-                                                                    }
-                                                                }
-                                                                Err(_) => break,
+                                                                let _ = tx_clone
+                                                                    .send(AppEvent::MonitorCommand(
+                                                                        entry,
+                                                                    ))
+                                                                    .await;
                                                             }
                                                         }
                                                     }
+                                                    Err(e) => {
+                                                        log!(
+                                                            LogLevel::Error,
+                                                            "[MONITOR] Connection error: {}",
+                                                            e
+                                                        );
+                                                    }
                                                 }
                                             });
 
@@ -1485,6 +1970,14 @@ async fn main() -> Result<()> {
                     }
                     _ => {}
                 }
+
+                // Fetch the next window of a described collection once the selection nears
+                // the end of the loaded rows, whichever key moved it there
+                if app.mode == Mode::Describe && app.describe_is_table() {
+                    if let Err(e) = retry_on_redirect!(app.describe_load_more().await) {
+                        app.describe_message = Some(e.to_string());
+                    }
+                }
             }
         }
 
@@ -1493,6 +1986,10 @@ async fn main() -> Result<()> {
             last_tick = Instant::now();
         }
 
+        if let Err(e) = app.poll_key_load().await {
+            log!(LogLevel::Error, "Error fetching keys: {}", e);
+        }
+
         // Handle async events - process ALL pending events (non-blocking)
         while let Ok(event) = rx.try_recv() {
             match event {
@@ -1527,13 +2024,13 @@ async fn main() -> Result<()> {
                     } else {
                         log!(
                             LogLevel::Info,
-                            "[EVENT-CONNECT] Connected successfully, fetching keys..."
+                            "[EVENT-CONNECT] Connected successfully, loading keys..."
                         );
                         // Fetch keys
-                        if let Err(e) = app.fetch_keys(None).await {
+                        if let Err(e) = app.start_key_load(None).await {
                             log!(
                                 LogLevel::Error,
-                                "[EVENT-CONNECT] Error fetching keys: {}",
+                                "[EVENT-CONNECT] Error starting key load: {}",
                                 e
                             );
                             app.splash_state
@@ -1546,8 +2043,27 @@ async fn main() -> Result<()> {
                         } else {
                             log!(
                                 LogLevel::Info,
-                                "[EVENT-CONNECT] Keys fetched, switching to Normal mode"
+                                "[EVENT-CONNECT] Key load started, switching to Normal mode"
                             );
+                            // Follow failovers of sentinel-managed masters
+                            app.stop_sentinel_watch();
+                            if let Some(server) = app.current_server.clone() {
+                                if let Some(sentinel_config) = server.sentinel {
+                                    let tx_clone = tx.clone();
+                                    app.sentinel_task = Some(tokio::spawn(async move {
+                                        loop {
+                                            let (host, port) = sentinel::wait_for_failover(
+                                                &sentinel_config,
+                                                &server.uri,
+                                            )
+                                            .await;
+                                            let _ = tx_clone
+                                                .send(AppEvent::SentinelFailover { host, port })
+                                                .await;
+                                        }
+                                    }));
+                                }
+                            }
                             app.splash_state.complete_step();
                             tokio::time::sleep(Duration::from_millis(500)).await;
                             app.mode = Mode::Normal;
@@ -1567,24 +2083,26 @@ async fn main() -> Result<()> {
                         }
                     }
                 }
+                AppEvent::SentinelFailover { host, port } => {
+                    log!(
+                        LogLevel::Warn,
+                        "[SENTINEL] Master failed over to {}:{}, reconnecting",
+                        host,
+                        port
+                    );
+                    reconnect_after_failover(&mut app, &tx, 1).await;
+                }
+                AppEvent::SentinelReconnect { server, attempt } => {
+                    // Skip retries of a server left in the meantime or already reconnected
+                    if app.sentinel_reconnect.is_some()
+                        && app.current_server.as_ref().map(|s| &s.name) == Some(&server)
+                    {
+                        reconnect_after_failover(&mut app, &tx, attempt).await;
+                    }
+                }
                 AppEvent::MonitorCommand(entry) => {
                     if app.monitor_active {
-                        // Prepend to beginning of list (newest first)
-                        app.monitor_entries.insert(0, entry);
-                        // Keep only last 1000 entries
-                        if app.monitor_entries.len() > 1000 {
-                            app.monitor_entries.pop();
-                        }
-                        // Only auto-scroll if user is at the top (viewing latest entries)
-                        // If user scrolled down, don't interrupt them
-                        if app.selected_monitor_index == 0 && app.monitor_scroll == 0 {
-                            // User is at top, keep them there to see new entries
-                            app.selected_monitor_index = 0;
-                            app.monitor_scroll = 0;
-                        } else {
-                            // User scrolled down, increment their position to keep viewing same entries
-                            app.selected_monitor_index += 1;
-                        }
+                        app.push_monitor_entry(entry);
                     }
                 }
 
@@ -1598,56 +2116,15 @@ async fn main() -> Result<()> {
                         }
                     }
                 }
-                AppEvent::StreamMessage(entry) => {
-                    log!(
-                        LogLevel::Info,
-                        "[HANDLER] ========================================"
-                    );
-                    log!(LogLevel::Info, "[HANDLER] StreamMessage received!");
-                    log!(
-                        LogLevel::Info,
-                        "[HANDLER]   stream_active: {}",
-                        app.stream_active
-                    );
-                    log!(LogLevel::Info, "[HANDLER]   Entry ID: {}", entry.id);
-                    log!(LogLevel::Info, "[HANDLER]   Fields: {:?}", entry.fields);
-                    if app.stream_active {
-                        log!(
-                            LogLevel::Info,
-                            "[HANDLER] Adding message to stream_messages"
-                        );
-                        log!(
-                            LogLevel::Info,
-                            "[HANDLER]   Current count: {}",
-                            app.stream_messages.len()
-                        );
-                        // Prepend to beginning of list (newest first)
-                        app.stream_messages.insert(0, entry);
-                        log!(
-                            LogLevel::Info,
-                            "[HANDLER]   New count: {}",
-                            app.stream_messages.len()
-                        );
-                        log!(LogLevel::Info, "[HANDLER] Message successfully added!");
-                        // Keep only last 1000 entries
-                        if app.stream_messages.len() > 1000 {
-                            app.stream_messages.pop();
-                        }
-                        log!(
-                            LogLevel::Info,
-                            "[HANDLER] ========================================"
-                        );
-                    } else {
-                        log!(
-                            LogLevel::Warn,
-                            "[HANDLER] Message IGNORED - stream_active is FALSE!"
-                        );
-                        log!(
-                            LogLevel::Info,
-                            "[HANDLER] ========================================"
-                        );
-                    }
-                }
+                AppEvent::StreamMessage {
+                    generation,
+                    stream,
+                    entry,
+                } => app.receive_stream_message(generation, stream, entry),
+                AppEvent::StreamReadError {
+                    generation,
+                    message,
+                } => app.receive_stream_error(generation, message),
             }
         }
 
@@ -1658,6 +2135,7 @@ async fn main() -> Result<()> {
 
     // Cleanup
     app.stop_monitor();
+    app.stop_sentinel_watch();
     app.stop_stream_consumer();
     if let Some(task) = app.pubsub_task.take() {
         task.abort();
@@ -1678,54 +2156,261 @@ enum AppEvent {
         server_name: String,
         info: ServerInfo,
     },
+    SentinelFailover {
+        host: String,
+        port: u16,
+    },
+    SentinelReconnect {
+        server: String, // Name of the server being reconnected
+        attempt: u32,
+    },
     MonitorCommand(model::MonitorEntry),
     PubSubMessage(model::PubSubMessage),
-    StreamMessage(model::StreamEntry),
+    StreamMessage {
+        generation: u64, // Of the reader, see `App::stream_generation`
+        stream: String,
+        entry: model::StreamEntry,
+    },
+    StreamReadError {
+        generation: u64,
+        message: String,
+    },
 }
 
-fn parse_monitor_output(line: &str) -> Option<model::MonitorEntry> {
-    use chrono::{TimeZone, Utc};
-
-    // MONITOR output format: 1234567890.123456 [0 127.0.0.1:12345] "command" "arg1" "arg2"
-    if line.is_empty() {
-        return None;
+/// Reconnect after a sentinel failover; `connect` asks the sentinels for the master
+/// again. While the new master doesn't answer, retry with backoff and show why in
+/// the header.
+async fn reconnect_after_failover(app: &mut App, tx: &mpsc::Sender<AppEvent>, attempt: u32) {
+    match app.connect().await {
+        Ok(()) => {
+            if app.sentinel_reconnect.take().is_some() {
+                log!(
+                    LogLevel::Info,
+                    "[SENTINEL] Reconnected after {} attempts",
+                    attempt
+                );
+            }
+            if app.active_resource == "keys" {
+                let _ = app.start_key_load(None).await;
+            } else if app.active_resource == "sentinel" {
+                let _ = app.fetch_sentinel_entries().await;
+            }
+        }
+        Err(e) => {
+            let delay = sentinel::reconnect_delay(attempt);
+            log!(
+                LogLevel::Error,
+                "[SENTINEL] Reconnect attempt {} failed: {}, retrying in {}s",
+                attempt,
+                e,
+                delay.as_secs()
+            );
+            let Some(server) = app.current_server.as_ref().map(|s| s.name.clone()) else {
+                return;
+            };
+            app.sentinel_reconnect = Some(format!(
+                "attempt {} failed, retrying in {}s: {}",
+                attempt,
+                delay.as_secs(),
+                e
+            ));
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+                let _ = tx
+                    .send(AppEvent::SentinelReconnect {
+                        server,
+                        attempt: attempt + 1,
+                    })
+                    .await;
+            });
+        }
     }
+}
 
-    let parts: Vec<&str> = line.splitn(3, ' ').collect();
-    if parts.len() < 3 {
-        return None;
+/// Start reading the marked streams (or the highlighted one) in the background
+fn start_stream_reader(app: &mut App, tx: &mpsc::Sender<AppEvent>, mode: StreamReadMode) {
+    let streams = app.start_stream_read(mode.clone());
+    if streams.is_empty() {
+        return;
     }
+    let factory = match app.stream_reader_factory(&streams) {
+        Ok(factory) => factory,
+        Err(e) => {
+            app.stream_read_error = Some(e.to_string());
+            return;
+        }
+    };
+    let generation = app.stream_generation;
+    let tx = tx.clone();
+    let (ack_tx, acks) = mpsc::unbounded_channel();
+    if matches!(mode, StreamReadMode::Group { .. }) {
+        app.stream_acks = Some(ack_tx);
+    }
+    log!(
+        LogLevel::Info,
+        "[STREAMS] Reading {:?} with {:?}",
+        streams,
+        mode
+    );
 
-    // Parse timestamp and convert to human-readable format with date
-    let timestamp_raw = parts[0];
-    let timestamp = if let Ok(ts_float) = timestamp_raw.parse::<f64>() {
-        let secs = ts_float as i64;
-        let nanos = ((ts_float - secs as f64) * 1_000_000_000.0) as u32;
-        if let Some(dt) = Utc.timestamp_opt(secs, nanos).single() {
-            // Convert to local time
-            let local = dt.with_timezone(&chrono::Local);
-            local.format("%Y-%m-%d %H:%M:%S").to_string()
+    app.stream_task = Some(tokio::spawn(async move {
+        let result = match mode {
+            StreamReadMode::Tail { start_id } => {
+                tail_streams(&factory, streams, &start_id, generation, &tx).await
+            }
+            StreamReadMode::Group { group, consumer } => {
+                consume_streams(&factory, &streams, &group, &consumer, generation, &tx, acks).await
+            }
+        };
+        if let Err(e) = result {
+            log!(LogLevel::Error, "[STREAMS] Reader stopped: {}", e);
+            let message = e.to_string();
+            let _ = tx
+                .send(AppEvent::StreamReadError {
+                    generation,
+                    message,
+                })
+                .await;
+        }
+    }));
+}
+
+/// Follow `streams` with XREAD BLOCK from `start_id`. Read-only: no group, no acks.
+async fn tail_streams(
+    factory: &ConnectionFactory,
+    streams: Vec<String>,
+    start_id: &str,
+    generation: u64,
+    tx: &mpsc::Sender<AppEvent>,
+) -> Result<()> {
+    let mut con = factory.blocking().await?;
+    let mut positions = Vec::new();
+    for stream in streams {
+        let id = if start_id == "$" {
+            streams::last_entry_id(&mut con, &stream).await?
         } else {
-            timestamp_raw.to_string()
+            start_id.to_string()
+        };
+        positions.push((stream, id));
+    }
+
+    loop {
+        for (stream, entry) in streams::read_after(&mut con, &mut positions).await? {
+            if tx
+                .send(AppEvent::StreamMessage {
+                    generation,
+                    stream,
+                    entry,
+                })
+                .await
+                .is_err()
+            {
+                return Ok(());
+            }
         }
-    } else {
-        timestamp_raw.to_string()
+    }
+}
+
+/// Read `streams` as `consumer` of `group`, creating the group at the start of a
+/// stream where it is missing. Entries are acknowledged on a second connection when
+/// the view sends them back through `acks`, so entries it never showed stay pending.
+async fn consume_streams(
+    factory: &ConnectionFactory,
+    streams: &[String],
+    group: &str,
+    consumer: &str,
+    generation: u64,
+    tx: &mpsc::Sender<AppEvent>,
+    mut acks: mpsc::UnboundedReceiver<(String, String)>,
+) -> Result<()> {
+    let mut con = factory.blocking().await?;
+    let mut ack_con = factory.blocking().await?;
+    for stream in streams {
+        if let Err(e) = streams::create_group(&mut con, stream, group, "0").await {
+            if !e.to_string().contains("BUSYGROUP") {
+                return Err(e);
+            }
+        }
+    }
+
+    let read = async {
+        loop {
+            for (stream, entry) in streams::read_group(&mut con, group, consumer, streams).await? {
+                let message = AppEvent::StreamMessage {
+                    generation,
+                    stream,
+                    entry,
+                };
+                if tx.send(message).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    };
+    // XREADGROUP BLOCK holds its connection, so acks go through their own
+    let ack = async {
+        while let Some((stream, id)) = acks.recv().await {
+            streams::ack(&mut ack_con, &stream, group, &id).await?;
+        }
+        Ok(())
     };
+    tokio::select! {
+        result = read => result,
+        result = ack => result,
+    }
+}
 
-    let client_db = parts[1].trim_matches(|c| c == '[' || c == ']');
-    let command = parts[2].to_string();
+/// Edit the described string value in the external editor, then save it through
+/// the TUI editor, which stays open if the save doesn't go through
+async fn edit_value_externally(
+    app: &mut App,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+) {
+    if !app.open_value_editor() {
+        return;
+    }
+    let (key, value) = app
+        .value_editor
+        .as_ref()
+        .map(|e| (e.key.clone(), e.editor.text()))
+        .unwrap_or_default();
+    match edit_in_external_editor(terminal, &key, &value) {
+        Ok(edited) if edited == value => {
+            app.value_editor = None;
+            app.mode = Mode::Describe;
+        }
+        Ok(edited) => {
+            if let Some(editor) = app.value_editor.as_mut() {
+                editor.editor = editor::TextEditor::new(&edited);
+            }
+            app.save_value_editor().await;
+        }
+        Err(e) => {
+            log!(LogLevel::Error, "External editor failed: {}", e);
+            if let Some(editor) = app.value_editor.as_mut() {
+                editor.message = Some(format!("External editor failed: {}", e));
+            }
+        }
+    }
+}
 
-    // Parse [db client:port]
-    let client_parts: Vec<&str> = client_db.splitn(2, ' ').collect();
-    let db = client_parts.first().unwrap_or(&"0").to_string();
-    let client = client_parts.get(1).unwrap_or(&"unknown").to_string();
+/// Hand the terminal to the external editor while it runs
+fn edit_in_external_editor(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    key: &str,
+    text: &str,
+) -> Result<String> {
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
 
-    Some(model::MonitorEntry {
-        timestamp,
-        db,
-        client,
-        command,
-    })
+    let result = editor::edit_externally(key, text);
+
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+    terminal.clear()?;
+    result
 }
 
 fn format_server_details(server: &model::ServerConfig) -> String {
@@ -37,6 +37,61 @@ pub struct ServerInfo {
     pub role: String, // master, slave, sentinel
 }
 
+/// A node as reported by `CLUSTER NODES`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterNode {
+    pub id: String,
+    pub addr: String,
+    pub host: String,
+    pub port: u16,
+    pub flags: String,
+    pub master_id: String, // "-" for primaries
+    pub link_state: String,
+    pub slots: Vec<(u16, u16)>,
+}
+
+impl ClusterNode {
+    pub fn is_primary(&self) -> bool {
+        self.flags.split(',').any(|f| f == "master")
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.flags.split(',').any(|f| f == "fail" || f == "noaddr")
+    }
+
+    pub fn role(&self) -> &'static str {
+        if self.is_primary() {
+            "primary"
+        } else {
+            "replica"
+        }
+    }
+
+    /// Slot ranges formatted like `0-5460,10923`
+    pub fn slot_ranges(&self) -> String {
+        self.slots
+            .iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}-{}", start, end)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// A Sentinel-managed master: tredis asks the sentinels for the current master address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentinelConfig {
+    pub master_name: String,
+    pub addresses: Vec<String>, // host:port of each sentinel
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>, // Sentinel password, if different from the master's
+}
+
 /// A saved server connection configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
@@ -44,6 +99,8 @@ pub struct ServerConfig {
     pub uri: String,
     #[serde(default)]
     pub info: Option<ServerInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sentinel: Option<SentinelConfig>,
 }
 
 /// The root config file structure stored in XDG config
@@ -51,9 +108,31 @@ pub struct ServerConfig {
 pub struct TredisConfig {
     #[serde(default)]
     pub servers: Vec<ServerConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_separator: Option<String>, // Namespace separator for the keys tree, `:` by default
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decoders: Vec<DecoderConfig>, // First match wins
+}
+
+/// Decoder for the string values of keys matching a glob
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecoderConfig {
+    pub pattern: String, // Key glob, e.g. `session:*`
+    pub decoder: String, // Steps joined by `+`: base64, gzip, zstd, lz4, then json, msgpack or protobuf
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descriptor_set: Option<String>, // protobuf: file written by `protoc --descriptor_set_out`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>, // protobuf: fully qualified message name
 }
 
 impl TredisConfig {
+    pub fn key_separator(&self) -> &str {
+        match self.key_separator.as_deref() {
+            Some(sep) if !sep.is_empty() => sep,
+            _ => ":",
+        }
+    }
+
     /// Get the config file path (XDG config dir / tredis / config.yaml)
     pub fn config_path() -> PathBuf {
         if let Some(config_dir) = dirs::config_dir() {
@@ -95,6 +174,7 @@ impl TredisConfig {
             name,
             uri,
             info: None,
+            sentinel: None,
         });
         self.save()
     }
@@ -104,17 +184,19 @@ impl TredisConfig {
 pub struct KeyInfo {
     pub key: String,
     pub key_type: String,
-    pub ttl: i64,
+    pub pttl: i64, // Milliseconds, -1 without expiry
     pub memory_usage: u64,
+    pub encoding: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum KeyValue {
     String(String),
+    Bytes(Vec<u8>), // Raw string value, shown through a `ValueViewer`
     List(Vec<String>),
     Set(Vec<String>),
     ZSet(Vec<(String, f64)>),
-    Hash(HashMap<String, String>),
+    Hash(Vec<(String, String)>), // Field/value pairs in the order they were loaded
     Stream(Vec<StreamEntry>),
     None,
     Error(String),
@@ -171,10 +253,48 @@ pub struct AclUser {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitorEntry {
+    pub time: f64, // Unix seconds as reported by the server
     pub timestamp: String,
     pub db: String,
-    pub client: String,
-    pub command: String,
+    pub client: String,    // ip:port, "lua" or "unix:/path"
+    pub args: Vec<String>, // Command name followed by its arguments
+}
+
+impl MonitorEntry {
+    /// Upper-cased command name, e.g. `GET`
+    pub fn command_name(&self) -> String {
+        self.args
+            .first()
+            .map(|c| c.to_uppercase())
+            .unwrap_or_default()
+    }
+
+    /// Arguments after the command name, quoted where needed
+    pub fn args_display(&self) -> String {
+        self.args
+            .iter()
+            .skip(1)
+            .map(|arg| {
+                if arg.is_empty() || arg.contains(|c: char| c.is_whitespace() || c == '"') {
+                    format!("{:?}", arg)
+                } else {
+                    arg.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// A master, replica or sentinel as reported by `SENTINEL MASTERS/REPLICAS/SENTINELS`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentinelEntry {
+    pub kind: String, // master, replica, sentinel
+    pub master: String,
+    pub name: String,
+    pub addr: String,
+    pub flags: String,
+    pub status: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,549 @@
+use crate::glob::glob_match;
+use crate::model::MonitorEntry;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, LineWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Parse a line of MONITOR output:
+/// `1339518083.107412 [0 127.0.0.1:60866] "set" "key" "some value"`
+///
+/// The client can also be `lua` or `unix:/path/to/socket`. Arguments are quoted
+/// and escaped the way Redis' `sdscatrepr` does it.
+pub fn parse_monitor_output(line: &str) -> Option<MonitorEntry> {
+    let line = line.trim_end();
+    if line.is_empty() {
+        return None;
+    }
+
+    let (timestamp_raw, rest) = line.split_once(' ')?;
+    let rest = rest.strip_prefix('[')?;
+    // Arguments always start with a quote, so `] "` marks the end of the client part
+    let close = rest.find("] \"").or_else(|| rest.rfind(']'))?;
+    let client_db = &rest[..close];
+    let args = parse_quoted_args(&rest[close + 1..]);
+
+    let (db, client) = client_db.split_once(' ').unwrap_or((client_db, "unknown"));
+
+    Some(MonitorEntry {
+        time: timestamp_raw.parse().unwrap_or(0.0),
+        timestamp: format_timestamp(timestamp_raw),
+        db: db.to_string(),
+        client: client.to_string(),
+        args,
+    })
+}
+
+/// Convert a `seconds.micros` timestamp to local time with date
+fn format_timestamp(raw: &str) -> String {
+    use chrono::{TimeZone, Utc};
+
+    if let Ok(ts_float) = raw.parse::<f64>() {
+        let secs = ts_float as i64;
+        let nanos = ((ts_float - secs as f64) * 1_000_000_000.0) as u32;
+        if let Some(dt) = Utc.timestamp_opt(secs, nanos).single() {
+            let local = dt.with_timezone(&chrono::Local);
+            return local.format("%Y-%m-%d %H:%M:%S").to_string();
+        }
+    }
+    raw.to_string()
+}
+
+/// Split `"arg1" "arg 2" "\x00\xff"` into decoded arguments
+fn parse_quoted_args(input: &str) -> Vec<String> {
+    let bytes = input.as_bytes();
+    let mut args = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'"' {
+            i += 1;
+            continue;
+        }
+        i += 1;
+
+        let mut arg = Vec::new();
+        while i < bytes.len() && bytes[i] != b'"' {
+            if bytes[i] == b'\\' && i + 1 < bytes.len() {
+                i += 1;
+                match bytes[i] {
+                    b'n' => arg.push(b'\n'),
+                    b'r' => arg.push(b'\r'),
+                    b't' => arg.push(b'\t'),
+                    b'a' => arg.push(0x07),
+                    b'b' => arg.push(0x08),
+                    b'x' if i + 2 < bytes.len() => {
+                        let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                        match u8::from_str_radix(hex, 16) {
+                            Ok(b) => {
+                                arg.push(b);
+                                i += 2;
+                            }
+                            Err(_) => arg.push(b'x'),
+                        }
+                    }
+                    other => arg.push(other),
+                }
+            } else {
+                arg.push(bytes[i]);
+            }
+            i += 1;
+        }
+        i += 1; // closing quote
+
+        args.push(crate::viewer::bytes_to_text(arg).0);
+    }
+
+    args
+}
+
+/// Commands that carry no key arguments
+const KEYLESS_COMMANDS: &[&str] = &[
+    "ACL",
+    "AUTH",
+    "BGSAVE",
+    "CLIENT",
+    "CLUSTER",
+    "COMMAND",
+    "CONFIG",
+    "DBSIZE",
+    "DISCARD",
+    "ECHO",
+    "EXEC",
+    "FLUSHALL",
+    "FLUSHDB",
+    "FUNCTION",
+    "HELLO",
+    "INFO",
+    "KEYS",
+    "LASTSAVE",
+    "MONITOR",
+    "MULTI",
+    "PING",
+    "PSUBSCRIBE",
+    "PUBLISH",
+    "PUBSUB",
+    "PUNSUBSCRIBE",
+    "QUIT",
+    "READONLY",
+    "RANDOMKEY",
+    "ROLE",
+    "SCAN",
+    "SCRIPT",
+    "SELECT",
+    "SENTINEL",
+    "SLOWLOG",
+    "SUBSCRIBE",
+    "SWAPDB",
+    "TIME",
+    "UNSUBSCRIBE",
+    "UNWATCH",
+];
+
+/// Commands where every argument is a key
+const ALL_KEYS_COMMANDS: &[&str] = &[
+    "DEL",
+    "EXISTS",
+    "MGET",
+    "PFCOUNT",
+    "PFMERGE",
+    "RENAME",
+    "RENAMENX",
+    "SDIFF",
+    "SDIFFSTORE",
+    "SINTER",
+    "SINTERSTORE",
+    "SUNION",
+    "SUNIONSTORE",
+    "TOUCH",
+    "UNLINK",
+    "WATCH",
+];
+
+/// Best-effort list of the keys a command touches, used for key filters and stats
+pub fn command_keys(entry: &MonitorEntry) -> Vec<&str> {
+    let args = &entry.args;
+    if args.len() < 2 {
+        return Vec::new();
+    }
+    let command = entry.command_name();
+
+    if KEYLESS_COMMANDS.contains(&command.as_str()) {
+        return Vec::new();
+    }
+    if ALL_KEYS_COMMANDS.contains(&command.as_str()) {
+        return args[1..].iter().map(|a| a.as_str()).collect();
+    }
+
+    match command.as_str() {
+        "MSET" | "MSETNX" => args[1..].iter().step_by(2).map(|a| a.as_str()).collect(),
+        // Blocking pops end with a timeout
+        "BLPOP" | "BRPOP" | "BZPOPMIN" | "BZPOPMAX" => {
+            args[1..args.len() - 1].iter().map(|a| a.as_str()).collect()
+        }
+        "EVAL" | "EVALSHA" | "EVAL_RO" | "EVALSHA_RO" | "FCALL" | "FCALL_RO" => {
+            let numkeys: usize = args.get(2).and_then(|n| n.parse().ok()).unwrap_or(0);
+            args.iter()
+                .skip(3)
+                .take(numkeys)
+                .map(|a| a.as_str())
+                .collect()
+        }
+        "XREAD" | "XREADGROUP" => {
+            // STREAMS key1 key2 ... id1 id2 ...
+            match args.iter().position(|a| a.eq_ignore_ascii_case("STREAMS")) {
+                Some(pos) => {
+                    let rest = &args[pos + 1..];
+                    rest[..rest.len() / 2].iter().map(|a| a.as_str()).collect()
+                }
+                None => Vec::new(),
+            }
+        }
+        // Subcommand first, then the key
+        "MEMORY" | "OBJECT" => args.get(2).map(|a| vec![a.as_str()]).unwrap_or_default(),
+        _ => vec![args[1].as_str()],
+    }
+}
+
+/// Live filter for the monitor view, parsed from input like
+/// `cmd:get,set client:10.0.0.5 db:0 key:user:*`.
+///
+/// Conditions of different kinds must all match; comma separated values of
+/// the same kind match if any of them does. Bare words match the command
+/// name or any argument.
+#[derive(Debug, Clone, Default)]
+pub struct MonitorFilter {
+    commands: Vec<String>,
+    clients: Vec<String>,
+    dbs: Vec<String>,
+    key_globs: Vec<String>,
+    words: Vec<String>,
+}
+
+impl MonitorFilter {
+    pub fn parse(input: &str) -> Self {
+        let mut filter = Self::default();
+
+        for token in input.split_whitespace() {
+            let (target, values) = match token.split_once(':') {
+                Some(("cmd", v)) => (&mut filter.commands, v),
+                Some(("client", v)) => (&mut filter.clients, v),
+                Some(("db", v)) => (&mut filter.dbs, v),
+                Some(("key", v)) => (&mut filter.key_globs, v),
+                _ => {
+                    filter.words.push(token.to_lowercase());
+                    continue;
+                }
+            };
+            target.extend(
+                values
+                    .split(',')
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.to_string()),
+            );
+        }
+
+        filter
+            .commands
+            .iter_mut()
+            .for_each(|c| *c = c.to_uppercase());
+        filter
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+            && self.clients.is_empty()
+            && self.dbs.is_empty()
+            && self.key_globs.is_empty()
+            && self.words.is_empty()
+    }
+
+    pub fn matches(&self, entry: &MonitorEntry) -> bool {
+        if !self.commands.is_empty() && !self.commands.contains(&entry.command_name()) {
+            return false;
+        }
+        if !self.clients.is_empty()
+            && !self
+                .clients
+                .iter()
+                .any(|c| entry.client.contains(c.as_str()))
+        {
+            return false;
+        }
+        if !self.dbs.is_empty() && !self.dbs.contains(&entry.db) {
+            return false;
+        }
+        if !self.key_globs.is_empty() {
+            let keys = command_keys(entry);
+            if !self
+                .key_globs
+                .iter()
+                .any(|glob| keys.iter().any(|key| glob_match(glob, key)))
+            {
+                return false;
+            }
+        }
+        self.words.iter().all(|word| {
+            entry
+                .args
+                .iter()
+                .any(|arg| arg.to_lowercase().contains(word.as_str()))
+        })
+    }
+}
+
+/// Window used to compute ops/sec, in seconds
+const RATE_WINDOW: f64 = 5.0;
+
+/// Upper bound on distinct keys tracked before rarely seen ones are dropped
+const MAX_TRACKED_KEYS: usize = 10_000;
+
+/// Running per-command and per-key counters over the monitored traffic
+#[derive(Debug, Clone, Default)]
+pub struct MonitorStats {
+    pub total: u64,
+    commands: HashMap<String, u64>,
+    keys: HashMap<String, u64>,
+    recent: VecDeque<f64>, // Timestamps of entries inside the rate window
+}
+
+impl MonitorStats {
+    pub fn record(&mut self, entry: &MonitorEntry) {
+        self.total += 1;
+        *self.commands.entry(entry.command_name()).or_insert(0) += 1;
+
+        for key in command_keys(entry) {
+            *self.keys.entry(key.to_string()).or_insert(0) += 1;
+        }
+        if self.keys.len() > MAX_TRACKED_KEYS {
+            self.prune_keys();
+        }
+
+        self.recent.push_back(entry.time);
+        while self
+            .recent
+            .front()
+            .is_some_and(|&t| t < entry.time - RATE_WINDOW)
+        {
+            self.recent.pop_front();
+        }
+    }
+
+    /// Keep only the busiest half of the tracked keys. Dropping at least half makes sure
+    /// the next prune is thousands of new keys away, even when all counts are equal.
+    fn prune_keys(&mut self) {
+        let keep = MAX_TRACKED_KEYS / 2;
+        let mut counts: Vec<u64> = self.keys.values().copied().collect();
+        let (_, &mut threshold, _) = counts.select_nth_unstable_by(keep, |a, b| b.cmp(a));
+        self.keys.retain(|_, count| *count > threshold);
+    }
+
+    /// Commands per second over the last few seconds before `now` (unix seconds)
+    pub fn ops_per_sec(&self, now: f64) -> f64 {
+        let count = self
+            .recent
+            .iter()
+            .filter(|&&t| t >= now - RATE_WINDOW && t <= now)
+            .count();
+        count as f64 / RATE_WINDOW
+    }
+
+    pub fn top_commands(&self, n: usize) -> Vec<(&str, u64)> {
+        top_n(&self.commands, n)
+    }
+
+    pub fn top_keys(&self, n: usize) -> Vec<(&str, u64)> {
+        top_n(&self.keys, n)
+    }
+}
+
+fn top_n(counts: &HashMap<String, u64>, n: usize) -> Vec<(&str, u64)> {
+    let mut sorted: Vec<(&str, u64)> = counts.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    sorted.truncate(n);
+    sorted
+}
+
+/// One line of a monitor recording
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedCommand {
+    timestamp: f64,
+    db: String,
+    client: String,
+    args: Vec<String>,
+}
+
+/// Writes the monitor feed to a JSON lines file as it arrives
+pub struct MonitorRecorder {
+    pub path: PathBuf,
+    pub count: u64,
+    writer: LineWriter<File>,
+}
+
+impl MonitorRecorder {
+    /// Start a new recording under `<config dir>/tredis/recordings`
+    pub fn start() -> Result<Self> {
+        let dir = crate::model::TredisConfig::config_path()
+            .parent()
+            .map(|p| p.join("recordings"))
+            .unwrap_or_else(|| PathBuf::from("recordings"));
+        std::fs::create_dir_all(&dir)?;
+
+        let path = dir.join(format!(
+            "monitor-{}.jsonl",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        let file = File::create(&path)
+            .with_context(|| format!("Cannot create recording {}", path.display()))?;
+
+        Ok(Self {
+            path,
+            count: 0,
+            writer: LineWriter::new(file),
+        })
+    }
+
+    pub fn record(&mut self, entry: &MonitorEntry) -> Result<()> {
+        let line = serde_json::to_string(&RecordedCommand {
+            timestamp: entry.time,
+            db: entry.db.clone(),
+            client: entry.client.clone(),
+            args: entry.args.clone(),
+        })?;
+        writeln!(self.writer, "{}", line)?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Load a recording written by [`MonitorRecorder`], newest entry first
+pub fn load_recording(path: &Path) -> Result<Vec<MonitorEntry>> {
+    let file =
+        File::open(path).with_context(|| format!("Cannot open recording {}", path.display()))?;
+
+    let mut entries = Vec::new();
+    for (line_no, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let recorded: RecordedCommand = serde_json::from_str(&line)
+            .with_context(|| format!("{}:{}: invalid entry", path.display(), line_no + 1))?;
+        entries.push(MonitorEntry {
+            time: recorded.timestamp,
+            timestamp: format_timestamp(&recorded.timestamp.to_string()),
+            db: recorded.db,
+            client: recorded.client,
+            args: recorded.args,
+        });
+    }
+
+    entries.reverse();
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(time: f64, args: &[&str]) -> MonitorEntry {
+        MonitorEntry {
+            time,
+            timestamp: String::new(),
+            db: "0".to_string(),
+            client: "127.0.0.1:1234".to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn stats_prune_keys_even_when_all_counts_are_equal() {
+        let mut stats = MonitorStats::default();
+        for i in 0..=MAX_TRACKED_KEYS {
+            let key = format!("key:{}", i);
+            stats.record(&entry(1.0, &["GET", &key]));
+            stats.record(&entry(1.0, &["GET", &key]));
+        }
+        assert!(stats.keys.len() <= MAX_TRACKED_KEYS / 2);
+    }
+
+    #[test]
+    fn stats_prune_keeps_busiest_keys() {
+        let mut stats = MonitorStats::default();
+        for _ in 0..5 {
+            stats.record(&entry(1.0, &["GET", "hot"]));
+        }
+        for i in 0..MAX_TRACKED_KEYS {
+            stats.record(&entry(1.0, &["GET", &format!("cold:{}", i)]));
+        }
+        assert_eq!(stats.top_keys(1), vec![("hot", 5)]);
+        assert!(stats.keys.len() <= MAX_TRACKED_KEYS);
+    }
+
+    #[test]
+    fn ops_per_sec_uses_the_given_clock() {
+        let mut stats = MonitorStats::default();
+        for i in 0..10 {
+            stats.record(&entry(1000.0 + i as f64 * 0.1, &["PING"]));
+        }
+        assert_eq!(stats.ops_per_sec(1001.0), 10.0 / RATE_WINDOW);
+        assert_eq!(stats.ops_per_sec(2000.0), 0.0);
+    }
+
+    #[test]
+    fn parse_monitor_output_reads_header_and_args() {
+        let entry = parse_monitor_output(
+            r#"1339518083.107412 [0 127.0.0.1:60866] "set" "key" "some value""#,
+        )
+        .unwrap();
+        assert_eq!(entry.time, 1339518083.107412);
+        assert_eq!(entry.db, "0");
+        assert_eq!(entry.client, "127.0.0.1:60866");
+        assert_eq!(entry.args, vec!["set", "key", "some value"]);
+    }
+
+    #[test]
+    fn parse_monitor_output_handles_lua_and_unix_clients() {
+        let entry = parse_monitor_output(r#"1.5 [3 lua] "get" "k""#).unwrap();
+        assert_eq!(entry.db, "3");
+        assert_eq!(entry.client, "lua");
+
+        let entry = parse_monitor_output(r#"1.5 [0 unix:/tmp/redis [1].sock] "ping""#).unwrap();
+        assert_eq!(entry.client, "unix:/tmp/redis [1].sock");
+        assert_eq!(entry.args, vec!["ping"]);
+    }
+
+    #[test]
+    fn parse_monitor_output_rejects_garbage() {
+        assert!(parse_monitor_output("").is_none());
+        assert!(parse_monitor_output("OK").is_none());
+        assert!(parse_monitor_output("1.5 no-bracket").is_none());
+    }
+
+    #[test]
+    fn parse_quoted_args_decodes_hex_escapes() {
+        assert_eq!(parse_quoted_args(r#""\x41\x42""#), vec!["AB"]);
+        // Multi-byte UTF-8 sent as escapes is reassembled
+        assert_eq!(parse_quoted_args(r#""caf\xc3\xa9""#), vec!["café"]);
+        // Invalid UTF-8 is shown with escapes again
+        assert_eq!(parse_quoted_args(r#""\x00\xff""#), vec![r"\x00\xff"]);
+    }
+
+    #[test]
+    fn parse_quoted_args_handles_escaped_quotes_and_backslashes() {
+        assert_eq!(
+            parse_quoted_args(r#""say \"hi\"" "a\\b""#),
+            vec![r#"say "hi""#, r"a\b"]
+        );
+    }
+
+    #[test]
+    fn parse_quoted_args_keeps_embedded_spaces_and_control_escapes() {
+        assert_eq!(
+            parse_quoted_args(r#""hello world" "a\nb\tc" """#),
+            vec!["hello world", "a\nb\tc", ""]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::connection::sentinel_uri;
+use crate::model::{SentinelConfig, SentinelEntry};
+use anyhow::Result;
+use futures::StreamExt;
+use redis::aio::MultiplexedConnection;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::timeout;
+
+/// Connect to the first reachable sentinel of the group
+pub async fn connect(config: &SentinelConfig, base_uri: &str) -> Result<MultiplexedConnection> {
+    let mut errors = Vec::new();
+
+    for addr in &config.addresses {
+        let client = redis::Client::open(sentinel_uri(base_uri, addr, config.password.as_deref()))?;
+        match timeout(
+            Duration::from_secs(5),
+            client.get_multiplexed_async_connection(),
+        )
+        .await
+        {
+            Ok(Ok(con)) => return Ok(con),
+            Ok(Err(e)) => errors.push(format!("{}: {}", addr, e)),
+            Err(_) => errors.push(format!("{}: timed out", addr)),
+        }
+    }
+
+    Err(anyhow::anyhow!(
+        "No sentinel reachable for '{}' ({})",
+        config.master_name,
+        errors.join(", ")
+    ))
+}
+
+/// Ask the sentinels for the address of the current master
+pub async fn resolve_master(config: &SentinelConfig, base_uri: &str) -> Result<(String, u16)> {
+    let mut con = connect(config, base_uri).await?;
+
+    let addr: Option<(String, u16)> = redis::cmd("SENTINEL")
+        .arg("GET-MASTER-ADDR-BY-NAME")
+        .arg(&config.master_name)
+        .query_async(&mut con)
+        .await?;
+
+    addr.ok_or_else(|| anyhow::anyhow!("Sentinels don't know master '{}'", config.master_name))
+}
+
+/// Whether the node behind `con` reports itself as a master. A sentinel may still
+/// hand out the old master right after a failover, which is a replica by then.
+pub async fn is_master(con: &mut MultiplexedConnection) -> Result<bool> {
+    let role: redis::Value = redis::cmd("ROLE").query_async(con).await?;
+    let first = match role {
+        redis::Value::Array(items) => items.into_iter().next(),
+        _ => None,
+    };
+    let role: Option<String> = first.and_then(|v| redis::from_redis_value(v).ok());
+    Ok(role.as_deref() == Some("master"))
+}
+
+/// Wait until the sentinels announce a failover of the configured master and
+/// return the address of the new master. Moves on to the next sentinel if the
+/// current one goes away.
+pub async fn wait_for_failover(config: &SentinelConfig, base_uri: &str) -> (String, u16) {
+    loop {
+        for addr in &config.addresses {
+            let uri = sentinel_uri(base_uri, addr, config.password.as_deref());
+            let Ok(client) = redis::Client::open(uri) else {
+                continue;
+            };
+            let Ok(Ok(mut pubsub)) =
+                timeout(Duration::from_secs(5), client.get_async_pubsub()).await
+            else {
+                continue;
+            };
+            if pubsub.subscribe("+switch-master").await.is_err() {
+                continue;
+            }
+
+            let mut messages = pubsub.on_message();
+            while let Some(msg) = messages.next().await {
+                // Payload: <master name> <old ip> <old port> <new ip> <new port>
+                let payload: String = msg.get_payload().unwrap_or_default();
+                let parts: Vec<&str> = payload.split_whitespace().collect();
+                if parts.len() == 5 && parts[0] == config.master_name {
+                    if let Ok(port) = parts[4].parse() {
+                        return (parts[3].to_string(), port);
+                    }
+                }
+            }
+        }
+
+        // No sentinel reachable right now, retry shortly
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+/// How long to wait before reconnection attempt `attempt` (from 1) after a
+/// failover: doubling from one second, at most 30 seconds
+pub fn reconnect_delay(attempt: u32) -> Duration {
+    let secs = 1u64
+        .checked_shl(attempt.saturating_sub(1))
+        .unwrap_or(u64::MAX);
+    Duration::from_secs(secs.min(30))
+}
+
+/// List every monitored master together with its replicas and sentinels
+pub async fn fetch_entries(con: &mut MultiplexedConnection) -> Result<Vec<SentinelEntry>> {
+    let masters: Vec<HashMap<String, String>> = redis::cmd("SENTINEL")
+        .arg("MASTERS")
+        .query_async(con)
+        .await?;
+
+    let mut entries = Vec::new();
+    for master in masters {
+        let master_name = field(&master, "name");
+
+        entries.push(SentinelEntry {
+            kind: "master".to_string(),
+            master: master_name.clone(),
+            name: master_name.clone(),
+            addr: address(&master),
+            flags: field(&master, "flags"),
+            status: format!(
+                "{} replicas, {} sentinels, quorum {}",
+                field(&master, "num-slaves"),
+                field(&master, "num-other-sentinels")
+                    .parse::<u64>()
+                    .map(|n| n + 1)
+                    .unwrap_or(1),
+                field(&master, "quorum")
+            ),
+        });
+
+        let replicas: Vec<HashMap<String, String>> = redis::cmd("SENTINEL")
+            .arg("REPLICAS")
+            .arg(&master_name)
+            .query_async(con)
+            .await
+            .unwrap_or_default();
+        for replica in replicas {
+            entries.push(SentinelEntry {
+                kind: "replica".to_string(),
+                master: master_name.clone(),
+                name: field(&replica, "name"),
+                addr: address(&replica),
+                flags: field(&replica, "flags"),
+                status: format!("link {}", field(&replica, "master-link-status")),
+            });
+        }
+
+        let sentinels: Vec<HashMap<String, String>> = redis::cmd("SENTINEL")
+            .arg("SENTINELS")
+            .arg(&master_name)
+            .query_async(con)
+            .await
+            .unwrap_or_default();
+        for sentinel in sentinels {
+            entries.push(SentinelEntry {
+                kind: "sentinel".to_string(),
+                master: master_name.clone(),
+                name: field(&sentinel, "runid").chars().take(8).collect(),
+                addr: address(&sentinel),
+                flags: field(&sentinel, "flags"),
+                status: format!("last ok ping {}ms", field(&sentinel, "last-ok-ping-reply")),
+            });
+        }
+    }
+
+    Ok(entries)
+}
+
+fn field(map: &HashMap<String, String>, key: &str) -> String {
+    map.get(key).cloned().unwrap_or_default()
+}
+
+fn address(map: &HashMap<String, String>) -> String {
+    format!("{}:{}", field(map, "ip"), field(map, "port"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconnect_delay_doubles_up_to_thirty_seconds() {
+        let delays: Vec<u64> = (1..=7).map(|a| reconnect_delay(a).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 30, 30]);
+        assert_eq!(reconnect_delay(0).as_secs(), 1);
+        assert_eq!(reconnect_delay(200).as_secs(), 30);
+    }
+}
@@ -0,0 +1,648 @@
+use crate::model::StreamEntry;
+use crate::viewer::bytes_to_text;
+use anyhow::Result;
+use redis::aio::MultiplexedConnection;
+use redis::Value;
+
+/// Most pending entries listed for a group
+pub const PENDING_LIMIT: usize = 100;
+
+/// How long an XREAD / XREADGROUP call waits for new entries
+pub const READ_BLOCK_MS: u64 = 5000;
+
+/// Entries returned per stream by one read
+const READ_COUNT: usize = 100;
+
+/// Field and value of an entry as returned by the server
+type RawField = (Vec<u8>, Vec<u8>);
+
+/// Entries per stream, as returned by XREAD and XREADGROUP
+type ReadReply = Vec<(String, Vec<(String, Vec<RawField>)>)>;
+
+/// How the streams view follows new entries
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamReadMode {
+    /// XREAD BLOCK after `start_id` (`$` for new entries only), without a group or acks
+    Tail { start_id: String },
+    /// XREADGROUP as `consumer` of `group`, acknowledging every entry read
+    Group { group: String, consumer: String },
+}
+
+/// Part of the stream inspector that receives navigation keys
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StreamPane {
+    #[default]
+    Entries,
+    Groups,
+    Consumers,
+    Pending,
+}
+
+impl StreamPane {
+    pub fn next(&self) -> StreamPane {
+        match self {
+            StreamPane::Entries => StreamPane::Groups,
+            StreamPane::Groups => StreamPane::Consumers,
+            StreamPane::Consumers => StreamPane::Pending,
+            StreamPane::Pending => StreamPane::Entries,
+        }
+    }
+}
+
+/// A consumer group as reported by `XINFO GROUPS`
+#[derive(Debug, Clone, Default)]
+pub struct StreamGroup {
+    pub name: String,
+    pub consumers: i64,
+    pub pending: i64,
+    pub last_delivered_id: String,
+    pub entries_read: Option<i64>, // Redis 7+
+    pub lag: Option<i64>,          // Redis 7+, unknown after some deletions
+}
+
+/// A group consumer as reported by `XINFO CONSUMERS`
+#[derive(Debug, Clone, Default)]
+pub struct StreamConsumer {
+    pub name: String,
+    pub pending: i64,
+    pub idle_ms: i64,             // Since the last attempted interaction
+    pub inactive_ms: Option<i64>, // Since the last successful interaction, Redis 7.2+
+}
+
+/// An entry delivered to a consumer and not acknowledged yet, from `XPENDING`
+#[derive(Debug, Clone, Default)]
+pub struct PendingEntry {
+    pub id: String,
+    pub consumer: String,
+    pub idle_ms: i64,    // Since it was last delivered
+    pub deliveries: i64, // Times it was delivered
+}
+
+/// Stream metadata shown next to the entries of a described stream
+#[derive(Debug, Clone, Default)]
+pub struct StreamDetails {
+    pub key: String,
+    pub summary: Vec<(String, String)>, // Scalar fields of `XINFO STREAM FULL`
+    pub groups: Vec<StreamGroup>,
+    pub selected_group: usize,
+    pub consumers: Vec<StreamConsumer>, // Of the selected group
+    pub selected_consumer: usize,
+    pub pending: Vec<PendingEntry>, // Of the selected group, oldest first
+    pub selected_pending: usize,
+    pub pane: StreamPane,
+    pub error: Option<String>,
+}
+
+impl StreamDetails {
+    pub fn selected_group(&self) -> Option<&StreamGroup> {
+        self.groups.get(self.selected_group)
+    }
+
+    pub fn selected_consumer(&self) -> Option<&StreamConsumer> {
+        self.consumers.get(self.selected_consumer)
+    }
+
+    pub fn selected_pending(&self) -> Option<&PendingEntry> {
+        self.pending.get(self.selected_pending)
+    }
+}
+
+/// Load the summary and groups of `key`, and the consumers of its first group
+pub async fn fetch_details(con: &mut MultiplexedConnection, key: &str) -> StreamDetails {
+    let mut details = StreamDetails {
+        key: key.to_string(),
+        ..StreamDetails::default()
+    };
+    if let Err(e) = refresh_details(con, &mut details).await {
+        details.error = Some(e.to_string());
+    }
+    details
+}
+
+/// Reload summary, groups and consumers, keeping the selected group when it still exists
+pub async fn refresh_details(
+    con: &mut MultiplexedConnection,
+    details: &mut StreamDetails,
+) -> Result<()> {
+    let selected = details.selected_group().map(|g| g.name.clone());
+
+    details.error = None;
+    details.summary = fetch_summary(con, &details.key).await?;
+    details.groups = fetch_groups(con, &details.key).await?;
+    details.selected_group = selected
+        .and_then(|name| details.groups.iter().position(|g| g.name == name))
+        .unwrap_or(0);
+    refresh_consumers(con, details).await
+}
+
+/// Reload the consumers and pending entries of the selected group
+pub async fn refresh_consumers(
+    con: &mut MultiplexedConnection,
+    details: &mut StreamDetails,
+) -> Result<()> {
+    (details.consumers, details.pending) = match details.selected_group() {
+        Some(group) => (
+            fetch_consumers(con, &details.key, &group.name).await?,
+            fetch_pending(con, &details.key, &group.name).await?,
+        ),
+        None => (Vec::new(), Vec::new()),
+    };
+    details.selected_consumer = details
+        .selected_consumer
+        .min(details.consumers.len().saturating_sub(1));
+    details.selected_pending = details
+        .selected_pending
+        .min(details.pending.len().saturating_sub(1));
+    Ok(())
+}
+
+/// Scalar fields of `XINFO STREAM key FULL`. COUNT 1 keeps the entries and
+/// per-group PEL it also returns small; groups are reduced to their number.
+async fn fetch_summary(
+    con: &mut MultiplexedConnection,
+    key: &str,
+) -> Result<Vec<(String, String)>> {
+    let info: Value = redis::cmd("XINFO")
+        .arg("STREAM")
+        .arg(key)
+        .arg("FULL")
+        .arg("COUNT")
+        .arg(1)
+        .query_async(con)
+        .await?;
+
+    let mut summary = Vec::new();
+    for (name, value) in pairs(info) {
+        match (name.as_str(), &value) {
+            ("groups", Value::Array(groups)) => summary.push((name, groups.len().to_string())),
+            (_, Value::Array(_) | Value::Map(_)) => {}
+            _ => summary.push((name, value_string(&value))),
+        }
+    }
+    Ok(summary)
+}
+
+async fn fetch_groups(con: &mut MultiplexedConnection, key: &str) -> Result<Vec<StreamGroup>> {
+    let groups: Vec<Value> = redis::cmd("XINFO")
+        .arg("GROUPS")
+        .arg(key)
+        .query_async(con)
+        .await?;
+
+    Ok(groups
+        .into_iter()
+        .map(|group| {
+            let mut info = StreamGroup::default();
+            for (name, value) in pairs(group) {
+                match name.as_str() {
+                    "name" => info.name = value_string(&value),
+                    "consumers" => info.consumers = value_int(&value).unwrap_or(0),
+                    "pending" => info.pending = value_int(&value).unwrap_or(0),
+                    "last-delivered-id" => info.last_delivered_id = value_string(&value),
+                    "entries-read" => info.entries_read = value_int(&value),
+                    "lag" => info.lag = value_int(&value),
+                    _ => {}
+                }
+            }
+            info
+        })
+        .collect())
+}
+
+async fn fetch_consumers(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+) -> Result<Vec<StreamConsumer>> {
+    let consumers: Vec<Value> = redis::cmd("XINFO")
+        .arg("CONSUMERS")
+        .arg(key)
+        .arg(group)
+        .query_async(con)
+        .await?;
+
+    Ok(consumers
+        .into_iter()
+        .map(|consumer| {
+            let mut info = StreamConsumer::default();
+            for (name, value) in pairs(consumer) {
+                match name.as_str() {
+                    "name" => info.name = value_string(&value),
+                    "pending" => info.pending = value_int(&value).unwrap_or(0),
+                    "idle" => info.idle_ms = value_int(&value).unwrap_or(0),
+                    "inactive" => info.inactive_ms = value_int(&value),
+                    _ => {}
+                }
+            }
+            info
+        })
+        .collect())
+}
+
+async fn fetch_pending(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+) -> Result<Vec<PendingEntry>> {
+    let entries: Vec<(String, String, i64, i64)> = redis::cmd("XPENDING")
+        .arg(key)
+        .arg(group)
+        .arg("-")
+        .arg("+")
+        .arg(PENDING_LIMIT)
+        .query_async(con)
+        .await?;
+
+    Ok(entries
+        .into_iter()
+        .map(|(id, consumer, idle_ms, deliveries)| PendingEntry {
+            id,
+            consumer,
+            idle_ms,
+            deliveries,
+        })
+        .collect())
+}
+
+/// `XGROUP CREATE` starting after `id`: `$` for new entries only, `0` for the whole stream
+pub async fn create_group(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+    id: &str,
+) -> Result<()> {
+    redis::cmd("XGROUP")
+        .arg("CREATE")
+        .arg(key)
+        .arg(group)
+        .arg(id)
+        .query_async::<()>(con)
+        .await?;
+    Ok(())
+}
+
+pub async fn destroy_group(con: &mut MultiplexedConnection, key: &str, group: &str) -> Result<()> {
+    redis::cmd("XGROUP")
+        .arg("DESTROY")
+        .arg(key)
+        .arg(group)
+        .query_async::<()>(con)
+        .await?;
+    Ok(())
+}
+
+/// `XGROUP SETID`, so the group is next delivered the entries after `id`
+pub async fn set_group_id(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+    id: &str,
+) -> Result<()> {
+    redis::cmd("XGROUP")
+        .arg("SETID")
+        .arg(key)
+        .arg(group)
+        .arg(id)
+        .query_async::<()>(con)
+        .await?;
+    Ok(())
+}
+
+/// `XGROUP DELCONSUMER`, returning the number of pending entries the consumer had
+pub async fn delete_consumer(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+    consumer: &str,
+) -> Result<i64> {
+    Ok(redis::cmd("XGROUP")
+        .arg("DELCONSUMER")
+        .arg(key)
+        .arg(group)
+        .arg(consumer)
+        .query_async(con)
+        .await?)
+}
+
+/// `XCLAIM` a pending entry for `consumer` whatever its idle time. False when
+/// the entry is no longer pending.
+pub async fn claim(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+    consumer: &str,
+    id: &str,
+) -> Result<bool> {
+    let claimed: Vec<String> = redis::cmd("XCLAIM")
+        .arg(key)
+        .arg(group)
+        .arg(consumer)
+        .arg(0)
+        .arg(id)
+        .arg("JUSTID")
+        .query_async(con)
+        .await?;
+    Ok(!claimed.is_empty())
+}
+
+/// `XAUTOCLAIM` the group's entries idle for at least `min_idle_ms`, scanning the
+/// whole PEL from `0-0`. Returns how many entries were claimed.
+pub async fn auto_claim(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+    consumer: &str,
+    min_idle_ms: u64,
+) -> Result<usize> {
+    let mut start = "0-0".to_string();
+    let mut claimed = 0;
+    loop {
+        // Redis 7 adds the IDs of deleted entries as a third element
+        let reply: Vec<Value> = redis::cmd("XAUTOCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_ms)
+            .arg(&start)
+            .arg("COUNT")
+            .arg(PENDING_LIMIT)
+            .arg("JUSTID")
+            .query_async(con)
+            .await?;
+        let mut reply = reply.into_iter();
+        start = reply.next().map(|v| value_string(&v)).unwrap_or_default();
+        if let Some(Value::Array(ids)) = reply.next() {
+            claimed += ids.len();
+        }
+        if start.is_empty() || start == "0-0" {
+            return Ok(claimed);
+        }
+    }
+}
+
+/// `XACK` a pending entry of the group. False when it was no longer pending.
+pub async fn ack(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    group: &str,
+    id: &str,
+) -> Result<bool> {
+    let acked: i64 = redis::cmd("XACK")
+        .arg(key)
+        .arg(group)
+        .arg(id)
+        .query_async(con)
+        .await?;
+    Ok(acked > 0)
+}
+
+/// ID of the last entry of `key`, or `0-0` when it is empty or missing. Resolves
+/// `$` once, so entries added between two XREAD calls are not skipped.
+pub async fn last_entry_id(con: &mut MultiplexedConnection, key: &str) -> Result<String> {
+    let last: Vec<(String, Vec<RawField>)> = redis::cmd("XREVRANGE")
+        .arg(key)
+        .arg("+")
+        .arg("-")
+        .arg("COUNT")
+        .arg(1)
+        .query_async(con)
+        .await?;
+    Ok(last
+        .into_iter()
+        .next()
+        .map(|(id, _)| id)
+        .unwrap_or_else(|| "0-0".to_string()))
+}
+
+/// XREAD BLOCK the entries after each `(stream, id)`, advancing the IDs past what
+/// was read. Returns nothing when no entry arrived within `READ_BLOCK_MS`.
+pub async fn read_after(
+    con: &mut MultiplexedConnection,
+    positions: &mut [(String, String)],
+) -> Result<Vec<(String, StreamEntry)>> {
+    let mut cmd = redis::cmd("XREAD");
+    cmd.arg("COUNT")
+        .arg(READ_COUNT)
+        .arg("BLOCK")
+        .arg(READ_BLOCK_MS)
+        .arg("STREAMS");
+    for (stream, _) in positions.iter() {
+        cmd.arg(stream);
+    }
+    for (_, id) in positions.iter() {
+        cmd.arg(id);
+    }
+    let reply: Option<ReadReply> = cmd.query_async(con).await?;
+
+    let entries = read_entries(reply.unwrap_or_default());
+    for (stream, entry) in &entries {
+        if let Some((_, id)) = positions.iter_mut().find(|(s, _)| s == stream) {
+            *id = entry.id.clone();
+        }
+    }
+    Ok(entries)
+}
+
+/// XREADGROUP BLOCK the entries of `streams` never delivered to `group`
+pub async fn read_group(
+    con: &mut MultiplexedConnection,
+    group: &str,
+    consumer: &str,
+    streams: &[String],
+) -> Result<Vec<(String, StreamEntry)>> {
+    let mut cmd = redis::cmd("XREADGROUP");
+    cmd.arg("GROUP")
+        .arg(group)
+        .arg(consumer)
+        .arg("COUNT")
+        .arg(READ_COUNT)
+        .arg("BLOCK")
+        .arg(READ_BLOCK_MS)
+        .arg("STREAMS")
+        .arg(streams);
+    for _ in streams {
+        cmd.arg(">");
+    }
+    let reply: Option<ReadReply> = cmd.query_async(con).await?;
+    Ok(read_entries(reply.unwrap_or_default()))
+}
+
+fn read_entries(reply: ReadReply) -> Vec<(String, StreamEntry)> {
+    reply
+        .into_iter()
+        .flat_map(|(stream, entries)| {
+            entries.into_iter().map(move |(id, fields)| {
+                let entry = StreamEntry {
+                    id,
+                    fields: fields
+                        .into_iter()
+                        .map(|(field, value)| (bytes_to_text(field).0, bytes_to_text(value).0))
+                        .collect(),
+                };
+                (stream.clone(), entry)
+            })
+        })
+        .collect()
+}
+
+/// Field/value pairs of an XINFO reply: a flat array on RESP2, a map on RESP3
+fn pairs(value: Value) -> Vec<(String, Value)> {
+    match value {
+        Value::Map(entries) => entries
+            .into_iter()
+            .map(|(k, v)| (value_string(&k), v))
+            .collect(),
+        Value::Array(items) => {
+            let mut items = items.into_iter();
+            let mut pairs = Vec::new();
+            while let (Some(k), Some(v)) = (items.next(), items.next()) {
+                pairs.push((value_string(&k), v));
+            }
+            pairs
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn value_string(value: &Value) -> String {
+    match value {
+        Value::Nil => "-".to_string(),
+        Value::Int(i) => i.to_string(),
+        Value::Double(d) => d.to_string(),
+        Value::Boolean(b) => b.to_string(),
+        Value::BulkString(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        Value::SimpleString(s) => s.clone(),
+        Value::VerbatimString { text, .. } => text.clone(),
+        Value::Okay => "OK".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn value_int(value: &Value) -> Option<i64> {
+    match value {
+        Value::Int(i) => Some(*i),
+        Value::BulkString(_) | Value::SimpleString(_) => value_string(value).parse().ok(),
+        _ => None,
+    }
+}
+
+/// Local time of the millisecond part of a stream entry ID
+pub fn id_timestamp(id: &str) -> String {
+    let ms = id.split('-').next().and_then(|ms| ms.parse::<i64>().ok());
+    match ms.and_then(chrono::DateTime::from_timestamp_millis) {
+        Some(time) => time
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S%.3f")
+            .to_string(),
+        None => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_entries_escapes_binary_values() {
+        let reply = vec![(
+            "s".to_string(),
+            vec![("1-0".to_string(), vec![(b"f".to_vec(), vec![0xff, b'a'])])],
+        )];
+        let entries = read_entries(reply);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0, "s");
+        assert_eq!(entries[0].1.fields["f"], r"\xffa");
+    }
+
+    #[test]
+    fn id_timestamp_needs_a_millisecond_part() {
+        assert_eq!(id_timestamp("x-1"), "-");
+        assert_ne!(id_timestamp("1700000000000-0"), "-");
+    }
+
+    #[test]
+    fn pairs_reads_resp2_arrays_and_resp3_maps() {
+        let bulk = |s: &str| Value::BulkString(s.as_bytes().to_vec());
+        let resp2 = Value::Array(vec![
+            bulk("name"),
+            bulk("g1"),
+            bulk("pending"),
+            Value::Int(3),
+            bulk("lag"),
+            Value::Nil,
+            bulk("dangling"),
+        ]);
+        let resp3 = Value::Map(vec![
+            (Value::SimpleString("name".to_string()), bulk("g1")),
+            (Value::SimpleString("pending".to_string()), Value::Int(3)),
+            (Value::SimpleString("lag".to_string()), Value::Nil),
+        ]);
+        for reply in [resp2, resp3] {
+            let pairs = pairs(reply);
+            let names: Vec<&str> = pairs.iter().map(|(k, _)| k.as_str()).collect();
+            assert_eq!(names, ["name", "pending", "lag"]);
+            assert_eq!(value_string(&pairs[0].1), "g1");
+            assert_eq!(value_int(&pairs[1].1), Some(3));
+            assert_eq!(value_string(&pairs[2].1), "-");
+            assert_eq!(value_int(&pairs[2].1), None);
+        }
+        assert!(pairs(Value::Int(1)).is_empty());
+    }
+
+    #[test]
+    fn value_int_parses_numeric_strings() {
+        assert_eq!(value_int(&Value::BulkString(b"42".to_vec())), Some(42));
+        assert_eq!(value_int(&Value::SimpleString("-7".to_string())), Some(-7));
+        assert_eq!(value_int(&Value::BulkString(b"1-0".to_vec())), None);
+        assert_eq!(value_int(&Value::Double(1.0)), None);
+    }
+
+    #[test]
+    fn stream_pane_cycles_back_to_entries() {
+        let mut pane = StreamPane::default();
+        let mut visited = Vec::new();
+        for _ in 0..4 {
+            visited.push(pane);
+            pane = pane.next();
+        }
+        assert_eq!(
+            visited,
+            [
+                StreamPane::Entries,
+                StreamPane::Groups,
+                StreamPane::Consumers,
+                StreamPane::Pending
+            ]
+        );
+        assert_eq!(pane, StreamPane::Entries);
+    }
+
+    #[tokio::test]
+    async fn auto_claim_follows_the_cursor_until_it_wraps() {
+        use crate::fake_redis::{bulk, FakeRedis};
+        let server = FakeRedis::start(|cmd| match cmd[0].as_str() {
+            // Redis 7 reply with the deleted IDs as third element
+            "XAUTOCLAIM" if cmd[5] == "0-0" => Some(format!(
+                "*3\r\n{}*2\r\n{}{}*0\r\n",
+                bulk("5-0"),
+                bulk("1-0"),
+                bulk("2-0")
+            )),
+            "XAUTOCLAIM" => Some(format!("*2\r\n{}*1\r\n{}", bulk("0-0"), bulk("6-0"))),
+            _ => None,
+        })
+        .await;
+        let client = redis::Client::open(server.uri.as_str()).unwrap();
+        let mut con = client.get_multiplexed_async_connection().await.unwrap();
+
+        let claimed = auto_claim(&mut con, "s", "g", "me", 1000).await.unwrap();
+        assert_eq!(claimed, 3);
+        let starts: Vec<String> = server
+            .commands()
+            .into_iter()
+            .filter(|c| c[0] == "XAUTOCLAIM")
+            .map(|c| c[5].clone())
+            .collect();
+        assert_eq!(starts, ["0-0", "5-0"]);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::analyze::{AnalysisReport, TOP_N};
+use crate::app::App;
+use crate::ui::format_bytes;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let title = match &app.analysis_export {
+        Some(path) => format!(" Keyspace Analysis - exported to {} ", path.display()),
+        None => " Keyspace Analysis ".to_string(),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let Some(report) = &app.analysis else {
+        let empty_msg = Paragraph::new("No analysis yet. Press R to scan the keyspace.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty_msg, inner_area);
+        return;
+    };
+    let report = report.lock().unwrap();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Progress
+            Constraint::Length(1), // Spacer
+            Constraint::Min(1),    // Report
+        ])
+        .split(inner_area);
+
+    let status = if let Some(e) = &report.error {
+        format!("Failed: {}", e)
+    } else if report.finished {
+        format!("Done: {} keys", report.scanned_keys)
+    } else if app.analysis_running() {
+        format!("{} / {} keys", report.scanned_keys, report.total_keys)
+    } else {
+        format!("Cancelled at {} keys", report.scanned_keys)
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(if report.error.is_some() {
+            Color::Red
+        } else {
+            Color::Green
+        }))
+        .ratio(report.progress())
+        .label(status);
+    f.render_widget(gauge, chunks[0]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[2]);
+
+    f.render_widget(
+        Paragraph::new(left_column(&report)).scroll((app.analysis_scroll as u16, 0)),
+        columns[0],
+    );
+    f.render_widget(
+        Paragraph::new(right_column(&report)).scroll((app.analysis_scroll as u16, 0)),
+        columns[1],
+    );
+}
+
+fn section(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn stat_line(label: &str, value: String, detail: String) -> Line<'static> {
+    let label = if label.chars().count() > 28 {
+        format!("{}…", label.chars().take(27).collect::<String>())
+    } else {
+        label.to_string()
+    };
+    Line::from(vec![
+        Span::styled(format!("  {:<28}", label), Style::default().fg(Color::Cyan)),
+        Span::styled(format!("{:>12}", value), Style::default().fg(Color::White)),
+        Span::styled(
+            format!("  {}", detail),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
+
+fn percent(part: u64, total: u64) -> String {
+    if total == 0 {
+        String::new()
+    } else {
+        format!("{:.1}%", part as f64 * 100.0 / total as f64)
+    }
+}
+
+fn left_column(report: &AnalysisReport) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        section("Summary"),
+        stat_line("Keys", report.scanned_keys.to_string(), String::new()),
+        stat_line("Memory", format_bytes(report.total_memory), String::new()),
+        Line::from(""),
+        section("Types"),
+    ];
+    for (key_type, stats) in &report.types {
+        lines.push(stat_line(
+            key_type,
+            stats.keys.to_string(),
+            format!(
+                "{}  {}",
+                percent(stats.keys, report.scanned_keys),
+                format_bytes(stats.memory)
+            ),
+        ));
+    }
+
+    let ttl = &report.ttl;
+    lines.push(Line::from(""));
+    lines.push(section("TTL"));
+    for (label, count) in [
+        ("No expiry", ttl.no_expiry),
+        ("< 1 hour", ttl.under_hour),
+        ("< 1 day", ttl.under_day),
+        ("Longer", ttl.longer),
+    ] {
+        lines.push(stat_line(
+            label,
+            count.to_string(),
+            percent(count, report.scanned_keys),
+        ));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section(&format!("Top {} prefixes by memory", TOP_N)));
+    for (prefix, stats) in report.top_prefixes(TOP_N) {
+        lines.push(stat_line(
+            prefix,
+            format_bytes(stats.memory),
+            format!("{} keys", stats.keys),
+        ));
+    }
+
+    lines
+}
+
+fn right_column(report: &AnalysisReport) -> Vec<Line<'static>> {
+    let mut lines = vec![section(&format!("Top {} keys by memory", TOP_N))];
+    for entry in &report.biggest_by_memory {
+        lines.push(stat_line(
+            &entry.key,
+            format_bytes(entry.size),
+            entry.key_type.clone(),
+        ));
+    }
+
+    for (key_type, entries) in &report.biggest_by_elements {
+        let unit = match key_type.as_str() {
+            "string" => "bytes",
+            "list" => "items",
+            "hash" => "fields",
+            "stream" => "entries",
+            _ => "members",
+        };
+        lines.push(Line::from(""));
+        lines.push(section(&format!("Biggest {} keys ({})", key_type, unit)));
+        for entry in entries {
+            lines.push(stat_line(&entry.key, entry.size.to_string(), String::new()));
+        }
+    }
+
+    lines
+}
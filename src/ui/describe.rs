@@ -1,24 +1,80 @@
+use super::info_view::highlight_matches;
 use crate::app::App;
 use crate::model::KeyValue;
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
     Frame,
 };
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Split area for search input if active
+    let (area, search_area) = if app.describe_search_active {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(3)])
+            .split(area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (area, None)
+    };
+
     let key_info = if !app.scan_result.is_empty() {
         Some(&app.scan_result[app.selected_key_index])
     } else {
         None
     };
 
-    let title = if let Some(info) = key_info {
-        format!(" Describe: {} ({}) ", info.key, info.key_type)
+    let title = match (&app.describe_window, key_info) {
+        // Streams opened from the streams resource are not in the keys list
+        (Some(window), _) if app.describe_key.is_none() => {
+            format!(" Describe: {} ({}) ", window.key, window.key_type)
+        }
+        (_, Some(info)) => format!(" Describe: {} ({}) ", info.key, info.key_type),
+        _ => " Describe ".to_string(),
+    };
+    // Collections load in windows, so show how much of the total is loaded
+    let title = match (&app.describe_window, &app.describe_search_results) {
+        (_, Some(_)) if app.describe_search_truncated => format!(
+            "{}[First {} server matches, refine the search] ",
+            title,
+            app.collection_rows().len()
+        ),
+        (_, Some(_)) => format!(
+            "{}[{} server matches, Esc for all rows] ",
+            title,
+            app.collection_rows().len()
+        ),
+        (Some(window), None) => format!(
+            "{}[{}/{}] ",
+            title,
+            app.collection_rows().len(),
+            window.total
+        ),
+        (None, None) => title,
+    };
+    let title = match &app.describe_data {
+        KeyValue::Bytes(raw) => format!(
+            "{}[{} bytes, {}] ",
+            title,
+            raw.len(),
+            app.describe_viewer_label()
+        ),
+        _ => title,
+    };
+    let title = if !app.describe_search_text.is_empty() && !app.describe_search_matches.is_empty() {
+        format!(
+            "{}[Match {}/{}] ",
+            title,
+            app.describe_search_current + 1,
+            app.describe_search_matches.len()
+        )
+    } else if !app.describe_search_text.is_empty() {
+        format!("{}[No matches] ", title)
     } else {
-        " Describe ".to_string()
+        title
     };
 
     let block = Block::default()
@@ -31,24 +87,48 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center);
+    let block = match &app.describe_message {
+        Some(message) => block.title_bottom(Span::styled(
+            format!(" {} ", message),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        None => block,
+    };
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    let content_text = match &app.describe_data {
-        KeyValue::String(s) => s.clone(),
-        KeyValue::List(l) => serde_json::to_string_pretty(l).unwrap_or_default(),
-        KeyValue::Set(s) => serde_json::to_string_pretty(s).unwrap_or_default(),
-        KeyValue::ZSet(z) => serde_json::to_string_pretty(z).unwrap_or_default(),
-        KeyValue::Hash(h) => serde_json::to_string_pretty(h).unwrap_or_default(),
-        KeyValue::Stream(_) => "Stream data...".to_string(),
-        KeyValue::None => "No data loaded.".to_string(),
-        KeyValue::Error(e) => format!("Error: {}", e),
-    };
+    if let Some(search_rect) = search_area {
+        render_search_input(f, app, search_rect);
+    }
+
+    if matches!(app.describe_data, KeyValue::Stream(_)) {
+        super::stream_inspector::render(f, app, inner_area);
+        return;
+    }
 
+    if app.describe_is_table() {
+        render_collection(f, app, inner_area);
+        return;
+    }
+
+    let content_text = app.describe_text();
+
+    let current_match = app
+        .describe_search_matches
+        .get(app.describe_search_current)
+        .copied();
     let lines: Vec<Line> = content_text
         .lines()
-        .map(|l| Line::from(Span::styled(l, Style::default().fg(Color::White))))
+        .enumerate()
+        .map(|(idx, l)| {
+            Line::from(highlight_matches(
+                l,
+                &app.describe_search_text,
+                Color::White,
+                current_match == Some(idx),
+            ))
+        })
         .collect();
 
     let scroll = app.describe_scroll as u16;
@@ -56,3 +136,105 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
 
     f.render_widget(paragraph, inner_area);
 }
+
+/// Hash, list, set and sorted set values as a table with a selectable row
+fn render_collection(f: &mut Frame, app: &App, area: Rect) {
+    // Hash field TTLs are only known on Redis 7.4+
+    let field_ttls =
+        matches!(app.describe_data, KeyValue::Hash(_)) && !app.describe_field_ttls.is_empty();
+
+    let headers: &[&str] = match app.describe_data {
+        KeyValue::Hash(_) if field_ttls => &["Field", "Value", "TTL"],
+        KeyValue::Hash(_) => &["Field", "Value"],
+        KeyValue::List(_) => &["Index", "Value"],
+        KeyValue::Set(_) => &["Member"],
+        _ => &["Member", "Score"],
+    };
+
+    let header = Row::new(headers.iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    }))
+    .height(1);
+
+    let search = app.describe_search_text.as_str();
+    let current_match = app
+        .describe_search_matches
+        .get(app.describe_search_current)
+        .copied();
+    let rows_data = app.collection_rows();
+    let rows = rows_data.iter().enumerate().map(|(idx, (first, second))| {
+        let is_current = current_match == Some(idx);
+        let mut cells = vec![Cell::from(Line::from(highlight_matches(
+            first,
+            search,
+            Color::White,
+            is_current,
+        )))];
+        if headers.len() > 1 {
+            cells.push(Cell::from(Line::from(highlight_matches(
+                second,
+                search,
+                Color::White,
+                is_current,
+            ))));
+        }
+        if field_ttls {
+            let ttl = match app.describe_field_ttls.get(first) {
+                Some(ttl) if *ttl >= 0 => format!("{}s", ttl),
+                _ => "-".to_string(),
+            };
+            cells.push(Cell::from(ttl));
+        }
+        Row::new(cells).style(Style::default().fg(Color::White))
+    });
+
+    let widths: &[Constraint] = match app.describe_data {
+        KeyValue::Set(_) => &[Constraint::Percentage(100)],
+        KeyValue::List(_) => &[Constraint::Length(8), Constraint::Min(10)],
+        KeyValue::ZSet(_) => &[Constraint::Min(10), Constraint::Length(20)],
+        _ if field_ttls => &[
+            Constraint::Percentage(30),
+            Constraint::Percentage(55),
+            Constraint::Percentage(15),
+        ],
+        _ => &[Constraint::Percentage(30), Constraint::Percentage(70)],
+    };
+
+    let table = Table::new(rows, widths.to_vec())
+        .header(header)
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    let mut state = TableState::default();
+    if !rows_data.is_empty() {
+        state.select(Some(app.describe_selected));
+    }
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn render_search_input(f: &mut Frame, app: &App, area: Rect) {
+    let search_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(Span::styled(
+            " Search (Enter: search whole key, n/N: next/prev, Esc: close) ",
+            Style::default().fg(Color::Yellow),
+        ));
+
+    let search_input = Paragraph::new(Line::from(vec![
+        Span::styled("/", Style::default().fg(Color::Yellow)),
+        Span::styled(&app.describe_search_text, Style::default().fg(Color::White)),
+        Span::styled("█", Style::default().fg(Color::Yellow)),
+    ]))
+    .block(search_block);
+
+    f.render_widget(search_input, area);
+}
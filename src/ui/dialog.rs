@@ -1,9 +1,10 @@
-use crate::app::{App, Mode, PendingActionType};
+use crate::app::{App, Mode, PendingAction, PendingActionType};
+use crate::keys::TtlChange;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
@@ -36,6 +37,90 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
             "Delete Selected Keys",
             format!("Delete {} selected keys?", pending.matched_keys.len()),
         ),
+        PendingActionType::OverwriteKey => (
+            "Overwrite Key",
+            format!(
+                "Key '{}' already exists. Replace it with the new {}?",
+                pending.key,
+                app.new_key_dialog_state.key_type()
+            ),
+        ),
+        PendingActionType::SetTtl => {
+            let ttl = pending
+                .field_edit
+                .as_ref()
+                .map(|e| e.value.clone())
+                .unwrap_or_default();
+            let target = if pending.matched_keys.is_empty() {
+                format!("'{}'", pending.key)
+            } else {
+                format!("{} selected keys", pending.matched_keys.len())
+            };
+            if expires_now(&ttl) {
+                (
+                    "Delete Keys",
+                    format!(
+                        "A TTL of {} expires {} immediately, which DELETES them. Continue?",
+                        ttl, target
+                    ),
+                )
+            } else {
+                ("Set TTL", format!("Set the TTL of {} to {}?", target, ttl))
+            }
+        }
+        PendingActionType::RenameKey => (
+            "Rename Key",
+            format!("Rename '{}' to '{}'?", pending.key, edit_target(pending)),
+        ),
+        PendingActionType::RenameKeyOverwrite => (
+            "Overwrite Key",
+            format!(
+                "'{}' already exists. Rename '{}' over it?",
+                edit_target(pending),
+                pending.key
+            ),
+        ),
+        PendingActionType::CopyKey => (
+            "Copy Key",
+            format!(
+                "Copy '{}' to '{}' in db {}?",
+                pending.key,
+                edit_target(pending),
+                edit_value(pending)
+            ),
+        ),
+        PendingActionType::CopyKeyReplace => (
+            "Replace Key",
+            format!(
+                "'{}' already exists in db {}. Replace it with a copy of '{}'?",
+                edit_target(pending),
+                edit_value(pending),
+                pending.key
+            ),
+        ),
+        PendingActionType::MoveKeys => {
+            let target = if pending.matched_keys.is_empty() {
+                format!("'{}'", pending.key)
+            } else {
+                format!("{} selected keys", pending.matched_keys.len())
+            };
+            (
+                "Move Keys",
+                format!("Move {} to db {}?", target, edit_value(pending)),
+            )
+        }
+        PendingActionType::ConsumeStreams => (
+            "Consume Streams",
+            format!(
+                "Read {} as consumer '{}' of group '{}'? Streams without the group get it \
+                created at their start, and every entry read is acknowledged.",
+                app.streams_to_read().join(", "),
+                edit_value(pending),
+                edit_target(pending)
+            ),
+        ),
+        _ if pending.action_type.is_stream_group_action() => stream_group_text(app, pending),
+        _ => field_edit_text(pending),
     };
 
     let title_color = Color::Red;
@@ -76,11 +161,155 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
 
     let paragraph = Paragraph::new(text)
         .block(block)
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
 
     f.render_widget(paragraph, area);
 }
 
+fn edit_target(pending: &PendingAction) -> &str {
+    pending
+        .field_edit
+        .as_ref()
+        .map(|e| e.target.as_str())
+        .unwrap_or("")
+}
+
+fn edit_value(pending: &PendingAction) -> &str {
+    pending
+        .field_edit
+        .as_ref()
+        .map(|e| e.value.as_str())
+        .unwrap_or("")
+}
+
+/// Title and message for changes to a single row of the described key
+fn field_edit_text(pending: &PendingAction) -> (&'static str, String) {
+    let edit = pending.field_edit.clone().unwrap_or_default();
+    let (target, value, key) = (edit.target, edit.value, &pending.key);
+    match pending.action_type {
+        PendingActionType::HashSetField => (
+            "HSET",
+            format!("Set field '{}' of '{}' to '{}'?", target, key, value),
+        ),
+        PendingActionType::HashAddField => (
+            "HSET",
+            format!("Add field '{}' = '{}' to '{}'?", target, value, key),
+        ),
+        PendingActionType::HashDeleteField => {
+            ("HDEL", format!("Delete field '{}' from '{}'?", target, key))
+        }
+        PendingActionType::ListSetItem => (
+            "LSET",
+            format!("Set element {} of '{}' to '{}'?", target, key, value),
+        ),
+        PendingActionType::ListPushItem => ("RPUSH", format!("Append '{}' to '{}'?", value, key)),
+        PendingActionType::ListRemoveItem => (
+            "LREM",
+            format!("Remove element {} ('{}') from '{}'?", target, value, key),
+        ),
+        PendingActionType::SetReplaceMember => (
+            "SREM + SADD",
+            format!("Replace member '{}' of '{}' with '{}'?", target, key, value),
+        ),
+        PendingActionType::SetAddMember => ("SADD", format!("Add '{}' to '{}'?", value, key)),
+        PendingActionType::SetRemoveMember => {
+            ("SREM", format!("Remove '{}' from '{}'?", target, key))
+        }
+        PendingActionType::ZSetUpdateScore => (
+            "ZADD",
+            format!("Set score of '{}' in '{}' to {}?", target, key, value),
+        ),
+        PendingActionType::ZSetAddMember => (
+            "ZADD",
+            format!("Add '{}' with score {} to '{}'?", target, value, key),
+        ),
+        PendingActionType::ZSetRemoveMember => {
+            ("ZREM", format!("Remove '{}' from '{}'?", target, key))
+        }
+        PendingActionType::HashFieldTtl if expires_now(&value) => (
+            "HEXPIRE",
+            format!(
+                "A TTL of {} expires field '{}' in '{}' immediately, which DELETES it. Continue?",
+                value, target, key
+            ),
+        ),
+        PendingActionType::HashFieldTtl => (
+            "HEXPIRE",
+            format!(
+                "Set the TTL of field '{}' in '{}' to {}?",
+                target, key, value
+            ),
+        ),
+        _ => ("Confirm", String::new()),
+    }
+}
+
+/// Whether the TTL typed in the dialog removes its target right away
+fn expires_now(ttl: &str) -> bool {
+    TtlChange::parse(ttl).is_ok_and(|change| change.expires_now())
+}
+
+/// Title and message for consumer group actions on the described stream
+fn stream_group_text(app: &App, pending: &PendingAction) -> (&'static str, String) {
+    let (target, value, key) = (edit_target(pending), edit_value(pending), &pending.key);
+    let group = app
+        .stream_details
+        .as_ref()
+        .and_then(|d| d.selected_group())
+        .map(|g| g.name.as_str())
+        .unwrap_or("");
+    match pending.action_type {
+        PendingActionType::StreamCreateGroup => (
+            "XGROUP CREATE",
+            format!(
+                "Create group '{}' on '{}' starting after {}?",
+                target, key, value
+            ),
+        ),
+        PendingActionType::StreamDestroyGroup => (
+            "XGROUP DESTROY",
+            format!(
+                "Destroy group '{}' of '{}' and its {} pending entries?",
+                target, key, value
+            ),
+        ),
+        PendingActionType::StreamSetGroupId => (
+            "XGROUP SETID",
+            format!(
+                "Deliver entries after {} next to group '{}'?",
+                value, target
+            ),
+        ),
+        PendingActionType::StreamDeleteConsumer => (
+            "XGROUP DELCONSUMER",
+            format!(
+                "Delete consumer '{}' of '{}'? Its {} pending entries are dropped.",
+                target, group, value
+            ),
+        ),
+        PendingActionType::StreamClaim => (
+            "XCLAIM",
+            format!("Give {} to consumer '{}' of '{}'?", target, value, group),
+        ),
+        PendingActionType::StreamAutoClaim => (
+            "XAUTOCLAIM",
+            format!(
+                "Give every entry of '{}' idle for {} ms to consumer '{}'?",
+                group, target, value
+            ),
+        ),
+        PendingActionType::StreamAck => (
+            "XACK",
+            format!(
+                "Acknowledge {} delivered to '{}' in group '{}'?",
+                target, value, group
+            ),
+        ),
+        _ => ("Confirm", String::new()),
+    }
+}
+
 fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
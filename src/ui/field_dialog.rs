@@ -0,0 +1,291 @@
+use crate::app::PendingActionType;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// A labelled input of the field dialog: free text, or one of `choices`
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label: String,
+    pub value: String,
+    pub choices: Vec<String>, // Empty for text inputs
+}
+
+/// State for the dialog that collects the values of a describe view edit
+#[derive(Debug, Clone)]
+pub struct FieldDialogState {
+    pub title: String,
+    pub action_type: PendingActionType,
+    pub target: String, // Hash field, list index or member being edited
+    pub fields: Vec<FormField>,
+    pub active_field: usize,
+    pub error_message: Option<String>,
+    pub hint: Option<String>, // Shown in place of the error
+    pub submit_label: &'static str,
+}
+
+impl FieldDialogState {
+    pub fn new(title: &str, action_type: PendingActionType, target: String) -> Self {
+        Self {
+            title: title.to_string(),
+            action_type,
+            target,
+            fields: Vec::new(),
+            active_field: 0,
+            error_message: None,
+            hint: None,
+            submit_label: "apply",
+        }
+    }
+
+    pub fn with_field(mut self, label: &str, value: String) -> Self {
+        self.fields.push(FormField {
+            label: label.to_string(),
+            value,
+            choices: Vec::new(),
+        });
+        self
+    }
+
+    /// Add a selector cycling through `choices`, starting at the first
+    pub fn with_choice(mut self, label: &str, choices: &[&str]) -> Self {
+        self.fields.push(FormField {
+            label: label.to_string(),
+            value: choices.first().copied().unwrap_or_default().to_string(),
+            choices: choices.iter().map(|c| c.to_string()).collect(),
+        });
+        self
+    }
+
+    pub fn with_submit_label(mut self, label: &'static str) -> Self {
+        self.submit_label = label;
+        self
+    }
+
+    pub fn with_hint(mut self, hint: &str) -> Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    pub fn toggle_field(&mut self) {
+        if !self.fields.is_empty() {
+            self.active_field = (self.active_field + 1) % self.fields.len();
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        if !self.fields.is_empty() {
+            self.active_field = (self.active_field + self.fields.len() - 1) % self.fields.len();
+        }
+    }
+
+    /// Whether the active field is a selector rather than a text input
+    pub fn choice_active(&self) -> bool {
+        self.fields
+            .get(self.active_field)
+            .is_some_and(|f| !f.choices.is_empty())
+    }
+
+    /// Move the active selector to the next or previous choice
+    pub fn cycle_choice(&mut self, forward: bool) {
+        let Some(field) = self.fields.get_mut(self.active_field) else {
+            return;
+        };
+        let count = field.choices.len();
+        if count == 0 {
+            return;
+        }
+        let index = field
+            .choices
+            .iter()
+            .position(|c| *c == field.value)
+            .unwrap_or(0);
+        let index = if forward {
+            (index + 1) % count
+        } else {
+            (index + count - 1) % count
+        };
+        field.value = field.choices[index].clone();
+        self.error_message = None;
+    }
+
+    pub fn current_input_mut(&mut self) -> Option<&mut String> {
+        self.fields
+            .get_mut(self.active_field)
+            .filter(|f| f.choices.is_empty())
+            .map(|f| &mut f.value)
+    }
+
+    /// Type into the active text input; space cycles a selector
+    pub fn push_char(&mut self, c: char) {
+        if let Some(input) = self.current_input_mut() {
+            input.push(c);
+        } else if c == ' ' {
+            self.cycle_choice(true);
+        }
+        self.error_message = None;
+    }
+
+    pub fn pop_char(&mut self) {
+        if let Some(input) = self.current_input_mut() {
+            input.pop();
+        }
+        self.error_message = None;
+    }
+
+    pub fn set_error(&mut self, msg: String) {
+        self.error_message = Some(msg);
+    }
+
+    /// Value of the field labelled `label`
+    pub fn value(&self, label: &str) -> &str {
+        self.fields
+            .iter()
+            .find(|f| f.label == label)
+            .map(|f| f.value.as_str())
+            .unwrap_or("")
+    }
+}
+
+pub fn render(f: &mut Frame, state: &FieldDialogState) {
+    let height = 6 + state.fields.len() as u16 * 3;
+    let area = centered_rect(60, height, f.area());
+
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" {} ", state.title))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray));
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut constraints = vec![
+        Constraint::Length(1), // Instruction
+        Constraint::Length(1), // Spacer
+    ];
+    for _ in &state.fields {
+        constraints.push(Constraint::Length(1)); // Label
+        constraints.push(Constraint::Length(1)); // Input
+        constraints.push(Constraint::Length(1)); // Spacer
+    }
+    constraints.push(Constraint::Length(1)); // Error
+    constraints.push(Constraint::Min(0));
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints(constraints)
+        .split(inner);
+
+    // Instructions
+    let instructions = Paragraph::new(Line::from(vec![
+        Span::styled("<Tab>", Style::default().fg(Color::Yellow)),
+        Span::styled(" switch field  ", Style::default().fg(Color::DarkGray)),
+        Span::styled("<Enter>", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!(" {}  ", state.submit_label),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled("<Esc>", Style::default().fg(Color::Yellow)),
+        Span::styled(" cancel", Style::default().fg(Color::DarkGray)),
+    ]));
+    f.render_widget(instructions, chunks[0]);
+
+    for (i, field) in state.fields.iter().enumerate() {
+        let active = i == state.active_field;
+
+        let label_style = if active {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let label = Paragraph::new(Span::styled(format!("{}:", field.label), label_style));
+        f.render_widget(label, chunks[2 + i * 3]);
+
+        if !field.choices.is_empty() {
+            f.render_widget(render_choices(field, active), chunks[3 + i * 3]);
+            continue;
+        }
+        let (text, style) = if active {
+            (
+                format!(" {}_", field.value),
+                Style::default().fg(Color::White).bg(Color::DarkGray),
+            )
+        } else {
+            (
+                format!(" {}", field.value),
+                Style::default().fg(Color::White),
+            )
+        };
+        f.render_widget(Paragraph::new(text).style(style), chunks[3 + i * 3]);
+    }
+
+    if let Some(ref error) = state.error_message {
+        let error_text = Paragraph::new(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        ));
+        f.render_widget(error_text, chunks[2 + state.fields.len() * 3]);
+    } else if let Some(ref hint) = state.hint {
+        let help = Paragraph::new(Span::styled(
+            hint.as_str(),
+            Style::default().fg(Color::DarkGray),
+        ));
+        f.render_widget(help, chunks[2 + state.fields.len() * 3]);
+    }
+}
+
+/// The choices of a selector in one line, the current one highlighted
+fn render_choices(field: &FormField, active: bool) -> Paragraph<'_> {
+    let mut spans = vec![Span::raw(" ")];
+    for choice in &field.choices {
+        let style = if *choice == field.value {
+            Style::default()
+                .fg(Color::Black)
+                .bg(if active {
+                    Color::Magenta
+                } else {
+                    Color::DarkGray
+                })
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        spans.push(Span::styled(format!(" {} ", choice), style));
+        spans.push(Span::raw(" "));
+    }
+    Paragraph::new(Line::from(spans))
+}
+
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Length(height),
+            Constraint::Percentage(30),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -1,4 +1,6 @@
-use crate::app::App;
+use crate::app::{App, Mode};
+use crate::model::KeyValue;
+use crate::streams::StreamPane;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -27,7 +29,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
-    let lines = vec![
+    let mut lines = vec![
         Line::from(vec![
             Span::styled("Server:  ", Style::default().fg(Color::DarkGray)),
             Span::styled(
@@ -48,6 +50,28 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
         ]),
     ];
 
+    if let Some(ref reconnect) = app.sentinel_reconnect {
+        lines.push(Line::from(vec![
+            Span::styled("Master:  ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("reconnecting, {}", reconnect),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    if let Some(ref node) = app.focused_node {
+        lines.push(Line::from(vec![
+            Span::styled("Node:    ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                node.addr.clone(),
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
     let paragraph = Paragraph::new(lines);
     f.render_widget(paragraph, area);
 }
@@ -83,7 +107,7 @@ fn render_stats_column(f: &mut Frame, app: &App, area: Rect) {
                 .fg(Color::White)
                 .add_modifier(Modifier::BOLD),
         ),
-        if app.pagination.next_cursor != 0 {
+        if app.pagination.has_next_page() {
             Span::styled("+", Style::default().fg(Color::Yellow))
         } else {
             Span::raw("")
@@ -106,6 +130,60 @@ fn render_stats_column(f: &mut Frame, app: &App, area: Rect) {
 
 fn render_keybindings_col1(f: &mut Frame, app: &App, area: Rect) {
     let bindings = match app.active_resource.as_str() {
+        _ if app.mode == Mode::EditValue => {
+            vec![("<C-s>", "Save"), ("<Esc>", "Cancel"), ("", ""), ("", "")]
+        }
+        _ if app.mode == Mode::Describe && app.describe_is_collection() => vec![
+            ("<j/k>", "Navigate"),
+            ("<e>", "Edit"),
+            ("<a>", "Add"),
+            ("<C-d>", "Delete"),
+        ],
+        _ if app.mode == Mode::Describe && app.stream_details.is_some() => {
+            match app.stream_pane() {
+                Some(StreamPane::Groups) => vec![
+                    ("<a>", "Create Group"),
+                    ("<s>", "Set ID"),
+                    ("<C-d>", "Destroy"),
+                    ("<Tab>", "Pane"),
+                ],
+                Some(StreamPane::Consumers) => vec![
+                    ("<j/k>", "Navigate"),
+                    ("<C-d>", "Delete"),
+                    ("<R>", "Refresh"),
+                    ("<Tab>", "Pane"),
+                ],
+                Some(StreamPane::Pending) => vec![
+                    ("<c>", "Claim"),
+                    ("<C>", "Claim Idle"),
+                    ("<A>", "Ack"),
+                    ("<Tab>", "Pane"),
+                ],
+                _ => vec![
+                    ("<Tab>", "Pane"),
+                    ("<j/k>", "Navigate"),
+                    ("<R>", "Refresh"),
+                    ("</>", "Search"),
+                ],
+            }
+        }
+        _ if app.mode == Mode::Describe && app.describe_is_table() => vec![
+            ("<j/k>", "Navigate"),
+            ("<G>", "Bottom"),
+            ("</>", "Search"),
+            ("<Esc>", "Back"),
+        ],
+        _ if app.mode == Mode::Describe
+            && app.describe_key.is_some()
+            && matches!(app.describe_data, KeyValue::Bytes(_)) =>
+        {
+            vec![
+                ("<e>", "Edit"),
+                ("<E>", "Edit in $EDITOR"),
+                ("<v>", "Viewer"),
+                ("</>", "Search"),
+            ]
+        }
         "servers" => vec![
             ("<c>", "Connect"),
             ("<d>", "Describe"),
@@ -118,13 +196,36 @@ fn render_keybindings_col1(f: &mut Frame, app: &App, area: Rect) {
             ("<[>", "Prev Page"),
             ("</>", "Filter"),
         ],
+        "streams" if app.stream_active => {
+            vec![("<j/k>", "Scroll"), ("<Esc>", "Stop"), ("", ""), ("", "")]
+        }
         "streams" => vec![
             ("<d>", "Describe"),
-            ("<c>", "Consume"),
+            ("<t/T>", "Tail / From ID"),
+            ("<c>", "Consume (Group)"),
+            ("<Space>", "Mark"),
+        ],
+        "nodes" => vec![
+            ("<i>", "Node Info"),
+            ("<c>", "Node Clients"),
+            ("<s>", "Node Slowlog"),
             ("<R>", "Refresh"),
-            ("", ""),
         ],
-        "monitor" => vec![("<j/k>", "Scroll"), ("<R>", "Clear"), ("", ""), ("", "")],
+        "analyze" => vec![
+            ("<j/k>", "Scroll"),
+            ("<R>", "Rescan"),
+            ("<Esc>", "Cancel"),
+            ("<e>", "Export JSON"),
+        ],
+        "monitor" if app.monitor_replay.is_some() => {
+            vec![("<j/k>", "Scroll"), ("</>", "Filter"), ("", ""), ("", "")]
+        }
+        "monitor" => vec![
+            ("<j/k>", "Scroll"),
+            ("</>", "Filter"),
+            ("<p>", "Pause/Resume"),
+            ("<r>", "Record"),
+        ],
         "info" => vec![
             ("<j/k>", "Scroll"),
             ("</>", "Search"),
@@ -8,7 +8,7 @@ use ratatui::{
 };
 
 /// Highlight search matches within text, returning spans with highlighted portions
-fn highlight_matches(
+pub fn highlight_matches(
     text: &str,
     search: &str,
     base_color: Color,
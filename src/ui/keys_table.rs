@@ -1,4 +1,5 @@
-use crate::app::App;
+use crate::app::{App, KeySort};
+use crate::ui::format_bytes;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -55,6 +56,29 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         )
     };
 
+    let mut title = title;
+    if app.key_tree_view {
+        match &app.key_tree_scope {
+            Some(scope) => title.push_str(&format!(
+                "[Tree: {}{}*] ",
+                scope,
+                app.tredis_config.key_separator()
+            )),
+            None => title.push_str("[Tree] "),
+        }
+    }
+    if let Some(load) = &app.key_load {
+        let (loaded, total) = load.progress.metadata();
+        title.push_str(&format!(
+            "- scanning: {}/{} keys, {} SCAN calls, metadata {}/{} (Esc to cancel) ",
+            load.progress.matched(),
+            app.pagination.page_size,
+            load.progress.scans(),
+            loaded,
+            total
+        ));
+    }
+
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::DarkGray))
@@ -65,17 +89,36 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center);
+    let block = match &app.key_message {
+        Some(message) => block.title_bottom(Span::styled(
+            format!(" {} ", message),
+            Style::default().fg(Color::Yellow),
+        )),
+        None => block,
+    };
 
     let inner_area = block.inner(table_area);
     f.render_widget(block, table_area);
 
-    let header_cells = ["Key", "Type", "TTL", "Memory"].iter().map(|h| {
-        Cell::from(*h).style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )
-    });
+    if app.key_tree_view {
+        render_tree(f, app, inner_area);
+        return;
+    }
+
+    let memory_header = match app.key_sort {
+        KeySort::Scan => "Memory",
+        KeySort::MemoryDesc => "Memory ↓",
+        KeySort::MemoryAsc => "Memory ↑",
+    };
+    let header_cells = ["Key", "Type", "TTL", memory_header, "Encoding"]
+        .into_iter()
+        .map(|h| {
+            Cell::from(h).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        });
     let header = Row::new(header_cells).height(1);
 
     let rows = app.scan_result.iter().map(|item| {
@@ -97,17 +140,19 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             } else {
                 get_type_style(&item.key_type)
             }),
-            Cell::from(item.ttl.to_string()),
-            Cell::from(item.memory_usage.to_string()),
+            Cell::from(format_ttl(item.pttl)),
+            Cell::from(format_bytes(item.memory_usage)),
+            Cell::from(item.encoding.clone()),
         ];
         Row::new(cells).style(row_style)
     });
 
     let widths = [
-        Constraint::Percentage(50), // Key
-        Constraint::Percentage(15), // Type
+        Constraint::Percentage(45), // Key
+        Constraint::Percentage(10), // Type
         Constraint::Percentage(15), // TTL
-        Constraint::Percentage(20), // Memory
+        Constraint::Percentage(15), // Memory
+        Constraint::Percentage(15), // Encoding
     ];
 
     let table = Table::new(rows, widths).header(header).row_highlight_style(
@@ -123,6 +168,107 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     f.render_stateful_widget(table, inner_area, &mut state);
 }
 
+/// Keys of the current page grouped by namespace
+fn render_tree(f: &mut Frame, app: &App, area: Rect) {
+    let rows = app.namespace_rows();
+    if rows.is_empty() {
+        let empty_msg = Paragraph::new("No keys on this page.")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(empty_msg, area);
+        return;
+    }
+
+    // Groups only count the keys loaded on this page, not the whole prefix
+    let memory_header = match app.key_sort {
+        KeySort::Scan => "Memory (page)",
+        _ => "Memory (page) ↓",
+    };
+    let header_cells = ["Namespace", "Keys (page)", memory_header, "Type", "TTL"]
+        .into_iter()
+        .map(|h| {
+            Cell::from(h).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        });
+    let header = Row::new(header_cells).height(1);
+
+    let table_rows = rows.iter().map(|row| {
+        let indent = "  ".repeat(row.depth);
+        match row.key_index.and_then(|i| app.scan_result.get(i)) {
+            Some(item) => {
+                let is_selected = app.selected_keys.contains(&item.key);
+                let row_style = if is_selected {
+                    Style::default()
+                        .bg(Color::Green)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Row::new(vec![
+                    Cell::from(format!("{}  {}", indent, row.name)),
+                    Cell::from(""),
+                    Cell::from(format_bytes(row.memory)),
+                    Cell::from(item.key_type.clone()).style(if is_selected {
+                        Style::default().fg(Color::Black)
+                    } else {
+                        get_type_style(&item.key_type)
+                    }),
+                    Cell::from(format_ttl(item.pttl)),
+                ])
+                .style(row_style)
+            }
+            None => {
+                let marker = if row.expanded { "▾" } else { "▸" };
+                Row::new(vec![
+                    Cell::from(format!("{}{} {}", indent, marker, row.name)).style(
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Cell::from(row.key_count.to_string()),
+                    Cell::from(format_bytes(row.memory)),
+                    Cell::from(""),
+                    Cell::from(""),
+                ])
+            }
+        }
+    });
+
+    let widths = [
+        Constraint::Percentage(50), // Namespace
+        Constraint::Percentage(10), // Keys
+        Constraint::Percentage(15), // Memory
+        Constraint::Percentage(10), // Type
+        Constraint::Percentage(15), // TTL
+    ];
+
+    let table = Table::new(table_rows, widths)
+        .header(header)
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut state = TableState::default();
+    state.select(Some(app.selected_tree_index.min(rows.len() - 1)));
+
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+/// Remaining time to live, e.g. `1h 5m` or `-` for keys without expiry
+fn format_ttl(pttl: i64) -> String {
+    if pttl < 0 {
+        return "-".to_string();
+    }
+    super::format_millis(pttl)
+}
+
 fn get_type_style(key_type: &str) -> Style {
     match key_type {
         "string" => Style::default().fg(Color::Cyan),
@@ -1,20 +1,27 @@
 pub mod acls_table;
+pub mod analyze_view;
 pub mod channels_table;
 pub mod clients_table;
 pub mod configs_table;
 pub mod describe;
 pub mod dialog;
+pub mod field_dialog;
 pub mod header;
 pub mod info_view;
 pub mod keys_table;
 pub mod monitor_table;
+pub mod new_key_dialog;
+pub mod nodes_table;
 pub mod pubsub_table;
 pub mod resources;
+pub mod sentinel_table;
 pub mod server_dialog;
 pub mod servers_table;
 pub mod slowlog_table;
 pub mod splash;
+pub mod stream_inspector;
 pub mod streams_table;
+pub mod value_editor;
 
 use crate::app::{App, Mode};
 use ratatui::{
@@ -49,6 +56,21 @@ pub fn render(f: &mut Frame, app: &App) {
         Mode::Describe => {
             describe::render(f, app, chunks[1]);
         }
+        Mode::EditValue => {
+            value_editor::render(f, app, chunks[1]);
+        }
+        // Describe view stays visible behind its edit dialogs
+        Mode::FieldDialog => {
+            describe::render(f, app, chunks[1]);
+        }
+        Mode::Confirm
+            if app
+                .pending_action
+                .as_ref()
+                .is_some_and(|p| p.action_type.is_field_edit()) =>
+        {
+            describe::render(f, app, chunks[1]);
+        }
         _ => match app.active_resource.as_str() {
             "servers" => servers_table::render(f, app, chunks[1]),
             "clients" => clients_table::render(f, app, chunks[1]),
@@ -57,6 +79,9 @@ pub fn render(f: &mut Frame, app: &App) {
             "config" => configs_table::render(f, app, chunks[1]),
             "acl" => acls_table::render(f, app, chunks[1]),
             "monitor" => monitor_table::render(f, app, chunks[1]),
+            "nodes" => nodes_table::render(f, app, chunks[1]),
+            "sentinel" => sentinel_table::render(f, app, chunks[1]),
+            "analyze" => analyze_view::render(f, app, chunks[1]),
             "streams" => streams_table::render(f, app, chunks[1]),
             "channels" => channels_table::render(f, app, chunks[1]),
             "pubsub" => pubsub_table::render(f, app, chunks[1]),
@@ -72,4 +97,68 @@ pub fn render(f: &mut Frame, app: &App) {
     if app.mode == Mode::Resources {
         resources::render(f, app);
     }
+
+    if app.mode == Mode::NewKeyDialog {
+        new_key_dialog::render(f, &app.new_key_dialog_state);
+    }
+
+    if app.mode == Mode::FieldDialog {
+        if let Some(dialog) = &app.field_dialog {
+            field_dialog::render(f, dialog);
+        }
+    }
+}
+
+/// Human readable byte size, e.g. `1.5 KB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Milliseconds as a short duration, e.g. `950ms`, `42s`, `3m 20s` or `1h 5m`
+pub fn format_millis(ms: i64) -> String {
+    if ms < 1000 {
+        return format!("{}ms", ms);
+    }
+
+    let secs = ms / 1000;
+    let (days, hours, minutes, seconds) = (
+        secs / 86400,
+        secs % 86400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    );
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes_picks_the_largest_unit() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(format_bytes(u64::MAX), "16777216.0 TB");
+    }
 }
@@ -1,14 +1,85 @@
 use crate::app::App;
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
 };
 
+/// Entries listed per section of the stats panel
+const TOP_N: usize = 10;
+
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
-    let title = format!(" Monitor ({} commands) ", app.monitor_entries.len());
+    let show_filter = app.monitor_filter_active || !app.monitor_filter_text.is_empty();
+
+    let (filter_area, area) = if show_filter {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(area);
+        (Some(chunks[0]), chunks[1])
+    } else {
+        (None, area)
+    };
+
+    if let Some(area) = filter_area {
+        let filter_display = if app.monitor_filter_active {
+            format!("/{}_", app.monitor_filter_text)
+        } else {
+            format!("/{}", app.monitor_filter_text)
+        };
+
+        let style = if app.monitor_filter_active {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let paragraph = Paragraph::new(Line::from(Span::styled(filter_display, style)));
+        f.render_widget(paragraph, area);
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(60), Constraint::Length(40)])
+        .split(area);
+
+    render_entries(f, app, chunks[0]);
+    render_stats(f, app, chunks[1]);
+}
+
+fn render_entries(f: &mut Frame, app: &App, area: Rect) {
+    let entries = app.visible_monitor_entries();
+
+    let name = match &app.monitor_replay {
+        Some(path) => format!(
+            "Replay {}",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        ),
+        None => "Monitor".to_string(),
+    };
+    let mut title = if app.monitor_filter.is_empty() {
+        format!(" {} ({} commands) ", name, entries.len())
+    } else {
+        format!(
+            " {} ({}/{} commands) ",
+            name,
+            entries.len(),
+            app.monitor_entries.len()
+        )
+    };
+    if let Some(recorder) = &app.monitor_recorder {
+        title.push_str(&format!(
+            "[REC {} → {}] ",
+            recorder.count,
+            recorder.path.display()
+        ));
+    }
+    if app.monitor_paused {
+        title.push_str(&format!("[PAUSED, {} buffered] ", app.monitor_buffer.len()));
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -16,7 +87,13 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         .title(Span::styled(
             title,
             Style::default()
-                .fg(Color::Cyan)
+                .fg(if app.monitor_recorder.is_some() {
+                    Color::Red
+                } else if app.monitor_paused {
+                    Color::Yellow
+                } else {
+                    Color::Cyan
+                })
                 .add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center);
@@ -24,11 +101,13 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    if app.monitor_entries.is_empty() {
-        let msg = if app.monitor_active {
-            "Monitor is active but no commands captured yet.\n\nRun Redis commands in another terminal to see them here.\n\nExample: redis-cli SET mykey myvalue"
-        } else {
+    if entries.is_empty() {
+        let msg = if !app.monitor_active {
             "Monitor not started. Switch to this view to begin monitoring."
+        } else if !app.monitor_entries.is_empty() {
+            "No captured commands match the filter."
+        } else {
+            "Monitor is active but no commands captured yet.\n\nRun Redis commands in another terminal to see them here.\n\nExample: redis-cli SET mykey myvalue"
         };
         let empty_msg = Paragraph::new(msg)
             .style(Style::default().fg(Color::Yellow))
@@ -37,12 +116,33 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
+    // Column header
+    let header_style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let header = Line::from(vec![
+        Span::styled(format!("{:<22}", "Time"), header_style),
+        Span::styled(format!("{:<5}", "DB"), header_style),
+        Span::styled(format!("{:<23}", "Client"), header_style),
+        Span::styled(format!("{:<13}", "Command"), header_style),
+        Span::styled("Arguments", header_style),
+    ]);
+    f.render_widget(
+        Paragraph::new(header),
+        Rect::new(inner_area.x, inner_area.y, inner_area.width, 1),
+    );
+    let inner_area = Rect::new(
+        inner_area.x,
+        inner_area.y + 1,
+        inner_area.width,
+        inner_area.height.saturating_sub(1),
+    );
+
     let visible_height = inner_area.height as usize;
-    let total_entries = app.monitor_entries.len();
+    let total_entries = entries.len();
     let scroll_offset = app.monitor_scroll;
 
-    let visible_entries = app
-        .monitor_entries
+    let visible_entries = entries
         .iter()
         .skip(scroll_offset)
         .take(visible_height)
@@ -62,18 +162,24 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
 
         let line = Line::from(vec![
             Span::styled(
-                format!("[{}] ", entry.timestamp),
+                format!("{:<22}", entry.timestamp),
                 Style::default().fg(Color::Yellow),
             ),
             Span::styled(
-                format!("DB:{} ", entry.db),
+                format!("{:<5}", entry.db),
                 Style::default().fg(Color::Green),
             ),
             Span::styled(
-                format!("{} ", entry.client),
+                format!("{:<23}", entry.client),
                 Style::default().fg(Color::Magenta),
             ),
-            Span::styled(&entry.command, Style::default().fg(Color::Cyan)),
+            Span::styled(
+                format!("{:<13}", entry.command_name()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(entry.args_display(), Style::default().fg(Color::White)),
         ])
         .style(style);
 
@@ -97,3 +203,76 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         f.render_stateful_widget(scrollbar, inner_area, &mut scrollbar_state);
     }
 }
+
+/// Side panel with ops/sec and the most frequent commands and keys
+fn render_stats(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            " Stats ",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let label_style = Style::default().fg(Color::Yellow);
+    let section_style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let name_width = (inner_area.width as usize).saturating_sub(10).max(8);
+
+    let stats = &app.monitor_stats;
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Ops/sec: ", label_style),
+            Span::styled(
+                format!("{:.1}", app.monitor_ops_per_sec()),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Total:   ", label_style),
+            Span::raw(stats.total.to_string()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Top commands", section_style)),
+    ];
+
+    for (command, count) in stats.top_commands(TOP_N) {
+        lines.push(count_line(command, count, name_width, Color::Cyan));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Top keys", section_style)));
+    for (key, count) in stats.top_keys(TOP_N) {
+        lines.push(count_line(key, count, name_width, Color::White));
+    }
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
+
+fn count_line(name: &str, count: u64, width: usize, color: Color) -> Line<'static> {
+    let name = if name.chars().count() > width {
+        let truncated: String = name.chars().take(width.saturating_sub(1)).collect();
+        format!("{}…", truncated)
+    } else {
+        name.to_string()
+    };
+    Line::from(vec![
+        Span::styled(
+            format!("{:<width$}", name, width = width),
+            Style::default().fg(color),
+        ),
+        Span::styled(
+            format!("{:>9}", count),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
@@ -0,0 +1,153 @@
+use super::field_dialog::{self, FieldDialogState};
+use crate::app::PendingActionType;
+use crate::keys::TtlChange;
+use anyhow::{bail, Result};
+use ratatui::Frame;
+
+/// Types a new key can be created with
+pub const KEY_TYPES: [&str; 6] = ["string", "list", "set", "hash", "zset", "stream"];
+
+const NAME: &str = "Name";
+const TYPE: &str = "Type (←/→)";
+const TTL: &str = "TTL (90, 15m, 2h, @date; empty for none)";
+const CONTENTS: &str = "Contents";
+
+/// State for the new key dialog: a field dialog with a key type selector
+#[derive(Debug, Clone)]
+pub struct NewKeyDialogState {
+    pub form: FieldDialogState,
+}
+
+impl Default for NewKeyDialogState {
+    fn default() -> Self {
+        let form = FieldDialogState::new("New Key", PendingActionType::CreateKey, String::new())
+            .with_field(NAME, String::new())
+            .with_choice(TYPE, &KEY_TYPES)
+            .with_field(TTL, String::new())
+            .with_field(CONTENTS, String::new())
+            .with_hint(contents_hint(KEY_TYPES[0]))
+            .with_submit_label("create");
+        Self { form }
+    }
+}
+
+impl NewKeyDialogState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(&self) -> &str {
+        self.form.value(NAME)
+    }
+
+    pub fn key_type(&self) -> &str {
+        self.form.value(TYPE)
+    }
+
+    pub fn contents(&self) -> &str {
+        self.form.value(CONTENTS)
+    }
+
+    /// Cycle the key type when the type field is active
+    pub fn cycle_type(&mut self, forward: bool) {
+        self.form.cycle_choice(forward);
+        self.form.hint = Some(contents_hint(self.key_type()).to_string());
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        if self.form.choice_active() {
+            if c == ' ' {
+                self.cycle_type(true);
+            }
+        } else {
+            self.form.push_char(c);
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.name().is_empty()
+    }
+
+    /// TTL in the format of the TTL dialog, `None` when left empty or `persist`.
+    /// A TTL that would delete the key right away is rejected.
+    pub fn ttl(&self) -> Result<Option<TtlChange>> {
+        let ttl = self.form.value(TTL).trim();
+        if ttl.is_empty() {
+            return Ok(None);
+        }
+        match TtlChange::parse(ttl)? {
+            TtlChange::Persist => Ok(None),
+            change if change.expires_now() => bail!("TTL {} would expire the key right away", ttl),
+            change => Ok(Some(change)),
+        }
+    }
+}
+
+/// Expected format of the contents field for a key type
+fn contents_hint(key_type: &str) -> &'static str {
+    match key_type {
+        "string" => "The value, taken as is",
+        "list" => "Elements: a b \"c d\"",
+        "set" => "Members: a b \"c d\"",
+        "hash" => "Field/value pairs: name alice age 30",
+        "zset" => "Score/member pairs: 1 alice 2.5 bob",
+        _ => "Field/value pairs of the first entry: event login",
+    }
+}
+
+pub fn render(f: &mut Frame, state: &NewKeyDialogState) {
+    field_dialog::render(f, &state.form);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(state: &mut NewKeyDialogState, label: &str, value: &str) {
+        let field = state.form.fields.iter_mut().find(|f| f.label == label);
+        field.unwrap().value = value.to_string();
+    }
+
+    fn with_ttl(ttl: &str) -> NewKeyDialogState {
+        let mut state = NewKeyDialogState::new();
+        set(&mut state, TTL, ttl);
+        state
+    }
+
+    #[test]
+    fn ttl_uses_the_ttl_dialog_format() {
+        assert_eq!(with_ttl("").ttl().unwrap(), None);
+        assert_eq!(with_ttl("persist").ttl().unwrap(), None);
+        assert_eq!(with_ttl("90").ttl().unwrap(), Some(TtlChange::Expire(90)));
+        assert_eq!(with_ttl("10m").ttl().unwrap(), Some(TtlChange::Expire(600)));
+        assert_eq!(with_ttl("1h").ttl().unwrap(), Some(TtlChange::Expire(3600)));
+        assert_eq!(
+            with_ttl("10x").ttl().unwrap_err().to_string(),
+            "Invalid TTL unit: x"
+        );
+        assert!(with_ttl("0").ttl().is_err());
+    }
+
+    #[test]
+    fn cycling_the_type_updates_the_contents_hint() {
+        let mut state = NewKeyDialogState::new();
+        assert_eq!(state.key_type(), "string");
+        state.form.toggle_field();
+        assert!(state.form.choice_active());
+        state.push_char(' ');
+        assert_eq!(state.key_type(), "list");
+        assert_eq!(state.form.hint.as_deref(), Some("Elements: a b \"c d\""));
+        state.cycle_type(false);
+        state.cycle_type(false);
+        assert_eq!(state.key_type(), "stream");
+    }
+
+    #[test]
+    fn name_is_required() {
+        let mut state = NewKeyDialogState::new();
+        assert!(!state.is_valid());
+        set(&mut state, NAME, "k");
+        assert!(state.is_valid());
+        assert_eq!(state.form.action_type, PendingActionType::CreateKey);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let primaries = app.cluster_nodes.iter().filter(|n| n.is_primary()).count();
+    let title = format!(
+        " Cluster Nodes ({} primaries, {} replicas) ",
+        primaries,
+        app.cluster_nodes.len() - primaries
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.cluster_nodes.is_empty() {
+        let empty_msg = Paragraph::new(
+            "No cluster nodes found. This view is only available for Redis Cluster servers.",
+        )
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+        f.render_widget(empty_msg, inner_area);
+        return;
+    }
+
+    let header_cells = ["ID", "Address", "Role", "Follows", "Slots", "Link", "Flags"]
+        .iter()
+        .map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        });
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.cluster_nodes.iter().map(|node| {
+        let role_style = if node.is_primary() {
+            Style::default().fg(Color::Magenta)
+        } else {
+            Style::default().fg(Color::Blue)
+        };
+        let link_style = if node.link_state == "connected" {
+            Style::default().fg(Color::Green)
+        } else {
+            Style::default().fg(Color::Red)
+        };
+        let follows = if node.is_primary() {
+            "-".to_string()
+        } else {
+            app.cluster_node_primary_addr(node)
+                .unwrap_or(node.master_id.as_str())
+                .to_string()
+        };
+
+        let cells = vec![
+            Cell::from(node.id.chars().take(8).collect::<String>()),
+            Cell::from(node.addr.clone()).style(Style::default().fg(Color::Cyan)),
+            Cell::from(node.role()).style(role_style),
+            Cell::from(follows),
+            Cell::from(node.slot_ranges()),
+            Cell::from(node.link_state.clone()).style(link_style),
+            Cell::from(node.flags.clone()).style(Style::default().fg(Color::DarkGray)),
+        ];
+        Row::new(cells)
+    });
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Percentage(20),
+        Constraint::Length(8),
+        Constraint::Percentage(20),
+        Constraint::Percentage(25),
+        Constraint::Length(13),
+        Constraint::Min(10),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.selected_cluster_node_index));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
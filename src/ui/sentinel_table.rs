@@ -0,0 +1,96 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let masters = app
+        .sentinel_entries
+        .iter()
+        .filter(|e| e.kind == "master")
+        .count();
+    let title = format!(" Sentinel ({} masters) ", masters);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.sentinel_entries.is_empty() {
+        let empty_msg = Paragraph::new(
+            "No sentinel data. Connect to a sentinel or configure a sentinel group for this server.",
+        )
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+        f.render_widget(empty_msg, inner_area);
+        return;
+    }
+
+    let header_cells = ["Type", "Master", "Name", "Address", "Flags", "Status"]
+        .iter()
+        .map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        });
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.sentinel_entries.iter().map(|entry| {
+        let kind_style = match entry.kind.as_str() {
+            "master" => Style::default().fg(Color::Magenta),
+            "replica" => Style::default().fg(Color::Blue),
+            _ => Style::default().fg(Color::Yellow),
+        };
+        let flags_style = if entry.flags.contains("down") {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let cells = vec![
+            Cell::from(entry.kind.clone()).style(kind_style),
+            Cell::from(entry.master.clone()),
+            Cell::from(entry.name.clone()),
+            Cell::from(entry.addr.clone()).style(Style::default().fg(Color::Cyan)),
+            Cell::from(entry.flags.clone()).style(flags_style),
+            Cell::from(entry.status.clone()),
+        ];
+        Row::new(cells)
+    });
+
+    let widths = [
+        Constraint::Length(9),
+        Constraint::Percentage(15),
+        Constraint::Percentage(15),
+        Constraint::Percentage(20),
+        Constraint::Percentage(15),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).row_highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = TableState::default();
+    state.select(Some(app.selected_sentinel_index));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+}
@@ -0,0 +1,292 @@
+use super::info_view::highlight_matches;
+use crate::app::App;
+use crate::streams::{self, StreamDetails, StreamPane};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+    Frame,
+};
+
+/// Stream describe view: XINFO summary, entries, consumer groups and the
+/// consumers and pending entries of the selected group
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Min(5),    // Entries
+            Constraint::Length(9), // Groups, consumers and pending entries
+        ])
+        .split(area);
+
+    let details = app.stream_details.as_ref();
+    render_summary(f, details, chunks[0]);
+
+    let pane = details.map(|d| d.pane).unwrap_or_default();
+    render_entries(f, app, pane == StreamPane::Entries, chunks[1]);
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(42),
+            Constraint::Percentage(25),
+            Constraint::Percentage(33),
+        ])
+        .split(chunks[2]);
+    match details {
+        Some(details) => {
+            render_groups(f, details, bottom[0]);
+            render_consumers(f, details, bottom[1]);
+            render_pending(f, details, bottom[2]);
+        }
+        None => {
+            f.render_widget(pane_block(" Groups ", false), bottom[0]);
+            f.render_widget(pane_block(" Consumers ", false), bottom[1]);
+            f.render_widget(pane_block(" Pending ", false), bottom[2]);
+        }
+    }
+}
+
+fn render_summary(f: &mut Frame, details: Option<&StreamDetails>, area: Rect) {
+    let line = match details {
+        Some(StreamDetails {
+            error: Some(error), ..
+        }) => Line::from(Span::styled(
+            format!("XINFO failed: {}", error),
+            Style::default().fg(Color::Red),
+        )),
+        Some(details) => {
+            let mut spans = Vec::new();
+            for (name, value) in &details.summary {
+                spans.push(Span::styled(
+                    format!("{}: ", name),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                spans.push(Span::styled(
+                    format!("{}   ", value),
+                    Style::default().fg(Color::White),
+                ));
+            }
+            Line::from(spans)
+        }
+        None => Line::from(""),
+    };
+    f.render_widget(Paragraph::new(line).wrap(Wrap { trim: true }), area);
+}
+
+fn pane_block(title: &str, focused: bool) -> Block<'_> {
+    let color = if focused {
+        Color::Cyan
+    } else {
+        Color::DarkGray
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(color))
+        .title(Span::styled(
+            title,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ))
+}
+
+fn header_row(headers: &[&'static str]) -> Row<'static> {
+    Row::new(headers.iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    }))
+    .height(1)
+}
+
+fn highlight_style(focused: bool) -> Style {
+    if focused {
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().add_modifier(Modifier::BOLD)
+    }
+}
+
+fn render_entries(f: &mut Frame, app: &App, focused: bool, area: Rect) {
+    let search = app.describe_search_text.as_str();
+    let current_match = app
+        .describe_search_matches
+        .get(app.describe_search_current)
+        .copied();
+
+    let rows_data = app.collection_rows();
+    let rows = rows_data.iter().enumerate().map(|(idx, (id, fields))| {
+        let is_current = current_match == Some(idx);
+        Row::new(vec![
+            Cell::from(Line::from(highlight_matches(
+                id,
+                search,
+                Color::Cyan,
+                is_current,
+            ))),
+            Cell::from(streams::id_timestamp(id)).style(Style::default().fg(Color::DarkGray)),
+            Cell::from(Line::from(highlight_matches(
+                fields,
+                search,
+                Color::White,
+                is_current,
+            ))),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(22),
+        Constraint::Length(23),
+        Constraint::Min(10),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header_row(&["ID", "Time", "Fields"]))
+        .block(pane_block(" Entries ", focused))
+        .row_highlight_style(highlight_style(focused))
+        .highlight_symbol("> ");
+
+    let mut state = TableState::default();
+    if !rows_data.is_empty() {
+        state.select(Some(app.describe_selected));
+    }
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn render_groups(f: &mut Frame, details: &StreamDetails, area: Rect) {
+    let focused = details.pane == StreamPane::Groups;
+    let optional = |value: Option<i64>| value.map(|v| v.to_string()).unwrap_or("-".into());
+
+    let rows = details.groups.iter().map(|group| {
+        Row::new(vec![
+            Cell::from(group.name.clone()).style(Style::default().fg(Color::Cyan)),
+            Cell::from(group.consumers.to_string()),
+            Cell::from(group.pending.to_string()).style(if group.pending > 0 {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            }),
+            Cell::from(group.last_delivered_id.clone()),
+            Cell::from(optional(group.lag)),
+            Cell::from(optional(group.entries_read)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Min(10),
+        Constraint::Length(9),
+        Constraint::Length(8),
+        Constraint::Length(22),
+        Constraint::Length(6),
+        Constraint::Length(8),
+    ];
+    let title = format!(" Groups ({}) ", details.groups.len());
+    let table = Table::new(rows, widths)
+        .header(header_row(&[
+            "Group",
+            "Consumers",
+            "Pending",
+            "Last Delivered",
+            "Lag",
+            "Read",
+        ]))
+        .block(pane_block(&title, focused))
+        .row_highlight_style(highlight_style(focused))
+        .highlight_symbol("> ");
+
+    let mut state = TableState::default();
+    if !details.groups.is_empty() {
+        state.select(Some(details.selected_group));
+    }
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn render_consumers(f: &mut Frame, details: &StreamDetails, area: Rect) {
+    let focused = details.pane == StreamPane::Consumers;
+
+    let rows = details.consumers.iter().map(|consumer| {
+        Row::new(vec![
+            Cell::from(consumer.name.clone()).style(Style::default().fg(Color::Cyan)),
+            Cell::from(consumer.pending.to_string()),
+            Cell::from(super::format_millis(consumer.idle_ms)),
+            Cell::from(
+                consumer
+                    .inactive_ms
+                    .filter(|ms| *ms >= 0)
+                    .map(super::format_millis)
+                    .unwrap_or("-".into()),
+            ),
+        ])
+    });
+
+    let widths = [
+        Constraint::Min(10),
+        Constraint::Length(8),
+        Constraint::Length(9),
+        Constraint::Length(9),
+    ];
+    let title = match details.selected_group() {
+        Some(group) => format!(" Consumers of {} ", group.name),
+        None => " Consumers ".to_string(),
+    };
+    let table = Table::new(rows, widths)
+        .header(header_row(&["Consumer", "Pending", "Idle", "Inactive"]))
+        .block(pane_block(&title, focused))
+        .row_highlight_style(highlight_style(focused))
+        .highlight_symbol("> ");
+
+    let mut state = TableState::default();
+    if !details.consumers.is_empty() {
+        state.select(Some(details.selected_consumer));
+    }
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn render_pending(f: &mut Frame, details: &StreamDetails, area: Rect) {
+    let focused = details.pane == StreamPane::Pending;
+
+    let rows = details.pending.iter().map(|entry| {
+        Row::new(vec![
+            Cell::from(entry.id.clone()).style(Style::default().fg(Color::Cyan)),
+            Cell::from(entry.consumer.clone()),
+            Cell::from(super::format_millis(entry.idle_ms)),
+            Cell::from(entry.deliveries.to_string()).style(if entry.deliveries > 1 {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            }),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(22),
+        Constraint::Min(8),
+        Constraint::Length(9),
+        Constraint::Length(10),
+    ];
+    let title = match details.selected_group() {
+        Some(group) if group.pending as usize > details.pending.len() => format!(
+            " Pending of {} (first {} of {}) ",
+            group.name,
+            details.pending.len(),
+            group.pending
+        ),
+        Some(group) => format!(" Pending of {} ", group.name),
+        None => " Pending ".to_string(),
+    };
+    let table = Table::new(rows, widths)
+        .header(header_row(&["ID", "Consumer", "Idle", "Delivered"]))
+        .block(pane_block(&title, focused))
+        .row_highlight_style(highlight_style(focused))
+        .highlight_symbol("> ");
+
+    let mut state = TableState::default();
+    if !details.pending.is_empty() {
+        state.select(Some(details.selected_pending));
+    }
+    f.render_stateful_widget(table, area, &mut state);
+}
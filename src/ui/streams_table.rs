@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::streams::StreamReadMode;
 use ratatui::{
     layout::{Alignment, Constraint, Rect},
     style::{Color, Modifier, Style},
@@ -17,7 +18,15 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         return;
     }
 
-    let title = format!(" Redis Streams ({}) ", app.streams.len());
+    let title = if app.selected_streams.is_empty() {
+        format!(" Redis Streams ({}) ", app.streams.len())
+    } else {
+        format!(
+            " Redis Streams ({}) - {} marked ",
+            app.streams.len(),
+            app.selected_streams.len()
+        )
+    };
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -55,6 +64,20 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let header = Row::new(header_cells).height(1);
 
     let rows = app.streams.iter().map(|item| {
+        if app.selected_streams.contains(&item.name) {
+            let cells = vec![
+                Cell::from(item.name.clone()),
+                Cell::from(item.length.to_string()),
+                Cell::from(item.first_entry_id.clone()),
+                Cell::from(item.last_entry_id.clone()),
+            ];
+            return Row::new(cells).style(
+                Style::default()
+                    .bg(Color::Green)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            );
+        }
         let cells = vec![
             Cell::from(item.name.clone()).style(Style::default().fg(Color::Cyan)),
             Cell::from(item.length.to_string()).style(Style::default().fg(Color::Green)),
@@ -85,57 +108,65 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_stream_messages(f: &mut Frame, app: &App, area: Rect) {
-    // Only log when there are messages (not every render)
-    if !app.stream_messages.is_empty() {
-        crate::log!(
-            crate::LogLevel::Info,
-            "[UI] Rendering {} stream messages - First: {} {:?}",
-            app.stream_messages.len(),
-            app.stream_messages[0].id,
-            app.stream_messages[0].fields
-        );
-    }
-
-    let stream_name = if !app.streams.is_empty() {
-        &app.streams[app.selected_stream_index].name
-    } else {
-        "Unknown"
+    let streams = app.stream_read_streams.join(", ");
+    let (title, color) = match &app.stream_read_mode {
+        Some(StreamReadMode::Group { group, consumer }) => (
+            format!(
+                " Consuming: {} (Group: {}, Consumer: {}, acking) - {} messages ",
+                streams,
+                group,
+                consumer,
+                app.stream_messages.len()
+            ),
+            Color::Yellow,
+        ),
+        Some(StreamReadMode::Tail { start_id }) => (
+            format!(
+                " Tailing: {} (read-only, after {}) - {} messages ",
+                streams,
+                start_id,
+                app.stream_messages.len()
+            ),
+            Color::Green,
+        ),
+        None => (String::new(), Color::Green),
     };
 
-    let title = format!(
-        " Consuming: {} (Group: {}) - {} messages ",
-        stream_name,
-        app.stream_consumer_group,
-        app.stream_messages.len()
-    );
-
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Green))
+        .border_style(Style::default().fg(color))
         .title(Span::styled(
             title,
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center);
+    let block = match &app.stream_read_error {
+        Some(error) => block.title_bottom(Span::styled(
+            format!(" Stopped: {} ", error),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        None => block,
+    };
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
     if app.stream_messages.is_empty() {
-        let hostname = hostname::get()
-            .ok()
-            .and_then(|h| h.into_string().ok())
-            .unwrap_or_else(|| "unknown".to_string());
-
-        let msg = format!(
-            "Waiting for messages on stream: {}\n\n\
-            Consumer Group: {}\n\
-            Consumer Name: tredis_{}\n\n\
-            Press Esc to stop consuming",
-            stream_name, app.stream_consumer_group, hostname
-        );
+        let msg = match &app.stream_read_mode {
+            Some(StreamReadMode::Group { group, consumer }) => format!(
+                "Waiting for messages on: {}\n\n\
+                Consumer Group: {}\n\
+                Consumer Name: {}\n\n\
+                Press Esc to stop consuming",
+                streams, group, consumer
+            ),
+            _ => format!(
+                "Waiting for messages on: {}\n\n\
+                Nothing is acknowledged or changed on the server\n\n\
+                Press Esc to stop tailing",
+                streams
+            ),
+        };
         let empty_msg = Paragraph::new(msg)
             .style(Style::default().fg(Color::Yellow))
             .alignment(Alignment::Center);
@@ -154,19 +185,31 @@ fn render_stream_messages(f: &mut Frame, app: &App, area: Rect) {
         .take(visible_height);
 
     let mut lines = Vec::new();
-    for msg in visible_messages {
-        let fields_str: Vec<String> = msg
+    for (stream, msg) in visible_messages {
+        let mut fields_str: Vec<String> = msg
             .fields
             .iter()
             .map(|(k, v)| format!("{}={}", k, v))
             .collect();
-
-        let line = Line::from(vec![
-            Span::styled(format!("[{}] ", msg.id), Style::default().fg(Color::Yellow)),
-            Span::styled(fields_str.join(", "), Style::default().fg(Color::White)),
-        ]);
-
-        lines.push(line);
+        fields_str.sort();
+
+        let mut spans = Vec::new();
+        if app.stream_read_streams.len() > 1 {
+            spans.push(Span::styled(
+                format!("{} ", stream),
+                Style::default().fg(Color::Cyan),
+            ));
+        }
+        spans.push(Span::styled(
+            format!("[{}] ", msg.id),
+            Style::default().fg(Color::Yellow),
+        ));
+        spans.push(Span::styled(
+            fields_str.join(", "),
+            Style::default().fg(Color::White),
+        ));
+
+        lines.push(Line::from(spans));
     }
 
     let paragraph = Paragraph::new(lines);
@@ -0,0 +1,65 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(value_editor) = &app.value_editor else {
+        return;
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(Span::styled(
+            format!(" Edit: {} ", value_editor.key),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    // Scrolling is computed here since the viewport height is only known at render time
+    let mut editor = value_editor.editor.clone();
+    editor.scroll_to_cursor(chunks[0].height as usize);
+
+    let lines: Vec<Line> = editor
+        .lines
+        .iter()
+        .map(|l| Line::from(Span::styled(l.as_str(), Style::default().fg(Color::White))))
+        .collect();
+    let paragraph = Paragraph::new(lines).scroll((editor.scroll as u16, 0));
+    f.render_widget(paragraph, chunks[0]);
+
+    let cursor_x = chunks[0].x + editor.col.min(u16::MAX as usize) as u16;
+    let cursor_y = chunks[0].y + (editor.row - editor.scroll) as u16;
+    if cursor_x < chunks[0].right() && cursor_y < chunks[0].bottom() {
+        f.set_cursor_position(Position::new(cursor_x, cursor_y));
+    }
+
+    let status = match &value_editor.message {
+        Some(message) => Line::from(Span::styled(
+            message.as_str(),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        None => Line::from(vec![
+            Span::styled("<C-s>", Style::default().fg(Color::Yellow)),
+            Span::styled(" Save (keeps TTL)  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("<Esc>", Style::default().fg(Color::Yellow)),
+            Span::styled(" Cancel", Style::default().fg(Color::DarkGray)),
+        ]),
+    };
+    f.render_widget(Paragraph::new(status), chunks[1]);
+}
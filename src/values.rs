@@ -0,0 +1,491 @@
+use crate::model::{KeyValue, StreamEntry};
+use crate::viewer::bytes_to_text;
+use anyhow::Result;
+use redis::aio::MultiplexedConnection;
+use std::collections::{HashMap, HashSet};
+
+/// Elements fetched per window of a collection
+pub const WINDOW_SIZE: usize = 200;
+
+/// Rows left below the selection before the next window is fetched
+pub const PREFETCH_MARGIN: usize = 50;
+
+/// Most elements a describe search collects from the server
+pub const SEARCH_LIMIT: usize = 1000;
+
+/// Where the next window of a collection starts
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowCursor {
+    Index(usize),        // LRANGE / ZRANGE rank
+    Scan(u64),           // SSCAN / HSCAN cursor, 0 only before the first call
+    StreamAfter(String), // Last stream entry ID loaded
+    Done,
+}
+
+/// Windowed loading state of the described collection
+#[derive(Debug, Clone)]
+pub struct DescribeWindow {
+    pub key: String,
+    pub key_type: String,
+    pub total: u64, // LLEN / SCARD / ZCARD / HLEN / XLEN when loading started
+    pub next: WindowCursor,
+    seen: HashSet<String>, // Set members, sorted set members and hash fields loaded so far
+    pub binary: HashSet<String>, // Rows (index, member or field) shown with `\xHH` escapes
+}
+
+impl DescribeWindow {
+    pub fn new(key: String, key_type: String) -> Self {
+        let next = match key_type.as_str() {
+            "set" | "hash" => WindowCursor::Scan(0),
+            "stream" => WindowCursor::StreamAfter(String::new()),
+            _ => WindowCursor::Index(0),
+        };
+        Self {
+            key,
+            key_type,
+            total: 0,
+            next,
+            seen: HashSet::new(),
+            binary: HashSet::new(),
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.next == WindowCursor::Done
+    }
+
+    /// Append a fetched window to the loaded data. SCAN may return an element more
+    /// than once, so set members, sorted set members and hash fields loaded before
+    /// are skipped.
+    pub fn append(&mut self, data: &mut KeyValue, window: KeyValue) {
+        let seen = &mut self.seen;
+        match (data, window) {
+            (KeyValue::List(items), KeyValue::List(more)) => items.extend(more),
+            (KeyValue::ZSet(items), KeyValue::ZSet(more)) => {
+                items.extend(more.into_iter().filter(|(m, _)| seen.insert(m.clone())));
+            }
+            (KeyValue::Stream(items), KeyValue::Stream(more)) => items.extend(more),
+            (KeyValue::Set(items), KeyValue::Set(more)) => {
+                items.extend(more.into_iter().filter(|m| seen.insert(m.clone())));
+            }
+            (KeyValue::Hash(items), KeyValue::Hash(more)) => {
+                items.extend(more.into_iter().filter(|(f, _)| seen.insert(f.clone())));
+            }
+            (data, window) => {
+                *seen = row_names(&window);
+                *data = window;
+            }
+        }
+    }
+}
+
+/// Number of elements in a collection
+pub async fn fetch_total(con: &mut MultiplexedConnection, key: &str, key_type: &str) -> u64 {
+    let command = match key_type {
+        "list" => "LLEN",
+        "set" => "SCARD",
+        "zset" => "ZCARD",
+        "hash" => "HLEN",
+        "stream" => "XLEN",
+        _ => return 0,
+    };
+    redis::cmd(command)
+        .arg(key)
+        .query_async(con)
+        .await
+        .unwrap_or(0)
+}
+
+/// Fetch the next window of `window`'s collection and advance its cursor
+pub async fn fetch_window(
+    con: &mut MultiplexedConnection,
+    window: &mut DescribeWindow,
+) -> Result<KeyValue> {
+    let key = window.key.as_str();
+    let (value, next) = match (window.key_type.as_str(), &window.next) {
+        (_, WindowCursor::Done) => return Ok(empty_value(&window.key_type)),
+        ("list", WindowCursor::Index(start)) => {
+            let stop = start + WINDOW_SIZE - 1;
+            let raw: Vec<Vec<u8>> = redis::cmd("LRANGE")
+                .arg(key)
+                .arg(*start)
+                .arg(stop)
+                .query_async(con)
+                .await?;
+            let next = next_index(*start, raw.len());
+            let mut items = Vec::with_capacity(raw.len());
+            for (i, element) in raw.into_iter().enumerate() {
+                let (text, escaped) = bytes_to_text(element);
+                if escaped {
+                    window.binary.insert((start + i).to_string());
+                }
+                items.push(text);
+            }
+            (KeyValue::List(items), next)
+        }
+        // Ranges by rank rather than ZSCAN, so members stay in score order
+        ("zset", WindowCursor::Index(start)) => {
+            let stop = start + WINDOW_SIZE - 1;
+            let raw: Vec<(Vec<u8>, f64)> = redis::cmd("ZRANGE")
+                .arg(key)
+                .arg(*start)
+                .arg(stop)
+                .arg("WITHSCORES")
+                .query_async(con)
+                .await?;
+            let next = next_index(*start, raw.len());
+            (zset_rows(raw, &mut window.binary), next)
+        }
+        ("set", WindowCursor::Scan(cursor)) => {
+            let (raw, next) =
+                scan_window::<Vec<u8>>(con, "SSCAN", key, *cursor, None, WINDOW_SIZE).await?;
+            (set_rows(raw, &mut window.binary), next)
+        }
+        ("hash", WindowCursor::Scan(cursor)) => {
+            let (raw, next) =
+                scan_window::<(Vec<u8>, Vec<u8>)>(con, "HSCAN", key, *cursor, None, WINDOW_SIZE)
+                    .await?;
+            (hash_rows(raw, &mut window.binary), next)
+        }
+        ("stream", WindowCursor::StreamAfter(last_id)) => {
+            let start = if last_id.is_empty() {
+                "-".to_string()
+            } else {
+                next_stream_id(last_id)
+            };
+            let entries = fetch_stream_range(con, key, &start, WINDOW_SIZE).await?;
+            let next = match entries.last() {
+                Some(last) if entries.len() == WINDOW_SIZE => {
+                    WindowCursor::StreamAfter(last.id.clone())
+                }
+                _ => WindowCursor::Done,
+            };
+            (KeyValue::Stream(entries), next)
+        }
+        _ => anyhow::bail!("Unsupported type: {}", window.key_type),
+    };
+
+    window.next = next;
+    Ok(value)
+}
+
+/// Field and value of a hash or stream entry as returned by the server
+type RawPair = (Vec<u8>, Vec<u8>);
+
+/// Set members as text, recording members that aren't UTF-8 in `binary`
+fn set_rows(raw: Vec<Vec<u8>>, binary: &mut HashSet<String>) -> KeyValue {
+    let mut items = Vec::with_capacity(raw.len());
+    for member in raw {
+        let (member, escaped) = bytes_to_text(member);
+        if escaped {
+            binary.insert(member.clone());
+        }
+        items.push(member);
+    }
+    KeyValue::Set(items)
+}
+
+/// Sorted set members as text, recording members that aren't UTF-8 in `binary`
+fn zset_rows(raw: Vec<(Vec<u8>, f64)>, binary: &mut HashSet<String>) -> KeyValue {
+    let mut items = Vec::with_capacity(raw.len());
+    for (member, score) in raw {
+        let (member, escaped) = bytes_to_text(member);
+        if escaped {
+            binary.insert(member.clone());
+        }
+        items.push((member, score));
+    }
+    KeyValue::ZSet(items)
+}
+
+/// Hash fields and values as text, recording fields with a field or value that isn't UTF-8 in `binary`
+fn hash_rows(raw: Vec<RawPair>, binary: &mut HashSet<String>) -> KeyValue {
+    let mut items = Vec::with_capacity(raw.len());
+    for (field, value) in raw {
+        let (field, field_escaped) = bytes_to_text(field);
+        let (value, value_escaped) = bytes_to_text(value);
+        if field_escaped || value_escaped {
+            binary.insert(field.clone());
+        }
+        items.push((field, value));
+    }
+    KeyValue::Hash(items)
+}
+
+fn next_index(start: usize, fetched: usize) -> WindowCursor {
+    if fetched < WINDOW_SIZE {
+        WindowCursor::Done
+    } else {
+        WindowCursor::Index(start + fetched)
+    }
+}
+
+/// Run SSCAN/HSCAN/ZSCAN from `cursor` until `limit` elements are collected or the scan wraps
+async fn scan_window<T: redis::FromRedisValue>(
+    con: &mut MultiplexedConnection,
+    command: &str,
+    key: &str,
+    mut cursor: u64,
+    pattern: Option<&str>,
+    limit: usize,
+) -> Result<(Vec<T>, WindowCursor)> {
+    let mut items = Vec::new();
+    loop {
+        let mut cmd = redis::cmd(command);
+        cmd.arg(key).arg(cursor);
+        if let Some(pattern) = pattern {
+            cmd.arg("MATCH").arg(pattern);
+        }
+        let (next, batch): (u64, Vec<T>) =
+            cmd.arg("COUNT").arg(WINDOW_SIZE).query_async(con).await?;
+        items.extend(batch);
+
+        if next == 0 {
+            return Ok((items, WindowCursor::Done));
+        }
+        cursor = next;
+        if items.len() >= limit {
+            return Ok((items, WindowCursor::Scan(cursor)));
+        }
+    }
+}
+
+/// Result of a server-side search in a collection
+pub struct SearchHits {
+    pub rows: KeyValue,
+    pub truncated: bool,         // The search stopped at `SEARCH_LIMIT` elements
+    pub binary: HashSet<String>, // Rows shown with `\xHH` escapes
+}
+
+/// Elements of a set, sorted set or hash whose member or field name contains
+/// `text` (ignoring ASCII case), found with SSCAN/ZSCAN/HSCAN MATCH over the whole key.
+/// Sorted set members come back in score order.
+pub async fn scan_matches(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    key_type: &str,
+    text: &str,
+) -> Result<SearchHits> {
+    let pattern = contains_pattern(text);
+    let pattern = Some(pattern.as_str());
+    let mut window = DescribeWindow::new(key.to_string(), key_type.to_string());
+    let (value, next) = match key_type {
+        "set" => {
+            let (raw, next) =
+                scan_window::<Vec<u8>>(con, "SSCAN", key, 0, pattern, SEARCH_LIMIT).await?;
+            (set_rows(raw, &mut window.binary), next)
+        }
+        "zset" => {
+            let (mut raw, next) =
+                scan_window::<(Vec<u8>, f64)>(con, "ZSCAN", key, 0, pattern, SEARCH_LIMIT).await?;
+            raw.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+            (zset_rows(raw, &mut window.binary), next)
+        }
+        "hash" => {
+            let (raw, next) =
+                scan_window::<(Vec<u8>, Vec<u8>)>(con, "HSCAN", key, 0, pattern, SEARCH_LIMIT)
+                    .await?;
+            (hash_rows(raw, &mut window.binary), next)
+        }
+        _ => (empty_value(key_type), WindowCursor::Done),
+    };
+
+    // SCAN may return an element more than once
+    let mut rows = empty_value(key_type);
+    window.append(&mut rows, value);
+    Ok(SearchHits {
+        rows,
+        truncated: next != WindowCursor::Done,
+        binary: window.binary,
+    })
+}
+
+/// Glob matching any string that contains `text`, ignoring case for ASCII letters.
+/// ASCII letters become `[aA]` classes and glob characters are escaped. Other
+/// characters are matched as they are: a class like `[éÉ]` would be compared
+/// byte by byte by the server and never match a multi-byte character.
+fn contains_pattern(text: &str) -> String {
+    let mut pattern = String::from("*");
+    for c in text.chars() {
+        if c.is_ascii_alphabetic() {
+            pattern.push('[');
+            pattern.push(c.to_ascii_lowercase());
+            pattern.push(c.to_ascii_uppercase());
+            pattern.push(']');
+        } else {
+            if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+                pattern.push('\\');
+            }
+            pattern.push(c);
+        }
+    }
+    pattern.push('*');
+    pattern
+}
+
+/// XRANGE from `start` (inclusive) with COUNT
+pub async fn fetch_stream_range(
+    con: &mut MultiplexedConnection,
+    key: &str,
+    start: &str,
+    count: usize,
+) -> Result<Vec<StreamEntry>> {
+    let entries: Vec<(String, Vec<RawPair>)> = redis::cmd("XRANGE")
+        .arg(key)
+        .arg(start)
+        .arg("+")
+        .arg("COUNT")
+        .arg(count)
+        .query_async(con)
+        .await?;
+
+    Ok(entries
+        .into_iter()
+        .map(|(id, fields)| StreamEntry {
+            id,
+            fields: fields
+                .into_iter()
+                .map(|(field, value)| (bytes_to_text(field).0, bytes_to_text(value).0))
+                .collect::<HashMap<_, _>>(),
+        })
+        .collect())
+}
+
+/// Smallest stream ID after `id`, so XRANGE can continue without the `(` syntax of Redis 6.2
+fn next_stream_id(id: &str) -> String {
+    match id.split_once('-') {
+        Some((ms, seq)) => match seq.parse::<u64>() {
+            Ok(seq) if seq < u64::MAX => format!("{}-{}", ms, seq + 1),
+            _ => format!("{}-0", ms.parse::<u64>().unwrap_or(0) + 1),
+        },
+        None => format!("{}-1", id),
+    }
+}
+
+pub fn empty_value(key_type: &str) -> KeyValue {
+    match key_type {
+        "list" => KeyValue::List(Vec::new()),
+        "set" => KeyValue::Set(Vec::new()),
+        "zset" => KeyValue::ZSet(Vec::new()),
+        "hash" => KeyValue::Hash(Vec::new()),
+        _ => KeyValue::Stream(Vec::new()),
+    }
+}
+
+/// Member or field names of the rows of `data` that are deduplicated
+fn row_names(data: &KeyValue) -> HashSet<String> {
+    match data {
+        KeyValue::Set(items) => items.iter().cloned().collect(),
+        KeyValue::ZSet(items) => items.iter().map(|(m, _)| m.clone()).collect(),
+        KeyValue::Hash(items) => items.iter().map(|(f, _)| f.clone()).collect(),
+        _ => HashSet::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_pattern_ignores_ascii_case() {
+        assert_eq!(contains_pattern("Ab1"), "*[aA][bB]1*");
+    }
+
+    #[test]
+    fn contains_pattern_escapes_glob_characters() {
+        assert_eq!(contains_pattern("*?[]\\"), r"*\*\?\[\]\\*");
+        assert_eq!(contains_pattern(""), "**");
+    }
+
+    #[test]
+    fn contains_pattern_keeps_non_ascii_literal() {
+        assert_eq!(contains_pattern("café"), "*[cC][aA][fF]é*");
+        assert_eq!(contains_pattern("Ärger"), "*Ä[rR][gG][eE][rR]*");
+    }
+
+    #[test]
+    fn next_stream_id_increments_sequence() {
+        assert_eq!(next_stream_id("1526919030474-55"), "1526919030474-56");
+        assert_eq!(next_stream_id("0-0"), "0-1");
+    }
+
+    #[test]
+    fn next_stream_id_rolls_over_to_next_millisecond() {
+        assert_eq!(
+            next_stream_id(&format!("1526919030474-{}", u64::MAX)),
+            "1526919030475-0"
+        );
+    }
+
+    #[test]
+    fn next_stream_id_without_sequence() {
+        assert_eq!(next_stream_id("1526919030474"), "1526919030474-1");
+    }
+
+    #[test]
+    fn append_skips_members_loaded_by_earlier_windows() {
+        let mut window = DescribeWindow::new("k".to_string(), "set".to_string());
+        let mut data = empty_value("set");
+        window.append(
+            &mut data,
+            KeyValue::Set(vec!["a".into(), "b".into(), "a".into()]),
+        );
+        window.append(&mut data, KeyValue::Set(vec!["b".into(), "c".into()]));
+        match data {
+            KeyValue::Set(items) => assert_eq!(items, vec!["a", "b", "c"]),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn append_dedups_hash_fields_by_name() {
+        let mut window = DescribeWindow::new("k".to_string(), "hash".to_string());
+        let mut data = empty_value("hash");
+        window.append(&mut data, KeyValue::Hash(vec![("f".into(), "1".into())]));
+        window.append(
+            &mut data,
+            KeyValue::Hash(vec![("f".into(), "2".into()), ("g".into(), "3".into())]),
+        );
+        match data {
+            KeyValue::Hash(items) => assert_eq!(
+                items,
+                vec![("f".to_string(), "1".to_string()), ("g".into(), "3".into())]
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn append_keeps_list_duplicates() {
+        let mut window = DescribeWindow::new("k".to_string(), "list".to_string());
+        let mut data = empty_value("list");
+        window.append(&mut data, KeyValue::List(vec!["a".into()]));
+        window.append(&mut data, KeyValue::List(vec!["a".into()]));
+        assert!(matches!(data, KeyValue::List(items) if items.len() == 2));
+    }
+
+    #[test]
+    fn hash_rows_mark_fields_with_binary_values() {
+        let mut binary = HashSet::new();
+        let rows = hash_rows(
+            vec![
+                (b"ok".to_vec(), b"v".to_vec()),
+                (b"raw".to_vec(), vec![0xff]),
+            ],
+            &mut binary,
+        );
+        match rows {
+            KeyValue::Hash(items) => assert_eq!(items[1], ("raw".into(), r"\xff".into())),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(binary, HashSet::from(["raw".to_string()]));
+    }
+
+    #[test]
+    fn set_rows_mark_escaped_members() {
+        let mut binary = HashSet::new();
+        let rows = set_rows(vec![b"a".to_vec(), vec![b'b', 0x80]], &mut binary);
+        assert!(matches!(rows, KeyValue::Set(items) if items == vec!["a", r"b\x80"]));
+        assert_eq!(binary, HashSet::from([r"b\x80".to_string()]));
+    }
+}
@@ -0,0 +1,287 @@
+use anyhow::{bail, Result};
+use base64::Engine;
+use std::io::Read;
+
+/// Most bytes a decompressing viewer inflates a value to
+const MAX_DECOMPRESSED: u64 = 16 * 1024 * 1024;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+const LZ4_FRAME_MAGIC: &[u8] = &[0x04, 0x22, 0x4d, 0x18];
+
+/// How a raw string value is shown in the describe view
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ValueViewer {
+    Decoded, // Decoder configured for the key in config.yaml
+    #[default]
+    Text,
+    Json,
+    Hex,
+    Base64,
+    Gzip,
+    Zstd,
+    Lz4,
+}
+
+impl ValueViewer {
+    pub const ALL: [ValueViewer; 8] = [
+        ValueViewer::Decoded,
+        ValueViewer::Text,
+        ValueViewer::Json,
+        ValueViewer::Hex,
+        ValueViewer::Base64,
+        ValueViewer::Gzip,
+        ValueViewer::Zstd,
+        ValueViewer::Lz4,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ValueViewer::Decoded => "decoded",
+            ValueViewer::Text => "text",
+            ValueViewer::Json => "json",
+            ValueViewer::Hex => "hex",
+            ValueViewer::Base64 => "base64",
+            ValueViewer::Gzip => "gzip",
+            ValueViewer::Zstd => "zstd",
+            ValueViewer::Lz4 => "lz4",
+        }
+    }
+
+    pub fn next(&self) -> ValueViewer {
+        let idx = Self::ALL.iter().position(|v| v == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    /// Pick a viewer from the content: compression magic numbers first, then
+    /// JSON, then printable UTF-8, falling back to a hex dump
+    pub fn detect(bytes: &[u8]) -> ValueViewer {
+        if bytes.starts_with(GZIP_MAGIC) {
+            ValueViewer::Gzip
+        } else if bytes.starts_with(ZSTD_MAGIC) {
+            ValueViewer::Zstd
+        } else if bytes.starts_with(LZ4_FRAME_MAGIC) {
+            ValueViewer::Lz4
+        } else if looks_like_json(bytes) {
+            ValueViewer::Json
+        } else if is_text(bytes) {
+            ValueViewer::Text
+        } else {
+            ValueViewer::Hex
+        }
+    }
+
+    /// Render `bytes` with this viewer. Decoded values are rendered by `decoders::Decoder::decode`.
+    pub fn render(&self, bytes: &[u8]) -> Result<String> {
+        match self {
+            ValueViewer::Decoded => bail!("No decoder configured for this key"),
+            ValueViewer::Text => Ok(String::from_utf8_lossy(bytes).into_owned()),
+            ValueViewer::Json => pretty_json(bytes),
+            ValueViewer::Hex => Ok(hex_dump(bytes)),
+            ValueViewer::Base64 => Ok(base64::engine::general_purpose::STANDARD.encode(bytes)),
+            ValueViewer::Gzip => Ok(render_decompressed(&gunzip(bytes)?)),
+            ValueViewer::Zstd => Ok(render_decompressed(&unzstd(bytes)?)),
+            ValueViewer::Lz4 => Ok(render_decompressed(&unlz4(bytes)?)),
+        }
+    }
+}
+
+pub fn gunzip(bytes: &[u8]) -> Result<Vec<u8>> {
+    read_limited(flate2::read::GzDecoder::new(bytes))
+}
+
+pub fn unzstd(bytes: &[u8]) -> Result<Vec<u8>> {
+    let decoder =
+        ruzstd::decoding::StreamingDecoder::new(bytes).map_err(|e| anyhow::anyhow!("{}", e))?;
+    read_limited(decoder)
+}
+
+pub fn unlz4(bytes: &[u8]) -> Result<Vec<u8>> {
+    if bytes.starts_with(LZ4_FRAME_MAGIC) {
+        read_limited(lz4_flex::frame::FrameDecoder::new(bytes))
+    } else {
+        // Block format with the uncompressed size prepended (u32 LE), as written by lz4_flex.
+        // Check the size before decompressing, which allocates it up front.
+        let Some((size, block)) = bytes.split_first_chunk::<4>() else {
+            bail!("LZ4 block is too short");
+        };
+        let size = u32::from_le_bytes(*size) as u64;
+        if size > MAX_DECOMPRESSED {
+            bail!(
+                "Decompressed value is larger than {} MiB",
+                MAX_DECOMPRESSED / 1024 / 1024
+            );
+        }
+        Ok(lz4_flex::block::decompress(block, size as usize)?)
+    }
+}
+
+fn read_limited(reader: impl Read) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    reader.take(MAX_DECOMPRESSED + 1).read_to_end(&mut out)?;
+    if out.len() as u64 > MAX_DECOMPRESSED {
+        bail!(
+            "Decompressed value is larger than {} MiB",
+            MAX_DECOMPRESSED / 1024 / 1024
+        );
+    }
+    Ok(out)
+}
+
+/// Decompressed payloads are shown as JSON or text when they are, as hex otherwise
+pub fn render_decompressed(bytes: &[u8]) -> String {
+    if looks_like_json(bytes) {
+        if let Ok(json) = pretty_json(bytes) {
+            return json;
+        }
+    }
+    if is_text(bytes) {
+        String::from_utf8_lossy(bytes).into_owned()
+    } else {
+        hex_dump(bytes)
+    }
+}
+
+fn looks_like_json(bytes: &[u8]) -> bool {
+    let trimmed = bytes.trim_ascii_start();
+    (trimmed.starts_with(b"{") || trimmed.starts_with(b"["))
+        && serde_json::from_slice::<serde_json::Value>(bytes).is_ok()
+}
+
+fn pretty_json(bytes: &[u8]) -> Result<String> {
+    let value: serde_json::Value = serde_json::from_slice(bytes)?;
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+/// Valid UTF-8 without control characters other than whitespace
+fn is_text(bytes: &[u8]) -> bool {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s
+            .chars()
+            .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t')),
+        Err(_) => false,
+    }
+}
+
+/// Keep valid UTF-8 as text, show anything else with `\xHH` escapes. The flag
+/// tells whether the bytes had to be escaped.
+pub fn bytes_to_text(bytes: Vec<u8>) -> (String, bool) {
+    match String::from_utf8(bytes) {
+        Ok(s) => (s, false),
+        Err(e) => {
+            let text = e
+                .as_bytes()
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        (b as char).to_string()
+                    } else {
+                        format!("\\x{:02x}", b)
+                    }
+                })
+                .collect();
+            (text, true)
+        }
+    }
+}
+
+/// Classic 16 bytes per line dump with offsets and an ASCII column
+pub fn hex_dump(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for (i, chunk) in bytes.chunks(16).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| {
+                if b.is_ascii_graphic() || b == b' ' {
+                    b as char
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        out.push_str(&format!(
+            "{:08x}  {:<47}  {}\n",
+            i * 16,
+            hex.join(" "),
+            ascii
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn detect_compression_magic() {
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gz.write_all(b"hello").unwrap();
+        assert_eq!(
+            ValueViewer::detect(&gz.finish().unwrap()),
+            ValueViewer::Gzip
+        );
+        assert_eq!(
+            ValueViewer::detect(&[0x28, 0xb5, 0x2f, 0xfd, 0x00]),
+            ValueViewer::Zstd
+        );
+        assert_eq!(
+            ValueViewer::detect(
+                &lz4_flex::frame::FrameEncoder::new(Vec::new())
+                    .finish()
+                    .unwrap()
+            ),
+            ValueViewer::Lz4
+        );
+    }
+
+    #[test]
+    fn detect_json_text_and_binary() {
+        assert_eq!(ValueViewer::detect(br#" {"a": [1, 2]}"#), ValueViewer::Json);
+        assert_eq!(ValueViewer::detect(b"[1, 2"), ValueViewer::Text);
+        assert_eq!(
+            ValueViewer::detect("héllo\nworld\t!".as_bytes()),
+            ValueViewer::Text
+        );
+        assert_eq!(ValueViewer::detect(b"bell\x07"), ValueViewer::Hex);
+        assert_eq!(ValueViewer::detect(&[0xff, 0xfe, 0x00]), ValueViewer::Hex);
+        assert_eq!(ValueViewer::detect(b""), ValueViewer::Text);
+    }
+
+    #[test]
+    fn bytes_to_text_escapes_only_invalid_utf8() {
+        assert_eq!(
+            bytes_to_text("héllo".as_bytes().to_vec()),
+            ("héllo".to_string(), false)
+        );
+        assert_eq!(
+            bytes_to_text(vec![b'a', 0xff, b' ', 0x00]),
+            (r"a\xff \x00".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn unlz4_block_round_trip() {
+        let block = lz4_flex::block::compress_prepend_size(b"some value some value");
+        assert_eq!(unlz4(&block).unwrap(), b"some value some value");
+    }
+
+    #[test]
+    fn unlz4_block_rejects_oversized_prefix() {
+        let mut block = u32::MAX.to_le_bytes().to_vec();
+        block.extend_from_slice(&[0x10, b'a']);
+        let err = unlz4(&block).unwrap_err();
+        assert!(err.to_string().contains("larger than"));
+        assert!(unlz4(&[0x01, 0x00]).is_err());
+    }
+
+    #[test]
+    fn gunzip_stops_at_limit() {
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+        gz.write_all(&vec![0u8; MAX_DECOMPRESSED as usize + 1])
+            .unwrap();
+        assert!(gunzip(&gz.finish().unwrap()).is_err());
+    }
+}